@@ -56,6 +56,37 @@ pub trait Stream {
         self: Pin<&mut Self>,
         lw: &LocalWaker,
     ) -> Poll<Option<Self::Item>>;
+
+    /// Returns the bounds on the remaining length of the stream.
+    ///
+    /// Specifically, `size_hint()` returns a tuple where the first element
+    /// is the lower bound, and the second element is the upper bound.
+    ///
+    /// The second half of the tuple that is returned is an `Option<usize>`.
+    /// A `None` here means that either there is no known upper bound, or the
+    /// upper bound is larger than `usize`.
+    ///
+    /// # Implementation notes
+    ///
+    /// It is not enforced that a stream implementation yields the declared
+    /// number of elements. A buggy stream may yield less than the lower bound
+    /// or more than the upper bound of elements.
+    ///
+    /// `size_hint()` is primarily intended to be used for optimizations such
+    /// as reserving space for the elements of the stream, but must not be
+    /// trusted to e.g. omit bounds checks in unsafe code. An incorrect
+    /// implementation of `size_hint()` should not lead to memory safety
+    /// violations.
+    ///
+    /// That said, the implementation should provide a correct estimation,
+    /// because otherwise it would be a violation of the trait's protocol.
+    ///
+    /// The default implementation returns `(0, None)` which is correct
+    /// for any stream.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
 }
 
 impl<'a, S: ?Sized + Stream + Unpin> Stream for &'a mut S {
@@ -67,6 +98,10 @@ impl<'a, S: ?Sized + Stream + Unpin> Stream for &'a mut S {
     ) -> Poll<Option<Self::Item>> {
         S::poll_next(Pin::new(&mut **self), lw)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
 }
 
 impl<P> Stream for Pin<P>
@@ -82,6 +117,10 @@ where
     ) -> Poll<Option<Self::Item>> {
         Pin::get_mut(self).as_mut().poll_next(lw)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
 }
 
 #[cfg(feature = "either")]
@@ -99,6 +138,13 @@ impl<A, B> Stream for Either<A, B>
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Either::Left(a) => a.size_hint(),
+            Either::Right(b) => b.size_hint(),
+        }
+    }
 }
 
 /// A convenience for streams that return `Result` values that includes
@@ -144,6 +190,10 @@ if_std! {
         ) -> Poll<Option<Self::Item>> {
             Pin::new(&mut **self).poll_next(lw)
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (**self).size_hint()
+        }
     }
 
     impl<S: Stream> Stream for ::std::panic::AssertUnwindSafe<S> {
@@ -155,6 +205,10 @@ if_std! {
         ) -> Poll<Option<S::Item>> {
             unsafe { Pin::map_unchecked_mut(self, |x| &mut x.0) }.poll_next(lw)
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.0.size_hint()
+        }
     }
 
     impl<T: Unpin> Stream for ::std::collections::VecDeque<T> {
@@ -166,5 +220,9 @@ if_std! {
         ) -> Poll<Option<Self::Item>> {
             Poll::Ready(self.pop_front())
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len(), Some(self.len()))
+        }
     }
 }
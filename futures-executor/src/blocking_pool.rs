@@ -0,0 +1,128 @@
+use futures_core::task::SpawnError;
+use std::collections::VecDeque;
+use std::prelude::v1::*;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// How long a thread of a `BlockingPool` waits for a new job before it exits.
+const KEEP_ALIVE: Duration = Duration::from_secs(10);
+
+/// A pool of threads for blocking closures, owned by a `ThreadPool`.
+///
+/// Threads are only started when a job is submitted and no idle thread is
+/// around to take it, up to a maximum number of threads. Beyond that, jobs
+/// wait in a queue for the next thread to become available. Threads that
+/// have been idle for `KEEP_ALIVE` exit, so an idle pool doesn't hold on to
+/// any threads.
+pub(crate) struct BlockingPool {
+    state: Mutex<State>,
+    cv: Condvar,
+    max_threads: usize,
+    name_prefix: Option<String>,
+}
+
+struct State {
+    jobs: VecDeque<Box<dyn Job>>,
+    // Threads that are running, whether busy or idle
+    threads: usize,
+    // Threads that are waiting for a job
+    idle: usize,
+    // Set once the owning pool has been closed, after which threads exit as
+    // soon as the queue is empty
+    closed: bool,
+    // Used to number the threads' names
+    started: usize,
+}
+
+// `Box<dyn FnOnce()>` can't be called yet, so jobs are called through this
+trait Job: Send {
+    fn run(self: Box<Self>);
+}
+
+impl<F: FnOnce() + Send> Job for F {
+    fn run(self: Box<Self>) {
+        (*self)()
+    }
+}
+
+impl BlockingPool {
+    pub(crate) fn new(max_threads: usize, name_prefix: Option<String>) -> BlockingPool {
+        BlockingPool {
+            state: Mutex::new(State {
+                jobs: VecDeque::new(),
+                threads: 0,
+                idle: 0,
+                closed: false,
+                started: 0,
+            }),
+            cv: Condvar::new(),
+            max_threads,
+            name_prefix,
+        }
+    }
+
+    // Queues `job` and makes sure a thread picks it up. This also works after
+    // the pool has been closed, so that a job accepted just before a shutdown
+    // still runs. Fails only if no thread could be started and none is
+    // running, in which case `job` is dropped without running.
+    pub(crate) fn spawn<F>(self: &Arc<Self>, job: F) -> Result<(), SpawnError>
+        where F: FnOnce() + Send + 'static,
+    {
+        let mut state = self.state.lock().unwrap();
+        state.jobs.push_back(Box::new(job));
+        if state.jobs.len() <= state.idle {
+            self.cv.notify_one();
+        } else if state.threads < self.max_threads {
+            state.threads += 1;
+            let mut builder = thread::Builder::new();
+            if let Some(ref name_prefix) = self.name_prefix {
+                builder = builder.name(format!("{}blocking-{}", name_prefix, state.started));
+            }
+            state.started += 1;
+            let pool = self.clone();
+            if builder.spawn(move || pool.work()).is_err() {
+                state.threads -= 1;
+                // The running threads will get to the job eventually, but
+                // without any the job would never run
+                if state.threads == 0 {
+                    let job = state.jobs.pop_back();
+                    drop(state);
+                    drop(job);
+                    return Err(SpawnError::shutdown());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Lets the threads exit once they have run all queued jobs
+    pub(crate) fn close(&self) {
+        self.state.lock().unwrap().closed = true;
+        self.cv.notify_all();
+    }
+
+    fn work(&self) {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if let Some(job) = state.jobs.pop_front() {
+                drop(state);
+                job.run();
+                state = self.state.lock().unwrap();
+                continue;
+            }
+            if state.closed {
+                break;
+            }
+
+            state.idle += 1;
+            let (guard, timeout) = self.cv.wait_timeout(state, KEEP_ALIVE).unwrap();
+            state = guard;
+            state.idle -= 1;
+            if timeout.timed_out() && state.jobs.is_empty() {
+                break;
+            }
+        }
+        state.threads -= 1;
+    }
+}
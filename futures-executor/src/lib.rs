@@ -18,11 +18,18 @@ macro_rules! if_std {
 
 if_std! {
     mod local_pool;
-    pub use crate::local_pool::{block_on, block_on_stream, BlockingStream, LocalPool, LocalSpawner};
+    pub use crate::local_pool::{
+        block_on, block_on_stream, BlockingStream, LocalPool, LocalSpawner,
+        LocalScope, LocalScopeSpawner, RunOne, Stalled,
+    };
 
+    mod blocking_pool;
     mod unpark_mutex;
     mod thread_pool;
-    pub use crate::thread_pool::{ThreadPool, ThreadPoolBuilder};
+    pub use crate::thread_pool::{
+        Metrics, Priority, Shutdown, ShutdownMode, ThreadPool,
+        ThreadPoolBuilder,
+    };
 
     mod enter;
     pub use crate::enter::{enter, Enter, EnterError};
@@ -12,6 +12,7 @@ use pin_utils::pin_mut;
 use std::cell::{RefCell};
 use std::marker::Unpin;
 use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
 use std::prelude::v1::*;
 use std::rc::{Rc, Weak};
 use std::sync::Arc;
@@ -43,6 +44,82 @@ pub struct LocalSpawner {
 
 type Incoming = RefCell<Vec<LocalFutureObj<'static, ()>>>;
 
+/// Why [`LocalPool::run_until_stalled`](LocalPool::run_until_stalled)
+/// returned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Stalled {
+    /// All tasks in the pool have completed.
+    Complete,
+    /// The remaining tasks are waiting to be woken up, e.g. by I/O or by
+    /// code outside of the pool.
+    Pending {
+        /// The number of tasks which are still in the pool.
+        tasks: usize,
+    },
+}
+
+/// The outcome of a single
+/// [`LocalPool::try_run_one_step`](LocalPool::try_run_one_step).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RunOne {
+    /// A task was polled and completed.
+    Completed,
+    /// A task was polled, but it hasn't completed yet.
+    Progressed,
+    /// No task was ready to be polled.
+    Stalled,
+}
+
+/// A set of tasks that may borrow from the stack frame they were created in.
+///
+/// Tasks spawned onto a `LocalScope` only need to live as long as `'scope`,
+/// so unlike tasks spawned onto a [`LocalPool`](LocalPool) they don't have to
+/// be `'static`. The scope is itself a future which completes once all of its
+/// tasks have completed. Run it on a `LocalPool` through
+/// [`run_until`](LocalPool::run_until) or from within another task.
+///
+/// The tasks are owned by the scope: dropping it, either because it has
+/// completed or because the thread is unwinding from a panic, drops every
+/// task that hasn't completed yet. Borrowed data is therefore never accessed
+/// after the scope is gone.
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// use futures::executor::{LocalPool, LocalScope};
+/// use std::cell::Cell;
+///
+/// let mut pool = LocalPool::new();
+/// let counter = Cell::new(0);
+///
+/// let scope = LocalScope::new();
+/// let mut spawner = scope.spawner();
+/// for _ in 0..3 {
+///     let counter = &counter;
+///     spawner.spawn_local_scoped(async move {
+///         counter.set(counter.get() + 1);
+///     }).unwrap();
+/// }
+///
+/// pool.run_until(scope);
+/// assert_eq!(counter.get(), 3);
+/// ```
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct LocalScope<'scope> {
+    pool: FuturesUnordered<LocalFutureObj<'scope, ()>>,
+    incoming: Rc<ScopeIncoming<'scope>>,
+}
+
+/// A handle to a [`LocalScope`](LocalScope) that spawns tasks onto it.
+///
+/// Spawning through this handle fails once the scope has been dropped.
+#[derive(Clone, Debug)]
+pub struct LocalScopeSpawner<'scope> {
+    incoming: Weak<ScopeIncoming<'scope>>,
+}
+
+type ScopeIncoming<'scope> = RefCell<Vec<LocalFutureObj<'scope, ()>>>;
+
 pub(crate) struct ThreadNotify {
     thread: Thread
 }
@@ -158,32 +235,216 @@ impl LocalPool {
         })
     }
 
-    // Make maximal progress on the entire pool of spawned task, returning `Ready`
-    // if the pool is empty and `Pending` if no further progress can be made.
-    fn poll_pool(&mut self, local_waker: &LocalWaker) -> Poll<()> {
-        // state for the FuturesUnordered, which will never be used
+    /// Runs all tasks in the pool until none of them can make progress
+    /// anymore, without blocking the calling thread.
+    ///
+    /// The tasks are polled in passes. Each pass polls every task that is
+    /// ready at its start once, while tasks that are woken up during the pass
+    /// are left for the next one. A pass makes progress if a new task is
+    /// spawned, a task completes, or a task is polled that was woken up by
+    /// another task or by an external event. This method returns once there
+    /// have been more consecutive passes without progress than there are
+    /// tasks in the pool.
+    ///
+    /// Tasks that keep driving each other, such as two tasks exchanging
+    /// messages over a channel, therefore run for as long as they keep
+    /// waking each other up. Only tasks that do nothing but wake themselves
+    /// up, which would otherwise keep this method busy forever, are
+    /// considered stalled; they are polled again by the next call. Tasks
+    /// waiting for external events, such as I/O or timers, are left alone
+    /// until those events wake them up.
+    ///
+    /// Returns [`Stalled::Complete`] if the pool is empty afterwards, and
+    /// [`Stalled::Pending`] with the number of tasks left in the pool
+    /// otherwise. Only tasks spawned onto the pool are counted, so a future
+    /// passed to [`run_until`](LocalPool::run_until) is never included.
+    ///
+    /// ```
+    /// use futures::channel::oneshot;
+    /// use futures::executor::{LocalPool, Stalled};
+    /// use futures::future::FutureExt;
+    /// use futures::task::LocalSpawnExt;
+    ///
+    /// let mut pool = LocalPool::new();
+    /// let mut spawner = pool.spawner();
+    /// let (tx, rx) = oneshot::channel::<()>();
+    ///
+    /// let handle = spawner.spawn_local_with_handle(rx.map(|_| "done")).unwrap();
+    /// assert_eq!(pool.run_until_stalled(), Stalled::Pending { tasks: 1 });
+    ///
+    /// // The task is waiting for `tx`, so it stays in the pool
+    /// tx.send(()).unwrap();
+    /// assert_eq!(pool.run_until_stalled(), Stalled::Complete);
+    /// assert_eq!(pool.run_until(handle), "done");
+    /// ```
+    pub fn run_until_stalled(&mut self) -> Stalled {
+        let _enter = enter()
+            .expect("cannot execute `LocalPool` executor from within \
+                     another executor");
+
+        let mut idle_passes = 0;
         loop {
             // empty the incoming queue of newly-spawned tasks
-            {
+            let mut progress = {
                 let mut incoming = self.incoming.borrow_mut();
+                let spawned = !incoming.is_empty();
                 for task in incoming.drain(..) {
                     self.pool.push(task)
                 }
+                spawned
+            };
+
+            if self.pool.poll_ready_once_progress(|()| ()) > 0 {
+                progress = true;
             }
 
-            let ret = self.pool.poll_next_unpin(local_waker);
-            // we queued up some new tasks; add them and poll again
-            if !self.incoming.borrow().is_empty() {
-                continue;
+            if progress {
+                idle_passes = 0;
+            } else {
+                idle_passes += 1;
+                if idle_passes > self.pool.len() {
+                    // Tasks spawned during the last pass haven't been moved
+                    // over yet
+                    let tasks = self.pool.len() + self.incoming.borrow().len();
+                    return if tasks == 0 {
+                        Stalled::Complete
+                    } else {
+                        Stalled::Pending { tasks }
+                    };
+                }
             }
+        }
+    }
+
+    /// Polls a single task of the pool that is ready to run, without
+    /// blocking the calling thread.
+    ///
+    /// Returns `true` if a task was polled, whether it completed or not.
+    /// Returns `false` if the pool is empty or none of its tasks can make
+    /// progress without being woken up first. At most one task is polled, and
+    /// only once, so the caller stays in control of how much work is done.
+    /// Use [`try_run_one_step`](LocalPool::try_run_one_step) to tell whether
+    /// the task completed.
+    ///
+    /// Tasks that returned `Pending` stay in the pool and are polled again
+    /// once they have been woken up. Waking any of them also unparks the
+    /// thread that called this method, so an event loop embedding the pool
+    /// can use `std::thread::park` or its own notification mechanism after
+    /// `false` is returned instead of calling this method in a busy loop.
+    ///
+    /// ```
+    /// use futures::executor::LocalPool;
+    /// use futures::future;
+    /// use futures::task::LocalSpawnExt;
+    ///
+    /// let mut pool = LocalPool::new();
+    /// let mut spawner = pool.spawner();
+    ///
+    /// spawner.spawn_local(future::ready(())).unwrap();
+    /// spawner.spawn_local(future::empty()).unwrap();
+    ///
+    /// // Runs the first task, then polls the second one, which never
+    /// // completes and never wakes up
+    /// assert!(pool.try_run_one());
+    /// assert!(pool.try_run_one());
+    /// assert!(!pool.try_run_one());
+    /// ```
+    pub fn try_run_one(&mut self) -> bool {
+        self.try_run_one_step() != RunOne::Stalled
+    }
 
-            // no queued tasks; we may be done
-            match ret {
-                Poll::Pending => return Poll::Pending,
-                Poll::Ready(None) => return Poll::Ready(()),
-                _ => {}
+    /// Polls a single task of the pool, without blocking the calling thread.
+    ///
+    /// Like [`try_run_one`](LocalPool::try_run_one), this polls at most one
+    /// task that is ready to run, but it reports what happened in more
+    /// detail: [`RunOne::Completed`] if the task completed,
+    /// [`RunOne::Progressed`] if it's still pending, and
+    /// [`RunOne::Stalled`] if no task was ready. Tasks spawned in the
+    /// meantime are ready to run at the next call. This makes it possible to
+    /// step through the interleaving of tasks one poll at a time, e.g. in
+    /// tests.
+    ///
+    /// As with [`try_run_one`](LocalPool::try_run_one), waking a task
+    /// unparks the thread that called this method, so the thread can park
+    /// after [`RunOne::Stalled`] has been returned.
+    ///
+    /// ```
+    /// use futures::channel::oneshot;
+    /// use futures::executor::{LocalPool, RunOne};
+    /// use futures::future::FutureExt;
+    /// use futures::task::LocalSpawnExt;
+    ///
+    /// let mut pool = LocalPool::new();
+    /// let mut spawner = pool.spawner();
+    /// let (tx, rx) = oneshot::channel::<()>();
+    /// spawner.spawn_local(rx.map(|_| ())).unwrap();
+    ///
+    /// assert_eq!(pool.try_run_one_step(), RunOne::Progressed);
+    /// assert_eq!(pool.try_run_one_step(), RunOne::Stalled);
+    /// tx.send(()).unwrap();
+    /// assert_eq!(pool.try_run_one_step(), RunOne::Completed);
+    /// ```
+    pub fn try_run_one_step(&mut self) -> RunOne {
+        let _enter = enter()
+            .expect("cannot execute `LocalPool` executor from within \
+                     another executor");
+
+        // empty the incoming queue of newly-spawned tasks
+        {
+            let mut incoming = self.incoming.borrow_mut();
+            for task in incoming.drain(..) {
+                self.pool.push(task)
+            }
+        }
+
+        CURRENT_THREAD_NOTIFY.with(|thread_notify| {
+            let local_waker =
+              task::local_waker_from_nonlocal(thread_notify.clone());
+            match self.pool.poll_one_ready(&local_waker) {
+                Some(Poll::Ready(())) => RunOne::Completed,
+                Some(Poll::Pending) => RunOne::Progressed,
+                None => RunOne::Stalled,
+            }
+        })
+    }
+
+    // Make maximal progress on the entire pool of spawned task, returning `Ready`
+    // if the pool is empty and `Pending` if no further progress can be made.
+    fn poll_pool(&mut self, local_waker: &LocalWaker) -> Poll<()> {
+        poll_tasks(&mut self.pool, &self.incoming, local_waker)
+    }
+}
+
+// Shared between `LocalPool` and `LocalScope`: polls `pool` until it is empty
+// or no further progress can be made, moving tasks spawned in the meantime
+// over from `incoming`.
+fn poll_tasks<'a>(
+    pool: &mut FuturesUnordered<LocalFutureObj<'a, ()>>,
+    incoming: &RefCell<Vec<LocalFutureObj<'a, ()>>>,
+    local_waker: &LocalWaker,
+) -> Poll<()> {
+    // state for the FuturesUnordered, which will never be used
+    loop {
+        // empty the incoming queue of newly-spawned tasks
+        {
+            let mut incoming = incoming.borrow_mut();
+            for task in incoming.drain(..) {
+                pool.push(task)
             }
         }
+
+        let ret = pool.poll_next_unpin(local_waker);
+        // we queued up some new tasks; add them and poll again
+        if !incoming.borrow().is_empty() {
+            continue;
+        }
+
+        // no queued tasks; we may be done
+        match ret {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(None) => return Poll::Ready(()),
+            _ => {}
+        }
     }
 }
 
@@ -193,6 +454,58 @@ impl Default for LocalPool {
     }
 }
 
+impl<'scope> LocalScope<'scope> {
+    /// Create a new, empty scope.
+    pub fn new() -> LocalScope<'scope> {
+        LocalScope {
+            pool: FuturesUnordered::new(),
+            incoming: Default::default(),
+        }
+    }
+
+    /// Get a clonable handle to the scope for spawning tasks onto it,
+    /// including from within tasks that are already running in the scope.
+    pub fn spawner(&self) -> LocalScopeSpawner<'scope> {
+        LocalScopeSpawner {
+            incoming: Rc::downgrade(&self.incoming)
+        }
+    }
+}
+
+impl<'scope> Default for LocalScope<'scope> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'scope> Future for LocalScope<'scope> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<()> {
+        let LocalScope { pool, incoming } = &mut *self;
+        poll_tasks(pool, incoming, lw)
+    }
+}
+
+impl<'scope> LocalScopeSpawner<'scope> {
+    /// Spawns a task onto the scope.
+    ///
+    /// The future only needs to outlive the scope, so it may borrow data
+    /// that the scope's creator keeps alive for longer. Returns an error if
+    /// the scope has already been dropped.
+    pub fn spawn_local_scoped<Fut>(&mut self, future: Fut) -> Result<(), SpawnError>
+    where
+        Fut: Future<Output = ()> + 'scope,
+    {
+        if let Some(incoming) = self.incoming.upgrade() {
+            incoming.borrow_mut().push(LocalFutureObj::new(Box::new(future)));
+            Ok(())
+        } else {
+            Err(SpawnError::shutdown())
+        }
+    }
+}
+
 lazy_static! {
     static ref GLOBAL_POOL: ThreadPool = ThreadPool::builder()
         .name_prefix("block_on-")
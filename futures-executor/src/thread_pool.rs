@@ -1,15 +1,19 @@
+use crate::blocking_pool::BlockingPool;
 use crate::enter;
 use crate::unpark_mutex::UnparkMutex;
 use futures_core::future::{Future, FutureObj};
-use futures_core::task::{Poll, Wake, Spawn, SpawnError};
-use futures_util::future::FutureExt;
-use futures_util::task::local_waker_ref_from_nonlocal;
+use futures_core::task::{LocalWaker, Poll, Wake, Spawn, SpawnError};
+use futures_util::future::{FutureExt, RemoteHandle};
+use futures_util::task::{local_waker_ref_from_nonlocal, AtomicWaker};
 use num_cpus;
+use std::any::Any;
 use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
 use std::prelude::v1::*;
-use std::sync::{Arc, Mutex};
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::mpsc;
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::thread;
 use std::fmt;
 
@@ -32,22 +36,190 @@ pub struct ThreadPoolBuilder {
     name_prefix: Option<String>,
     after_start: Option<Arc<dyn Fn(usize) + Send + Sync>>,
     before_stop: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    panic_handler: Option<Arc<PanicHandler>>,
+    on_task_start: Option<Arc<dyn Fn() + Send + Sync>>,
+    on_task_complete: Option<Arc<dyn Fn() + Send + Sync>>,
+    high_priority_ratio: usize,
+    max_blocking_threads: usize,
 }
 
+type PanicHandler = dyn Fn(Box<dyn Any + Send>) + Send + Sync;
+
 trait AssertSendSync: Send + Sync {}
 impl AssertSendSync for ThreadPool {}
 
 struct PoolState {
-    tx: Mutex<mpsc::Sender<Message>>,
-    rx: Mutex<mpsc::Receiver<Message>>,
+    queue: Mutex<Queue>,
+    queue_cv: Condvar,
     cnt: AtomicUsize,
     size: usize,
+    // Event counts that only ever grow, each bumped once per event. The
+    // numbers of active and queued tasks and of busy workers are derived from
+    // them. Only the counts a draining pool needs to decide when to stop are
+    // updated with `SeqCst`, the others are relaxed.
+    //
+    // Task futures created by a spawn, and task futures dropped
+    spawned: AtomicUsize,
+    released: AtomicUsize,
+    // Tasks pushed onto the queue, and tasks popped off it by a worker
+    enqueued: AtomicUsize,
+    dequeued: AtomicUsize,
+    // Dequeued tasks that a worker has finished polling or dropping
+    handled: AtomicUsize,
+    // Tasks whose future has completed
+    completed: AtomicUsize,
+    // Set once `shutdown` has been called, after which no tasks are accepted
+    shutting_down: AtomicBool,
+    // Whether queued tasks are dropped instead of run during a shutdown
+    drop_queued: AtomicBool,
+    // Set once the workers have been told to stop
+    closed: AtomicBool,
+    // Worker threads that have stopped, and the futures waiting for them
+    exited: AtomicUsize,
+    exit_wakers: Mutex<Vec<Arc<AtomicWaker>>>,
+    threads: Mutex<Vec<thread::JoinHandle<()>>>,
+    // Runs the closures passed to `spawn_blocking`
+    blocking: Arc<BlockingPool>,
+    // Called with the payload of a task that panicked, if set
+    panic_handler: Option<Arc<PanicHandler>>,
+    on_task_start: Option<Arc<dyn Fn() + Send + Sync>>,
+    on_task_complete: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+/// A snapshot of the state of a [`ThreadPool`](ThreadPool), returned by
+/// [`ThreadPool::metrics`](ThreadPool::metrics).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Metrics {
+    queued: usize,
+    active: usize,
+    busy_workers: usize,
+    completed: usize,
+}
+
+impl Metrics {
+    /// The number of tasks that are ready to be polled but are waiting for a
+    /// worker thread, see [`ThreadPool::queued_count`](ThreadPool::queued_count).
+    pub fn queued(&self) -> usize {
+        self.queued
+    }
+
+    /// The number of tasks that have been spawned and haven't completed yet,
+    /// see [`ThreadPool::active_count`](ThreadPool::active_count).
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// The number of worker threads that are polling a task, or dropping one
+    /// during a [`ShutdownMode::DropQueued`](ShutdownMode::DropQueued)
+    /// shutdown.
+    pub fn busy_workers(&self) -> usize {
+        self.busy_workers
+    }
+
+    /// The number of tasks that have completed since the pool was created.
+    ///
+    /// This only ever grows. Tasks that were dropped by a
+    /// [`ShutdownMode::DropQueued`](ShutdownMode::DropQueued) shutdown don't
+    /// count, while tasks that panicked count if a
+    /// [`panic_handler`](ThreadPoolBuilder::panic_handler) caught the panic.
+    pub fn completed(&self) -> usize {
+        self.completed
+    }
+}
+
+// The tasks waiting for a worker thread
+struct Queue {
+    high: VecDeque<Task>,
+    normal: VecDeque<Task>,
+    // High priority tasks dequeued in a row while normal ones were waiting
+    high_streak: usize,
+    high_priority_ratio: usize,
+    // Set once the workers have been told to stop, which they do before
+    // taking any more tasks, whatever their priority
+    closed: bool,
+}
+
+impl Queue {
+    fn push(&mut self, task: Task) {
+        match task.wake_handle.priority {
+            Priority::High => self.high.push_back(task),
+            Priority::Normal => self.normal.push_back(task),
+        }
+    }
+
+    fn pop(&mut self) -> Option<Message> {
+        if self.closed {
+            return Some(Message::Close);
+        }
+        let task = if self.normal.is_empty() {
+            self.high_streak = 0;
+            self.high.pop_front()
+        } else if !self.high.is_empty() && self.high_streak < self.high_priority_ratio {
+            self.high_streak += 1;
+            self.high.pop_front()
+        } else {
+            self.high_streak = 0;
+            self.normal.pop_front()
+        };
+        task.map(Message::Run)
+    }
+
+    // Takes out all tasks that are left in the queue
+    fn drain(&mut self) -> Vec<Task> {
+        self.high.drain(..).chain(self.normal.drain(..)).collect()
+    }
+}
+
+/// The priority of a task spawned onto a [`ThreadPool`](ThreadPool) through
+/// [`spawn_obj_with_priority`](ThreadPool::spawn_obj_with_priority).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Priority {
+    /// The task is polled before any normal priority tasks that are waiting
+    /// for a worker thread, up to the ratio set with
+    /// [`high_priority_ratio`](ThreadPoolBuilder::high_priority_ratio).
+    High,
+    /// The priority of tasks spawned through [`Spawn`](futures_core::task::Spawn).
+    Normal,
+}
+
+impl Default for Priority {
+    fn default() -> Self {
+        Priority::Normal
+    }
+}
+
+/// Determines what happens to outstanding tasks when a
+/// [`ThreadPool`](ThreadPool) is [shut down](ThreadPool::shutdown).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShutdownMode {
+    /// Keep running tasks until every task spawned onto the pool has
+    /// completed, then stop the worker threads.
+    ///
+    /// This includes tasks that are waiting to be woken up, so a task that
+    /// never completes prevents the shutdown from completing as well.
+    Drain,
+
+    /// Stop the worker threads as soon as they have finished polling the
+    /// tasks they're currently polling.
+    ///
+    /// Tasks that are waiting for a worker thread are dropped without being
+    /// polled again.
+    DropQueued,
+}
+
+/// Future for the [`ThreadPool::shutdown`](ThreadPool::shutdown) method.
+#[must_use = "futures do nothing unless polled"]
+pub struct Shutdown {
+    state: Arc<PoolState>,
+    waker: Arc<AtomicWaker>,
 }
 
 impl fmt::Debug for ThreadPool {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("ThreadPool")
             .field("size", &self.state.size)
+            .field("active", &self.active_count())
+            .field("queued", &self.queued_count())
             .finish()
     }
 }
@@ -97,52 +269,372 @@ impl ThreadPool {
     pub fn run<F: Future>(&mut self, f: F) -> F::Output {
         crate::LocalPool::new().run_until(f)
     }
-}
 
-impl Spawn for ThreadPool {
-    fn spawn_obj(
+    /// Shuts down the thread pool, returning a future that completes once
+    /// all of its worker threads have stopped.
+    ///
+    /// The pool stops accepting tasks immediately: spawning onto this pool
+    /// or any of its clones returns an error from then on, including from
+    /// tasks that are still running on the pool. `mode` determines what
+    /// happens to the tasks that were already spawned, see
+    /// [`ShutdownMode`](ShutdownMode).
+    ///
+    /// Once the worker threads have stopped, they are joined by the returned
+    /// future, so [`before_stop`](ThreadPoolBuilder::before_stop) has run on
+    /// every worker by the time it completes. The future must not be awaited
+    /// by a task that's running on the pool itself, as it would wait for its
+    /// own worker thread.
+    ///
+    /// ```
+    /// use futures::executor::{block_on, ShutdownMode, ThreadPool};
+    /// use futures::future;
+    /// use futures::task::SpawnExt;
+    ///
+    /// let mut pool = ThreadPool::new().unwrap();
+    /// pool.spawn(future::lazy(|_| println!("Hello from the pool!"))).unwrap();
+    ///
+    /// let mut spawner = pool.clone();
+    /// block_on(pool.shutdown(ShutdownMode::Drain));
+    /// assert!(spawner.spawn(future::ready(())).is_err());
+    /// ```
+    pub fn shutdown(self, mode: ShutdownMode) -> Shutdown {
+        let state = self.state.clone();
+        let waker = Arc::new(AtomicWaker::new());
+        state.exit_wakers.lock().unwrap().push(waker.clone());
+
+        state.drop_queued.store(mode == ShutdownMode::DropQueued, Ordering::SeqCst);
+        state.shutting_down.store(true, Ordering::SeqCst);
+        if mode == ShutdownMode::DropQueued || state.is_idle() {
+            state.close();
+        }
+        Shutdown { state, waker }
+    }
+
+    /// Returns the number of tasks spawned onto this pool that haven't
+    /// completed yet.
+    ///
+    /// This includes tasks that are currently being polled, tasks waiting for
+    /// a worker thread to poll them and tasks waiting to be woken up.
+    ///
+    /// The count is derived from two counters read with relaxed atomic
+    /// loads, so it is only an approximate snapshot: it is not synchronized
+    /// with the tasks themselves and may already be out of date when it is
+    /// returned. A task is counted
+    /// from the moment it is spawned until just after its future has
+    /// completed, or until the task is dropped without completing, e.g.
+    /// because all of its wakers were dropped.
+    pub fn active_count(&self) -> usize {
+        self.state.active()
+    }
+
+    /// Returns the number of tasks that are ready to be polled but are waiting
+    /// for a worker thread to become available.
+    ///
+    /// A consistently non-zero value indicates that the pool has too few
+    /// worker threads for its load. Like
+    /// [`active_count`](ThreadPool::active_count), this is an approximate
+    /// snapshot read with relaxed ordering.
+    pub fn queued_count(&self) -> usize {
+        self.state.queued()
+    }
+
+    /// Returns a snapshot of the pool's task and worker counts.
+    ///
+    /// Like [`active_count`](ThreadPool::active_count), the counts are
+    /// derived from counters read with relaxed atomic loads, one after the
+    /// other, so they are only approximately consistent with each other.
+    ///
+    /// ```
+    /// use futures::executor::{block_on, ThreadPool};
+    /// use futures::future;
+    /// use futures::task::SpawnExt;
+    ///
+    /// let mut pool = ThreadPool::new().unwrap();
+    /// let handle = pool.spawn_with_handle(future::ready(())).unwrap();
+    /// block_on(handle);
+    ///
+    /// // The task is counted as completed just after its output was sent
+    /// let metrics = pool.metrics();
+    /// assert!(metrics.completed() <= 1);
+    /// ```
+    pub fn metrics(&self) -> Metrics {
+        Metrics {
+            queued: self.state.queued(),
+            active: self.state.active(),
+            busy_workers: self.state.busy_workers(),
+            completed: self.state.completed.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Spawns a task with the given priority.
+    ///
+    /// Whenever a worker thread becomes available, it polls a
+    /// [`High`](Priority::High) priority task that is ready to run before any
+    /// [`Normal`](Priority::Normal) priority ones. To keep a steady stream of
+    /// high priority tasks from starving the others, a normal priority task
+    /// is polled after every
+    /// [`high_priority_ratio`](ThreadPoolBuilder::high_priority_ratio) high
+    /// priority ones if there is one waiting. A task keeps its priority
+    /// whenever it's woken up.
+    ///
+    /// Spawning through [`Spawn`](futures_core::task::Spawn) uses
+    /// [`Normal`](Priority::Normal) priority.
+    pub fn spawn_obj_with_priority(
         &mut self,
         future: FutureObj<'static, ()>,
+        priority: Priority,
     ) -> Result<(), SpawnError> {
+        // Count the task before checking for a shutdown, so that a draining
+        // pool can't stop before this task has completed. If the task is
+        // rejected, dropping it releases its count again.
+        let future = TaskFuture::new(future, self.clone());
+        self.status()?;
         let task = Task {
             future,
             wake_handle: Arc::new(WakeHandle {
-                exec: self.clone(),
                 mutex: UnparkMutex::new(),
+                priority,
             }),
-            exec: self.clone(),
+            started: false,
         };
-        self.state.send(Message::Run(task));
+        self.state.send(task);
         Ok(())
     }
+
+    /// Spawns a task that polls the given future with the given priority.
+    ///
+    /// This is a convenience for
+    /// [`spawn_obj_with_priority`](ThreadPool::spawn_obj_with_priority) that
+    /// boxes the future.
+    ///
+    /// ```
+    /// use futures::executor::{Priority, ThreadPool};
+    /// use futures::future;
+    ///
+    /// let mut pool = ThreadPool::new().unwrap();
+    /// pool.spawn_with_priority(future::lazy(|_| println!("accepted")), Priority::High)
+    ///     .unwrap();
+    /// ```
+    pub fn spawn_with_priority<Fut>(
+        &mut self,
+        future: Fut,
+        priority: Priority,
+    ) -> Result<(), SpawnError>
+        where Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.spawn_obj_with_priority(FutureObj::new(Box::new(future)), priority)
+    }
+
+    /// Runs the blocking closure `f` on one of the pool's blocking threads
+    /// and returns a future that resolves to its result.
+    ///
+    /// This is meant for synchronous work, such as file IO or heavy
+    /// computations, which would otherwise keep a worker thread from polling
+    /// other tasks. The blocking threads are separate from the worker
+    /// threads. They are started as needed, up to
+    /// [`max_blocking_threads`](ThreadPoolBuilder::max_blocking_threads), and
+    /// exit after having been idle for a while. Beyond that limit, closures
+    /// wait in a queue for a blocking thread to become available.
+    ///
+    /// An error is returned if the pool is shutting down, or if no blocking
+    /// thread is running and a new one couldn't be started, in which case `f`
+    /// isn't run. Otherwise `f` always runs to completion, even if the pool
+    /// shuts down in the meantime: dropping the returned
+    /// [`RemoteHandle`](futures_util::future::RemoteHandle) detaches it and
+    /// discards its result. A panic in `f` is propagated to the task
+    /// awaiting the handle. A [`Shutdown`](Shutdown) doesn't wait for the
+    /// blocking threads.
+    ///
+    /// This takes precedence over
+    /// [`SpawnExt::spawn_blocking`](futures_util::task::SpawnExt::spawn_blocking),
+    /// which starts a new thread for every call.
+    ///
+    /// ```
+    /// use futures::executor::{block_on, ThreadPool};
+    ///
+    /// let mut pool = ThreadPool::builder().max_blocking_threads(2).create().unwrap();
+    /// let handles: Vec<_> = (1..=4)
+    ///     .map(|i| pool.spawn_blocking(move || i * 10).unwrap())
+    ///     .collect();
+    ///
+    /// let results: Vec<_> = handles.into_iter().map(block_on).collect();
+    /// assert_eq!(results, vec![10, 20, 30, 40]);
+    /// ```
+    pub fn spawn_blocking<F, R>(&mut self, f: F) -> Result<RemoteHandle<R>, SpawnError>
+        where F: FnOnce() -> R + Send + 'static,
+              R: Send + 'static,
+    {
+        self.status()?;
+        let (job, handle) = RemoteHandle::blocking(f);
+        self.state.blocking.spawn(job)?;
+        Ok(handle)
+    }
+}
+
+impl Spawn for ThreadPool {
+    fn spawn_obj(
+        &mut self,
+        future: FutureObj<'static, ()>,
+    ) -> Result<(), SpawnError> {
+        self.spawn_obj_with_priority(future, Priority::Normal)
+    }
+
+    fn status(&self) -> Result<(), SpawnError> {
+        if self.state.shutting_down.load(Ordering::SeqCst) {
+            Err(SpawnError::shutdown())
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl PoolState {
-    fn send(&self, msg: Message) {
-        self.tx.lock().unwrap().send(msg).unwrap();
+    fn send(&self, task: Task) {
+        let mut queue = self.queue.lock().unwrap();
+        if queue.closed {
+            // No worker would ever take the task, and it would keep the pool
+            // alive from within its own queue. Dropping it can lock the queue
+            // again, so release the lock first.
+            drop(queue);
+            drop(task);
+            return;
+        }
+        // Count the task before a worker can possibly receive it, so that the
+        // number of queued tasks never underflows.
+        self.enqueued.fetch_add(1, Ordering::Relaxed);
+        queue.push(task);
+        drop(queue);
+        self.queue_cv.notify_one();
+    }
+
+    // Waits for the next message for a worker thread
+    fn recv(&self) -> Message {
+        let mut queue = self.queue.lock().unwrap();
+        loop {
+            if let Some(msg) = queue.pop() {
+                return msg;
+            }
+            queue = self.queue_cv.wait(queue).unwrap();
+        }
+    }
+
+    // Tells every worker thread to stop, unless that has been done already
+    fn close(&self) {
+        if !self.closed.swap(true, Ordering::SeqCst) {
+            self.queue.lock().unwrap().closed = true;
+            self.queue_cv.notify_all();
+            self.blocking.close();
+        }
+    }
+
+    // Called once a task's future has completed, or has panicked and the
+    // panic was caught
+    fn task_finished(&self) {
+        self.completed.fetch_add(1, Ordering::Relaxed);
+        if let Some(ref on_task_complete) = self.on_task_complete {
+            on_task_complete();
+        }
+    }
+
+    // Called whenever a task's future is dropped, for whatever reason
+    fn task_released(&self) {
+        self.released.fetch_add(1, Ordering::SeqCst);
+        // The last task has completed while the pool is draining
+        if self.shutting_down.load(Ordering::SeqCst) && self.is_idle() {
+            self.close();
+        }
+    }
+
+    // Whether every task future that was created has been dropped again
+    fn is_idle(&self) -> bool {
+        let released = self.released.load(Ordering::SeqCst);
+        self.spawned.load(Ordering::SeqCst) == released
+    }
+
+    fn active(&self) -> usize {
+        gauge(&self.spawned, &self.released)
+    }
+
+    fn queued(&self) -> usize {
+        gauge(&self.enqueued, &self.dequeued)
+    }
+
+    fn busy_workers(&self) -> usize {
+        gauge(&self.dequeued, &self.handled)
     }
 
     fn work(&self,
             idx: usize,
             after_start: Option<Arc<dyn Fn(usize) + Send + Sync>>,
             before_stop: Option<Arc<dyn Fn(usize) + Send + Sync>>) {
+        // Counts the worker as exited even if a task panics on it
+        let _exit = ExitGuard(self);
         let _scope = enter().unwrap();
         if let Some(after_start) = after_start {
             after_start(idx);
         }
         loop {
-            let msg = self.rx.lock().unwrap().recv().unwrap();
+            let msg = self.recv();
             match msg {
-                Message::Run(task) => task.run(),
+                Message::Run(task) => {
+                    self.dequeued.fetch_add(1, Ordering::Relaxed);
+                    // Counts the task as handled even if it panics
+                    let _handled = IncrementOnDrop(&self.handled);
+                    if self.drop_queued.load(Ordering::Relaxed) {
+                        drop(task);
+                    } else {
+                        task.run()
+                    }
+                }
                 Message::Close => break,
             }
         }
+
+        // No worker takes the tasks that are still queued anymore, so drop
+        // them here. Dropping a task can lock the queue again, so this
+        // happens after the lock has been released.
+        let tasks = self.queue.lock().unwrap().drain();
+        let count = tasks.len();
+        if count > 0 {
+            self.dequeued.fetch_add(count, Ordering::Relaxed);
+            drop(tasks);
+            self.handled.fetch_add(count, Ordering::Relaxed);
+        }
+
         if let Some(before_stop) = before_stop {
             before_stop(idx);
         }
     }
 }
 
+struct ExitGuard<'a>(&'a PoolState);
+
+impl<'a> Drop for ExitGuard<'a> {
+    fn drop(&mut self) {
+        self.0.exited.fetch_add(1, Ordering::SeqCst);
+        for waker in self.0.exit_wakers.lock().unwrap().iter() {
+            waker.wake();
+        }
+    }
+}
+
+struct IncrementOnDrop<'a>(&'a AtomicUsize);
+
+impl<'a> Drop for IncrementOnDrop<'a> {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+// Derives the current value of a gauge from the counts of the events that
+// raise and lower it. The lowering count is read first so that a concurrent
+// update can't make the result underflow; should the loads still observe the
+// counts out of order, the result is clamped to zero.
+fn gauge(raised: &AtomicUsize, lowered: &AtomicUsize) -> usize {
+    let lowered = lowered.load(Ordering::Relaxed);
+    raised.load(Ordering::Relaxed).saturating_sub(lowered)
+}
+
 impl Clone for ThreadPool {
     fn clone(&self) -> ThreadPool {
         self.state.cnt.fetch_add(1, Ordering::Relaxed);
@@ -153,9 +645,7 @@ impl Clone for ThreadPool {
 impl Drop for ThreadPool {
     fn drop(&mut self) {
         if self.state.cnt.fetch_sub(1, Ordering::Relaxed) == 1 {
-            for _ in 0..self.state.size {
-                self.state.send(Message::Close);
-            }
+            self.state.close();
         }
     }
 }
@@ -171,6 +661,11 @@ impl ThreadPoolBuilder {
             name_prefix: None,
             after_start: None,
             before_stop: None,
+            panic_handler: None,
+            on_task_start: None,
+            on_task_complete: None,
+            high_priority_ratio: 8,
+            max_blocking_threads: 64,
         }
     }
 
@@ -195,6 +690,13 @@ impl ThreadPoolBuilder {
     ///
     /// Thread name prefix is used for generating thread names. For example, if prefix is
     /// `my-pool-`, then threads in the pool will get names like `my-pool-1` etc.
+    /// The number appended to the prefix is the index of the worker thread,
+    /// starting at `0`, which is the same index that is passed to the
+    /// [`after_start`](ThreadPoolBuilder::after_start) and
+    /// [`before_stop`](ThreadPoolBuilder::before_stop) hooks.
+    ///
+    /// Threads started for [`spawn_blocking`](ThreadPool::spawn_blocking) are
+    /// named `my-pool-blocking-0` etc.
     ///
     /// By default, worker threads are assigned Rust's standard thread name.
     pub fn name_prefix<S: Into<String>>(&mut self, name_prefix: S) -> &mut Self {
@@ -205,7 +707,10 @@ impl ThreadPoolBuilder {
     /// Execute the closure `f` immediately after each worker thread is started,
     /// but before running any tasks on it.
     ///
-    /// This hook is intended for bookkeeping and monitoring.
+    /// This hook is intended for bookkeeping and monitoring, such as setting
+    /// up thread-local state that the tasks rely on. The worker doesn't poll
+    /// its first task until `f` has returned, although other workers may
+    /// already be running tasks by then.
     /// The closure `f` will be dropped after the `builder` is dropped
     /// and all worker threads in the pool have executed it.
     ///
@@ -220,7 +725,11 @@ impl ThreadPoolBuilder {
 
     /// Execute closure `f` just prior to shutting down each worker thread.
     ///
-    /// This hook is intended for bookkeeping and monitoring.
+    /// This hook is intended for bookkeeping and monitoring. Worker threads
+    /// are shut down once the last handle to the pool is dropped, and `f` is
+    /// called after the worker has finished polling its last task, so no
+    /// task runs on that thread afterwards. Tasks that are still queued at
+    /// that point are dropped without being polled again.
     /// The closure `f` will be dropped after the `builder` is droppped
     /// and all threads in the pool have executed it.
     ///
@@ -233,19 +742,151 @@ impl ThreadPoolBuilder {
         self
     }
 
+    /// Execute closure `f` with the panic payload whenever a task panics
+    /// while it's being polled.
+    ///
+    /// The panic is caught, the task that panicked is dropped and counts as
+    /// completed, and the worker thread goes on to run other tasks. By
+    /// default, panics aren't caught, so a panicking task takes down the
+    /// worker thread it's running on, and the pool has one worker less
+    /// from then on.
+    ///
+    /// Like any caught panic, the panic is still reported by the panic hook
+    /// before `f` is called. `f` runs on the worker thread, so it should
+    /// return quickly.
+    ///
+    /// ```
+    /// use futures::executor::{block_on, ShutdownMode, ThreadPool};
+    /// use futures::future;
+    /// use futures::task::SpawnExt;
+    /// use std::sync::mpsc;
+    /// use std::sync::Mutex;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// let tx = Mutex::new(tx);
+    /// let mut pool = ThreadPool::builder()
+    ///     .pool_size(1)
+    ///     .panic_handler(move |payload| {
+    ///         let msg = *payload.downcast::<&str>().unwrap();
+    ///         tx.lock().unwrap().send(msg).unwrap();
+    ///     })
+    ///     .create()
+    ///     .unwrap();
+    ///
+    /// pool.spawn(future::lazy(|_| panic!("oh no"))).unwrap();
+    /// assert_eq!(rx.recv().unwrap(), "oh no");
+    ///
+    /// // The worker thread is still around to run the next task
+    /// let handle = pool.spawn_with_handle(future::ready(1)).unwrap();
+    /// assert_eq!(block_on(handle), 1);
+    /// block_on(pool.shutdown(ShutdownMode::Drain));
+    /// ```
+    pub fn panic_handler<F>(&mut self, f: F) -> &mut Self
+        where F: Fn(Box<dyn Any + Send>) + Send + Sync + 'static
+    {
+        self.panic_handler = Some(Arc::new(f));
+        self
+    }
+
+    /// Set how many [`High`](Priority::High) priority tasks are polled in a
+    /// row while [`Normal`](Priority::Normal) priority tasks are waiting for a
+    /// worker thread, before one of the latter is polled.
+    ///
+    /// This bounds how long a normal priority task can be held up by high
+    /// priority ones, see
+    /// [`spawn_obj_with_priority`](ThreadPool::spawn_obj_with_priority). By
+    /// default, this is `8`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio == 0`.
+    pub fn high_priority_ratio(&mut self, ratio: usize) -> &mut Self {
+        assert!(ratio > 0, "the high priority ratio must be non-zero");
+        self.high_priority_ratio = ratio;
+        self
+    }
+
+    /// Set the maximum number of threads that run the closures passed to
+    /// [`spawn_blocking`](ThreadPool::spawn_blocking).
+    ///
+    /// These threads are separate from the worker threads and are started
+    /// only as needed, up to this limit. Once the limit is reached, further
+    /// closures wait for a blocking thread to become available. A blocking
+    /// thread exits after it has been idle for 10 seconds. By default, the
+    /// limit is `64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max == 0`.
+    pub fn max_blocking_threads(&mut self, max: usize) -> &mut Self {
+        assert!(max > 0, "the maximum number of blocking threads must be non-zero");
+        self.max_blocking_threads = max;
+        self
+    }
+
+    /// Execute closure `f` on the worker thread whenever a task is about to
+    /// be polled for the first time.
+    ///
+    /// Together with [`on_task_complete`](ThreadPoolBuilder::on_task_complete),
+    /// this is intended for feeding external metrics systems. Since `f` runs
+    /// on the worker thread before the task, it should return quickly.
+    pub fn on_task_start<F>(&mut self, f: F) -> &mut Self
+        where F: Fn() + Send + Sync + 'static
+    {
+        self.on_task_start = Some(Arc::new(f));
+        self
+    }
+
+    /// Execute closure `f` on the worker thread whenever a task's future has
+    /// completed.
+    ///
+    /// This is called for exactly the tasks counted by
+    /// [`Metrics::completed`](Metrics::completed), right after the count has
+    /// been incremented.
+    pub fn on_task_complete<F>(&mut self, f: F) -> &mut Self
+        where F: Fn() + Send + Sync + 'static
+    {
+        self.on_task_complete = Some(Arc::new(f));
+        self
+    }
+
     /// Create a [`ThreadPool`](ThreadPool) with the given configuration.
     ///
     /// # Panics
     ///
     /// Panics if `pool_size == 0`.
     pub fn create(&mut self) -> Result<ThreadPool, io::Error> {
-        let (tx, rx) = mpsc::channel();
         let pool = ThreadPool {
             state: Arc::new(PoolState {
-                tx: Mutex::new(tx),
-                rx: Mutex::new(rx),
+                queue: Mutex::new(Queue {
+                    high: VecDeque::new(),
+                    normal: VecDeque::new(),
+                    high_streak: 0,
+                    high_priority_ratio: self.high_priority_ratio,
+                    closed: false,
+                }),
+                queue_cv: Condvar::new(),
                 cnt: AtomicUsize::new(1),
                 size: self.pool_size,
+                spawned: AtomicUsize::new(0),
+                released: AtomicUsize::new(0),
+                enqueued: AtomicUsize::new(0),
+                dequeued: AtomicUsize::new(0),
+                handled: AtomicUsize::new(0),
+                completed: AtomicUsize::new(0),
+                shutting_down: AtomicBool::new(false),
+                drop_queued: AtomicBool::new(false),
+                closed: AtomicBool::new(false),
+                exited: AtomicUsize::new(0),
+                exit_wakers: Mutex::new(Vec::new()),
+                threads: Mutex::new(Vec::new()),
+                blocking: Arc::new(BlockingPool::new(
+                    self.max_blocking_threads,
+                    self.name_prefix.clone(),
+                )),
+                panic_handler: self.panic_handler.clone(),
+                on_task_start: self.on_task_start.clone(),
+                on_task_complete: self.on_task_complete.clone(),
             }),
         };
         assert!(self.pool_size > 0);
@@ -261,7 +902,8 @@ impl ThreadPoolBuilder {
             if self.stack_size > 0 {
                 thread_builder = thread_builder.stack_size(self.stack_size);
             }
-            thread_builder.spawn(move || state.work(counter, after_start, before_stop))?;
+            let thread = thread_builder.spawn(move || state.work(counter, after_start, before_stop))?;
+            pool.state.threads.lock().unwrap().push(thread);
         }
         Ok(pool)
     }
@@ -275,45 +917,92 @@ impl Default for ThreadPoolBuilder {
 
 /// A task responsible for polling a future to completion.
 struct Task {
+    future: TaskFuture,
+    wake_handle: Arc<WakeHandle>,
+    // Whether the future has been polled before
+    started: bool,
+}
+
+// The future of a spawned task. The task is counted as active until this is
+// dropped, so it's also released when the future never completes, e.g. when
+// all of its wakers are dropped or the pool drops the task from its queue.
+struct TaskFuture {
     future: FutureObj<'static, ()>,
     exec: ThreadPool,
-    wake_handle: Arc<WakeHandle>,
 }
 
+// A task waiting to be woken up only holds its future, so that the task is
+// dropped along with the last of its wakers.
 struct WakeHandle {
-    mutex: UnparkMutex<Task>,
-    exec: ThreadPool,
+    mutex: UnparkMutex<TaskFuture>,
+    priority: Priority,
+}
+
+impl TaskFuture {
+    fn new(future: FutureObj<'static, ()>, exec: ThreadPool) -> TaskFuture {
+        exec.state.spawned.fetch_add(1, Ordering::SeqCst);
+        TaskFuture { future, exec }
+    }
+}
+
+impl Drop for TaskFuture {
+    fn drop(&mut self) {
+        self.exec.state.task_released();
+    }
 }
 
 impl Task {
     /// Actually run the task (invoking `poll` on the future) on the current
     /// thread.
     pub fn run(self) {
-        let Task { mut future, wake_handle, mut exec } = self;
+        let Task { mut future, wake_handle, started } = self;
+        let state = future.exec.state.clone();
         let local_waker = local_waker_ref_from_nonlocal(&wake_handle);
 
+        if !started {
+            if let Some(ref on_task_start) = state.on_task_start {
+                on_task_start();
+            }
+        }
+
         // Safety: The ownership of this `Task` object is evidence that
         // we are in the `POLLING`/`REPOLL` state for the mutex.
         unsafe {
             wake_handle.mutex.start_poll();
 
             loop {
-                let res = future.poll_unpin(&local_waker);
+                // If the future panics and the panic isn't caught, unwinding
+                // drops `future`, which releases the task
+                let res = match state.panic_handler {
+                    Some(ref panic_handler) => {
+                        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+                            future.future.poll_unpin(&local_waker)
+                        }));
+                        match res {
+                            Ok(res) => res,
+                            Err(payload) => {
+                                // The task is done for, but the worker
+                                // thread carries on
+                                state.task_finished();
+                                drop(future);
+                                wake_handle.mutex.complete();
+                                return panic_handler(payload);
+                            }
+                        }
+                    }
+                    None => future.future.poll_unpin(&local_waker),
+                };
                 match res {
                     Poll::Pending => {}
-                    Poll::Ready(()) => return wake_handle.mutex.complete(),
+                    Poll::Ready(()) => {
+                        state.task_finished();
+                        drop(future);
+                        return wake_handle.mutex.complete();
+                    }
                 }
-                let task = Task {
-                    future,
-                    wake_handle: wake_handle.clone(),
-                    exec,
-                };
-                match wake_handle.mutex.wait(task) {
+                match wake_handle.mutex.wait(future) {
                     Ok(()) => return, // we've waited
-                    Err(task) => { // someone's notified us
-                        future = task.future;
-                        exec = task.exec;
-                    }
+                    Err(f) => future = f, // someone's notified us
                 }
             }
         }
@@ -328,10 +1017,70 @@ impl fmt::Debug for Task {
     }
 }
 
+impl Shutdown {
+    /// Stops waiting for the outstanding tasks of a
+    /// [`Drain`](ShutdownMode::Drain) shutdown, continuing as if the pool had
+    /// been shut down with [`DropQueued`](ShutdownMode::DropQueued).
+    ///
+    /// This is meant for shutting down with a deadline: race this future
+    /// against a timer, and call this method if the timer fires first. The
+    /// tasks that are being polled right now still run until they return, so
+    /// the future has to be awaited afterwards for the worker threads to be
+    /// joined.
+    ///
+    /// ```
+    /// use futures::executor::{block_on, ShutdownMode, ThreadPool};
+    ///
+    /// let pool = ThreadPool::new().unwrap();
+    /// let shutdown = pool.shutdown(ShutdownMode::Drain);
+    ///
+    /// // The deadline passed before the tasks completed
+    /// shutdown.drop_queued();
+    /// block_on(shutdown);
+    /// ```
+    pub fn drop_queued(&self) {
+        self.state.drop_queued.store(true, Ordering::SeqCst);
+        self.state.close();
+    }
+}
+
+impl Future for Shutdown {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<()> {
+        self.waker.register(lw);
+        if self.state.exited.load(Ordering::SeqCst) < self.state.size {
+            return Poll::Pending;
+        }
+
+        // The workers have stopped, so this doesn't block for long
+        for thread in self.state.threads.lock().unwrap().drain(..) {
+            let _ = thread.join();
+        }
+        Poll::Ready(())
+    }
+}
+
+impl fmt::Debug for Shutdown {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Shutdown")
+            .field("size", &self.state.size)
+            .field("exited", &self.state.exited.load(Ordering::Relaxed))
+            .finish()
+    }
+}
+
 impl Wake for WakeHandle {
     fn wake(arc_self: &Arc<Self>) {
         match arc_self.mutex.notify() {
-            Ok(task) => arc_self.exec.state.send(Message::Run(task)),
+            Ok(future) => {
+                let state = future.exec.state.clone();
+                state.send(Task {
+                    future,
+                    wake_handle: arc_self.clone(),
+                    started: true,
+                });
+            }
             Err(()) => {}
         }
     }
@@ -340,7 +1089,33 @@ impl Wake for WakeHandle {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use futures_util::future::{lazy, poll_fn};
     use std::sync::mpsc;
+    use std::time::Duration;
+
+    struct SendOnDrop(mpsc::Sender<()>);
+
+    impl Drop for SendOnDrop {
+        fn drop(&mut self) {
+            let _ = self.0.send(());
+        }
+    }
+
+    // Waits for the shutdown to complete, giving up after a while instead of
+    // hanging the test
+    fn wait_for(shutdown: Shutdown) {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            crate::block_on(shutdown);
+            tx.send(()).unwrap();
+        });
+        rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    }
+
+    // Waits for the pool to finish its tasks and stop its worker threads
+    fn shut_down(pool: ThreadPool) {
+        wait_for(pool.shutdown(ShutdownMode::Drain));
+    }
 
     #[test]
     fn test_drop_after_start() {
@@ -354,4 +1129,370 @@ mod tests {
         let count = rx.into_iter().count();
         assert_eq!(count, 2);
     }
+
+    #[test]
+    fn test_hooks_run_around_tasks() {
+        let (tx, rx) = mpsc::channel();
+        let (start_tx, stop_tx) = (Mutex::new(tx.clone()), Mutex::new(tx.clone()));
+        let mut pool = ThreadPoolBuilder::new()
+            .pool_size(1)
+            .after_start(move |_| start_tx.lock().unwrap().send("start").unwrap())
+            .before_stop(move |_| stop_tx.lock().unwrap().send("stop").unwrap())
+            .create().unwrap();
+
+        let (done_tx, done_rx) = mpsc::channel();
+        pool.spawn_obj(Box::pinned(lazy(move |_| {
+            tx.send("task").unwrap();
+            done_tx.send(()).unwrap();
+        })).into()).unwrap();
+        done_rx.recv().unwrap();
+        drop(pool);
+
+        let events: Vec<_> = rx.into_iter().collect();
+        assert_eq!(events, vec!["start", "task", "stop"]);
+    }
+
+    #[test]
+    fn test_shutdown_drain() {
+        let mut pool = ThreadPoolBuilder::new().pool_size(1).create().unwrap();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let (tx, rx) = mpsc::channel();
+
+        pool.spawn_obj(Box::pinned(lazy(move |_| {
+            release_rx.recv().unwrap();
+        })).into()).unwrap();
+        pool.spawn_obj(Box::pinned(lazy(move |_| {
+            tx.send(()).unwrap();
+        })).into()).unwrap();
+
+        let mut spawner = pool.clone();
+        let shutdown = pool.shutdown(ShutdownMode::Drain);
+        assert!(spawner.spawn_obj(Box::pinned(lazy(|_| ())).into()).is_err());
+
+        release_tx.send(()).unwrap();
+        crate::block_on(shutdown);
+        assert_eq!(rx.try_recv(), Ok(()));
+    }
+
+    #[test]
+    fn test_shutdown_drop_queued() {
+        let mut pool = ThreadPoolBuilder::new().pool_size(1).create().unwrap();
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let (tx, rx) = mpsc::channel::<()>();
+
+        pool.spawn_obj(Box::pinned(lazy(move |_| {
+            started_tx.send(()).unwrap();
+            release_rx.recv().unwrap();
+        })).into()).unwrap();
+        started_rx.recv().unwrap();
+        pool.spawn_obj(Box::pinned(lazy(move |_| {
+            tx.send(()).unwrap();
+        })).into()).unwrap();
+
+        let shutdown = pool.shutdown(ShutdownMode::DropQueued);
+        release_tx.send(()).unwrap();
+        crate::block_on(shutdown);
+        assert_eq!(rx.try_recv(), Err(mpsc::TryRecvError::Disconnected));
+    }
+
+    #[test]
+    fn test_shutdown_drop_queued_high_priority() {
+        let mut pool = ThreadPoolBuilder::new()
+            .pool_size(1)
+            .high_priority_ratio(2)
+            .create().unwrap();
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let (dropped_tx, dropped_rx) = mpsc::channel();
+        let (ran_tx, ran_rx) = mpsc::channel::<()>();
+
+        pool.spawn_obj(Box::pinned(lazy(move |_| {
+            started_tx.send(()).unwrap();
+            release_rx.recv().unwrap();
+        })).into()).unwrap();
+        started_rx.recv().unwrap();
+
+        // More high priority tasks than are polled in a row before the
+        // workers would get to a normal priority message
+        for _ in 0..5 {
+            let guard = SendOnDrop(dropped_tx.clone());
+            let ran_tx = ran_tx.clone();
+            pool.spawn_with_priority(lazy(move |_| {
+                let _ = &guard;
+                ran_tx.send(()).unwrap();
+            }), Priority::High).unwrap();
+        }
+        drop((dropped_tx, ran_tx));
+
+        let shutdown = pool.clone().shutdown(ShutdownMode::DropQueued);
+        release_tx.send(()).unwrap();
+        wait_for(shutdown);
+
+        for _ in 0..5 {
+            dropped_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        }
+        assert_eq!(ran_rx.try_recv(), Err(mpsc::TryRecvError::Disconnected));
+        assert_eq!(pool.active_count(), 0);
+        assert_eq!(pool.queued_count(), 0);
+    }
+
+    #[test]
+    fn test_shutdown_drain_then_drop_queued() {
+        let mut pool = ThreadPoolBuilder::new().pool_size(1).create().unwrap();
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let (tx, rx) = mpsc::channel::<()>();
+
+        pool.spawn_obj(Box::pinned(lazy(move |_| {
+            started_tx.send(()).unwrap();
+            release_rx.recv().unwrap();
+        })).into()).unwrap();
+        started_rx.recv().unwrap();
+        pool.spawn_obj(Box::pinned(lazy(move |_| {
+            tx.send(()).unwrap();
+        })).into()).unwrap();
+
+        let mut spawner = pool.clone();
+        let shutdown = pool.shutdown(ShutdownMode::Drain);
+        let err = spawner.spawn_obj(Box::pinned(lazy(|_| ())).into()).unwrap_err();
+        assert!(err.is_shutdown());
+
+        // Give up on the queued task, the running one still completes
+        shutdown.drop_queued();
+        release_tx.send(()).unwrap();
+        crate::block_on(shutdown);
+        assert_eq!(rx.try_recv(), Err(mpsc::TryRecvError::Disconnected));
+    }
+
+    #[test]
+    fn test_task_woken_after_close_is_dropped() {
+        let mut pool = ThreadPoolBuilder::new().pool_size(1).create().unwrap();
+        let (waker_tx, waker_rx) = mpsc::channel();
+        let (dropped_tx, dropped_rx) = mpsc::channel();
+
+        let guard = SendOnDrop(dropped_tx);
+        let mut waker_tx = Some(waker_tx);
+        pool.spawn_obj(Box::pinned(poll_fn(move |lw| {
+            let _ = &guard;
+            if let Some(waker_tx) = waker_tx.take() {
+                waker_tx.send(lw.clone().into_waker()).unwrap();
+            }
+            Poll::Pending
+        })).into()).unwrap();
+        let waker = waker_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+
+        wait_for(pool.clone().shutdown(ShutdownMode::DropQueued));
+
+        // No worker is left to run the task, so waking it drops it
+        waker.wake();
+        dropped_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(pool.active_count(), 0);
+        assert_eq!(pool.queued_count(), 0);
+    }
+
+    #[test]
+    fn test_shutdown_after_unhandled_panic() {
+        let mut pool = ThreadPoolBuilder::new().pool_size(1).create().unwrap();
+        pool.spawn_obj(Box::pinned(lazy(|_| panic!("unhandled"))).into()).unwrap();
+
+        // The panic takes down the only worker thread, which still has to
+        // count as stopped for the shutdown to complete
+        shut_down(pool);
+    }
+
+    #[test]
+    fn test_name_prefix() {
+        let (tx, rx) = mpsc::sync_channel(2);
+        let _cpu_pool = ThreadPoolBuilder::new()
+            .pool_size(2)
+            .name_prefix("my-pool-")
+            .after_start(move |idx| {
+                let name = thread::current().name().map(str::to_owned);
+                tx.send((idx, name)).unwrap();
+            })
+            .create().unwrap();
+
+        let mut names: Vec<_> = rx.into_iter().collect();
+        names.sort();
+        assert_eq!(names, vec![
+            (0, Some("my-pool-0".to_owned())),
+            (1, Some("my-pool-1".to_owned())),
+        ]);
+    }
+
+    #[test]
+    fn test_task_counts() {
+        let mut pool = ThreadPoolBuilder::new().pool_size(1).create().unwrap();
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let (done_tx, done_rx) = mpsc::channel();
+
+        // Occupy the only worker thread until we release it
+        pool.spawn_obj(Box::pinned(lazy(move |_| {
+            started_tx.send(()).unwrap();
+            release_rx.recv().unwrap();
+        })).into()).unwrap();
+        started_rx.recv().unwrap();
+
+        pool.spawn_obj(Box::pinned(lazy(move |_| {
+            done_tx.send(()).unwrap();
+        })).into()).unwrap();
+        assert_eq!(pool.active_count(), 2);
+        assert_eq!(pool.queued_count(), 1);
+
+        release_tx.send(()).unwrap();
+        done_rx.recv().unwrap();
+        assert_eq!(pool.queued_count(), 0);
+        // The task is only released once its future has returned, which a
+        // draining shutdown waits for
+        shut_down(pool.clone());
+        assert_eq!(pool.active_count(), 0);
+    }
+
+    #[test]
+    fn test_dropped_task_is_released() {
+        let mut pool = ThreadPoolBuilder::new().pool_size(1).create().unwrap();
+        let (tx, rx) = mpsc::channel();
+
+        // The task never stores its waker, so nothing can ever wake it up
+        let guard = SendOnDrop(tx);
+        pool.spawn_obj(Box::pinned(poll_fn(move |_| {
+            let _ = &guard;
+            Poll::Pending
+        })).into()).unwrap();
+
+        rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(pool.active_count(), 0);
+    }
+
+    #[test]
+    fn test_panic_handler() {
+        let (panic_tx, panic_rx) = mpsc::channel();
+        let panic_tx = Mutex::new(panic_tx);
+        let mut pool = ThreadPoolBuilder::new()
+            .pool_size(1)
+            .panic_handler(move |payload| {
+                let msg = *payload.downcast::<&str>().unwrap();
+                panic_tx.lock().unwrap().send(msg).unwrap();
+            })
+            .create().unwrap();
+        let (done_tx, done_rx) = mpsc::channel();
+
+        pool.spawn_obj(Box::pinned(lazy(|_| panic!("first"))).into()).unwrap();
+        pool.spawn_obj(Box::pinned(lazy(|_| panic!("second"))).into()).unwrap();
+        pool.spawn_obj(Box::pinned(lazy(move |_| {
+            done_tx.send(()).unwrap();
+        })).into()).unwrap();
+
+        // The only worker thread survives both panics
+        assert_eq!(panic_rx.recv().unwrap(), "first");
+        assert_eq!(panic_rx.recv().unwrap(), "second");
+        done_rx.recv().unwrap();
+        shut_down(pool.clone());
+        assert_eq!(pool.active_count(), 0);
+    }
+
+    #[test]
+    fn test_metrics_and_hooks() {
+        let started = Arc::new(AtomicUsize::new(0));
+        let completed = Arc::new(AtomicUsize::new(0));
+        let (started2, completed2) = (started.clone(), completed.clone());
+        let mut pool = ThreadPoolBuilder::new()
+            .pool_size(1)
+            .on_task_start(move || { started2.fetch_add(1, Ordering::SeqCst); })
+            .on_task_complete(move || { completed2.fetch_add(1, Ordering::SeqCst); })
+            .create().unwrap();
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+
+        // Occupy the only worker thread until we release it
+        pool.spawn_obj(Box::pinned(lazy(move |_| {
+            started_tx.send(()).unwrap();
+            release_rx.recv().unwrap();
+        })).into()).unwrap();
+        started_rx.recv().unwrap();
+        pool.spawn_obj(Box::pinned(lazy(|_| ())).into()).unwrap();
+
+        let metrics = pool.metrics();
+        assert_eq!(metrics.busy_workers(), 1);
+        assert_eq!(metrics.queued(), 1);
+        assert_eq!(metrics.active(), 2);
+        assert_eq!(metrics.completed(), 0);
+        assert_eq!(started.load(Ordering::SeqCst), 1);
+
+        release_tx.send(()).unwrap();
+        shut_down(pool.clone());
+        let metrics = pool.metrics();
+        assert_eq!(metrics.busy_workers(), 0);
+        assert_eq!(metrics.queued(), 0);
+        assert_eq!(metrics.active(), 0);
+        assert_eq!(metrics.completed(), 2);
+        assert_eq!(started.load(Ordering::SeqCst), 2);
+        assert_eq!(completed.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_spawn_blocking_is_bounded() {
+        let mut pool = ThreadPoolBuilder::new()
+            .pool_size(1)
+            .max_blocking_threads(1)
+            .create().unwrap();
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+
+        let started_tx2 = started_tx.clone();
+        let first = pool.spawn_blocking(move || {
+            started_tx.send(1).unwrap();
+            release_rx.recv().unwrap();
+        }).unwrap();
+        let second = pool.spawn_blocking(move || {
+            started_tx2.send(2).unwrap();
+        }).unwrap();
+        assert_eq!(started_rx.recv_timeout(Duration::from_secs(5)), Ok(1));
+
+        // The only blocking thread is busy, so the second closure waits
+        assert!(started_rx.recv_timeout(Duration::from_millis(50)).is_err());
+        release_tx.send(()).unwrap();
+        assert_eq!(started_rx.recv_timeout(Duration::from_secs(5)), Ok(2));
+        crate::block_on(first);
+        crate::block_on(second);
+    }
+
+    #[test]
+    fn test_priority_order() {
+        let mut pool = ThreadPoolBuilder::new()
+            .pool_size(1)
+            .high_priority_ratio(2)
+            .create().unwrap();
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let (tx, rx) = mpsc::channel();
+
+        // Occupy the only worker thread until everything has been queued
+        pool.spawn_obj(Box::pinned(lazy(move |_| {
+            started_tx.send(()).unwrap();
+            release_rx.recv().unwrap();
+        })).into()).unwrap();
+        started_rx.recv().unwrap();
+
+        let tasks = vec![
+            ("N1", Priority::Normal),
+            ("N2", Priority::Normal),
+            ("H1", Priority::High),
+            ("H2", Priority::High),
+            ("H3", Priority::High),
+        ];
+        for (label, priority) in tasks {
+            let tx = tx.clone();
+            pool.spawn_with_priority(lazy(move |_| tx.send(label).unwrap()), priority)
+                .unwrap();
+        }
+        drop(tx);
+
+        // At most two high priority tasks run before a normal one gets a turn
+        release_tx.send(()).unwrap();
+        let order: Vec<_> = rx.into_iter().collect();
+        assert_eq!(order, vec!["H1", "H2", "N1", "H3", "N2"]);
+    }
 }
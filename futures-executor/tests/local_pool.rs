@@ -1,12 +1,15 @@
 #![feature(pin, arbitrary_self_types, futures_api)]
 
-use futures::channel::oneshot;
-use futures::executor::LocalPool;
-use futures::future::{Future, lazy};
-use futures::task::{LocalWaker, Poll, Spawn, LocalSpawn};
+use futures::channel::{mpsc, oneshot};
+use futures::executor::{LocalPool, LocalScope, RunOne, Stalled};
+use futures::future::{self, Future, FutureExt, lazy};
+use futures::stream::{self, StreamExt};
+use futures::task::{LocalWaker, Poll, Spawn, LocalSpawn, LocalSpawnExt};
 use std::cell::{Cell, RefCell};
 use std::pin::Pin;
 use std::rc::Rc;
+use std::thread;
+use std::time::{Duration, Instant};
 
 struct Pending(Rc<()>);
 
@@ -168,3 +171,221 @@ fn tasks_are_scheduled_fairly() {
     pool.run();
 }
 
+#[test]
+fn scope_runs_borrowing_tasks() {
+    let cnt = Cell::new(0);
+    let mut pool = LocalPool::new();
+    let scope = LocalScope::new();
+    let mut spawn = scope.spawner();
+
+    for _ in 0..3 {
+        let mut inner_spawn = spawn.clone();
+        let cnt = &cnt;
+        spawn.spawn_local_scoped(lazy(move |_| {
+            cnt.set(cnt.get() + 1);
+            inner_spawn.spawn_local_scoped(lazy(move |_| {
+                cnt.set(cnt.get() + 10);
+            })).unwrap();
+        })).unwrap();
+    }
+
+    pool.run_until(scope);
+    assert_eq!(cnt.get(), 33);
+}
+
+#[test]
+fn scope_drops_tasks_when_dropped() {
+    let dropped = Rc::new(());
+    let mut pool = LocalPool::new();
+    let scope = LocalScope::new();
+    let mut spawn = scope.spawner();
+
+    let pending = Pending(dropped.clone());
+    spawn.spawn_local_scoped(pending).unwrap();
+    assert_eq!(Rc::strong_count(&dropped), 2);
+
+    // The task never completes, so only poll the scope until `run_until`
+    // returns for another reason.
+    pool.run_until(lazy(|lw| {
+        let mut scope = scope;
+        assert!(Pin::new(&mut scope).poll(lw).is_pending());
+    }));
+    assert_eq!(Rc::strong_count(&dropped), 1);
+    assert!(spawn.spawn_local_scoped(lazy(|_| ())).is_err());
+}
+
+#[test]
+fn spawn_local_blocking_runs_inline() {
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+    let thread = std::thread::current().id();
+
+    let handle = spawn.spawn_local_blocking(move || {
+        std::thread::current().id() == thread
+    }).unwrap();
+    assert!(pool.run_until(handle));
+}
+
+#[test]
+fn try_run_one_returns_after_each_task() {
+    let cnt = Rc::new(Cell::new(0));
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+
+    for _ in 0..2 {
+        let cnt = cnt.clone();
+        spawn.spawn_local_obj(Box::pinned(lazy(move |_| {
+            cnt.set(cnt.get() + 1);
+        })).into()).unwrap();
+    }
+
+    assert!(pool.try_run_one());
+    assert_eq!(cnt.get(), 1);
+    assert!(pool.try_run_one());
+    assert_eq!(cnt.get(), 2);
+    assert!(!pool.try_run_one());
+}
+
+#[test]
+fn try_run_one_polls_a_single_task() {
+    let cnt = Rc::new(Cell::new(0));
+    let (_tx, rx) = oneshot::channel::<()>();
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+    spawn.spawn_local_obj(Box::pinned(rx.map(|_| ())).into()).unwrap();
+    let cnt2 = cnt.clone();
+    spawn.spawn_local_obj(Box::pinned(lazy(move |_| cnt2.set(1))).into()).unwrap();
+
+    // Only the receiver is polled, even though the other task is ready
+    assert!(pool.try_run_one());
+    assert_eq!(cnt.get(), 0);
+    assert!(pool.try_run_one());
+    assert_eq!(cnt.get(), 1);
+}
+
+#[test]
+fn try_run_one_repolls_woken_tasks() {
+    let (tx, rx) = oneshot::channel();
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+    spawn.spawn_local_obj(Box::pinned(rx.map(|_| ())).into()).unwrap();
+
+    assert!(pool.try_run_one());
+    assert!(!pool.try_run_one());
+    tx.send(()).unwrap();
+    assert!(pool.try_run_one());
+    assert!(!pool.try_run_one());
+}
+
+#[test]
+fn try_run_one_unparks_thread_on_wakeup() {
+    let (tx, rx) = oneshot::channel();
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+    spawn.spawn_local_obj(Box::pinned(rx.map(|_| ())).into()).unwrap();
+
+    assert!(pool.try_run_one());
+    assert!(!pool.try_run_one());
+
+    // Waking the task from another thread unparks this one, so parking
+    // returns right away instead of waiting for the timeout
+    thread::spawn(move || tx.send(()).unwrap()).join().unwrap();
+    let start = Instant::now();
+    thread::park_timeout(Duration::from_secs(5));
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert!(pool.try_run_one());
+}
+
+#[test]
+fn run_until_stalled_runs_chains_of_wakeups() {
+    let (tx1, rx1) = oneshot::channel();
+    let (tx2, rx2) = oneshot::channel();
+    let done = Rc::new(Cell::new(false));
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+
+    let done2 = done.clone();
+    spawn.spawn_local_obj(Box::pinned(rx2.map(move |_| done2.set(true))).into()).unwrap();
+    spawn.spawn_local_obj(Box::pinned(rx1.map(move |_| tx2.send(()).unwrap())).into()).unwrap();
+    spawn.spawn_local_obj(Box::pinned(lazy(move |_| tx1.send(()).unwrap())).into()).unwrap();
+
+    pool.run_until_stalled();
+    assert!(done.get());
+}
+
+#[test]
+fn run_until_stalled_returns_for_self_waking_tasks() {
+    struct Spin(Rc<Cell<usize>>);
+
+    impl Future for Spin {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<()> {
+            self.0.set(self.0.get() + 1);
+            lw.wake();
+            Poll::Pending
+        }
+    }
+
+    let polls = Rc::new(Cell::new(0));
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+    spawn.spawn_local_obj(Box::pinned(Spin(polls.clone())).into()).unwrap();
+
+    pool.run_until_stalled();
+    let first = polls.get();
+    assert!(first > 0);
+
+    // The task is still in the pool and gets polled again
+    pool.run_until_stalled();
+    assert!(polls.get() > first);
+}
+
+#[test]
+fn run_until_stalled_runs_tasks_exchanging_messages() {
+    let (tx, rx) = mpsc::channel(0);
+    let sum = Rc::new(Cell::new(0));
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+
+    // Neither task completes before all messages have been passed over the
+    // channel, but they keep waking each other up
+    let sending = stream::iter(0..100).map(Ok).forward(tx);
+    spawn.spawn_local_obj(Box::pinned(sending.map(|_| ())).into()).unwrap();
+    let sum2 = sum.clone();
+    let receiving = rx.fold(0, |acc, x| future::ready(acc + x));
+    spawn.spawn_local_obj(Box::pinned(receiving.map(move |s| sum2.set(s))).into()).unwrap();
+
+    assert_eq!(pool.run_until_stalled(), Stalled::Complete);
+    assert_eq!(sum.get(), 4950);
+}
+
+#[test]
+fn run_until_stalled_reports_remaining_tasks() {
+    let mut pool = LocalPool::new();
+    assert_eq!(pool.run_until_stalled(), Stalled::Complete);
+
+    let mut spawn = pool.spawner();
+    let (tx, rx) = oneshot::channel();
+    spawn.spawn_local_obj(Box::pinned(Pending(Rc::new(()))).into()).unwrap();
+    spawn.spawn_local_obj(Box::pinned(rx.map(|_| ())).into()).unwrap();
+    assert_eq!(pool.run_until_stalled(), Stalled::Pending { tasks: 2 });
+
+    tx.send(()).unwrap();
+    assert_eq!(pool.run_until_stalled(), Stalled::Pending { tasks: 1 });
+}
+
+#[test]
+fn try_run_one_step_polls_one_task_at_a_time() {
+    let (tx, rx) = oneshot::channel();
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+    spawn.spawn_local_obj(Box::pinned(rx.map(|_| ())).into()).unwrap();
+    spawn.spawn_local_obj(Box::pinned(lazy(move |_| tx.send(()).unwrap())).into()).unwrap();
+
+    // The receiver is polled first and has to wait for the sender
+    assert_eq!(pool.try_run_one_step(), RunOne::Progressed);
+    assert_eq!(pool.try_run_one_step(), RunOne::Completed);
+    assert_eq!(pool.try_run_one_step(), RunOne::Completed);
+    assert_eq!(pool.try_run_one_step(), RunOne::Stalled);
+}
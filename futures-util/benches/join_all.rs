@@ -0,0 +1,62 @@
+#![feature(test, futures_api, pin)]
+
+extern crate test;
+use crate::test::Bencher;
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, join_all, maybe_done, Future, FutureExt, MaybeDone};
+use futures::task::Poll;
+use std::pin::Pin;
+
+const NUM: usize = 10_000;
+
+// Completes the futures one at a time, polling the join after each one, so
+// that most of the futures are idle whenever the join is polled.
+#[bench]
+fn join_all_mostly_idle(b: &mut Bencher) {
+    b.iter(|| {
+        let (mut txs, rxs): (Vec<_>, Vec<_>) =
+            (0..NUM).map(|_| oneshot::channel::<()>()).unzip();
+        let mut join = join_all(rxs);
+
+        block_on(future::poll_fn(move |lw| {
+            for tx in txs.drain(..) {
+                assert!(join.poll_unpin(lw).is_pending());
+                tx.send(()).unwrap();
+            }
+            assert!(join.poll_unpin(lw).is_ready());
+            Poll::Ready(())
+        }))
+    });
+}
+
+// The same workload, polling every unfinished future on every wakeup like
+// `join_all` used to do for any number of futures.
+#[bench]
+fn poll_every_future_mostly_idle(b: &mut Bencher) {
+    b.iter(|| {
+        let (mut txs, rxs): (Vec<_>, Vec<_>) =
+            (0..NUM).map(|_| oneshot::channel::<()>()).unzip();
+        let mut elems: Vec<_> = rxs.into_iter().map(maybe_done).collect();
+
+        block_on(future::poll_fn(move |lw| {
+            let poll_all = |elems: &mut Vec<MaybeDone<oneshot::Receiver<()>>>| {
+                let mut all_done = true;
+                for elem in elems {
+                    if Pin::new(elem).poll(lw).is_pending() {
+                        all_done = false;
+                    }
+                }
+                all_done
+            };
+
+            for tx in txs.drain(..) {
+                assert!(!poll_all(&mut elems));
+                tx.send(()).unwrap();
+            }
+            assert!(poll_all(&mut elems));
+            Poll::Ready(())
+        }))
+    });
+}
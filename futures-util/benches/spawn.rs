@@ -0,0 +1,72 @@
+#![feature(test, futures_api, pin)]
+
+extern crate test;
+use crate::test::Bencher;
+
+use futures::executor::LocalPool;
+use futures::future::{self, Future, FutureObj};
+use futures::task::{Spawn, SpawnExt};
+use std::pin::Pin;
+
+const NUM: usize = 10_000;
+
+// Spawns futures that aren't boxed yet, which `spawn` boxes once.
+#[bench]
+fn spawn_unboxed(b: &mut Bencher) {
+    let mut pool = LocalPool::new();
+    let mut spawner = pool.spawner();
+
+    b.iter(|| {
+        for _ in 0..NUM {
+            spawner.spawn(future::ready(())).unwrap();
+        }
+        pool.run_until_stalled();
+    });
+}
+
+// Spawns already boxed futures of a concrete type through `spawn`, which
+// boxes them a second time.
+#[bench]
+fn spawn_boxed(b: &mut Bencher) {
+    let mut pool = LocalPool::new();
+    let mut spawner = pool.spawner();
+
+    b.iter(|| {
+        for _ in 0..NUM {
+            spawner.spawn(Box::pinned(future::ready(()))).unwrap();
+        }
+        pool.run_until_stalled();
+    });
+}
+
+// Spawns the same boxed futures through `FutureObj::from`, which reuses
+// their allocation.
+#[bench]
+fn spawn_boxed_future_obj(b: &mut Bencher) {
+    let mut pool = LocalPool::new();
+    let mut spawner = pool.spawner();
+
+    b.iter(|| {
+        for _ in 0..NUM {
+            let future = Box::pinned(future::ready(()));
+            spawner.spawn_obj(FutureObj::from(future)).unwrap();
+        }
+        pool.run_until_stalled();
+    });
+}
+
+// Spawns boxed trait objects, which are boxed a second time either way.
+#[bench]
+fn spawn_boxed_dyn(b: &mut Bencher) {
+    let mut pool = LocalPool::new();
+    let mut spawner = pool.spawner();
+
+    b.iter(|| {
+        for _ in 0..NUM {
+            let future: Pin<Box<dyn Future<Output = ()> + Send>> =
+                Box::pinned(future::ready(()));
+            spawner.spawn(future).unwrap();
+        }
+        pool.run_until_stalled();
+    });
+}
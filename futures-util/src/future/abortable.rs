@@ -4,17 +4,31 @@ use futures_core::task::{LocalWaker, Poll};
 use pin_utils::unsafe_pinned;
 use std::marker::Unpin;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::prelude::v1::*;
+use std::sync::{Arc, Mutex, Weak};
 use std::sync::atomic::{AtomicBool, Ordering};
 
 /// A future which can be remotely short-circuited using an `AbortHandle`.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 #[must_use = "futures do nothing unless polled"]
 pub struct Abortable<Fut> {
     future: Fut,
+    waker: Arc<AtomicWaker>,
     inner: Arc<AbortInner>,
 }
 
+impl<Fut: Clone> Clone for Abortable<Fut> {
+    fn clone(&self) -> Self {
+        // The clone may be polled by a different task, so it needs to be
+        // woken separately
+        Abortable {
+            future: self.future.clone(),
+            waker: self.inner.register(),
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 impl<Fut: Unpin> Unpin for Abortable<Fut> {}
 
 impl<Fut> Abortable<Fut> where Fut: Future {
@@ -27,6 +41,9 @@ impl<Fut> Abortable<Fut> where Fut: Future {
     /// already been called, the future will complete immediately without making
     /// any further progress.
     ///
+    /// A registration can be cloned to tie several futures to the same
+    /// handle, so that all of them are aborted together.
+    ///
     /// Example:
     ///
     /// ```
@@ -41,6 +58,7 @@ impl<Fut> Abortable<Fut> where Fut: Future {
     pub fn new(future: Fut, reg: AbortRegistration) -> Self {
         Abortable {
             future,
+            waker: reg.inner.register(),
             inner: reg.inner,
         }
     }
@@ -49,7 +67,10 @@ impl<Fut> Abortable<Fut> where Fut: Future {
 /// A registration handle for a `Abortable` future.
 /// Values of this type can be acquired from `AbortHandle::new` and are used
 /// in calls to `Abortable::new`.
-#[derive(Debug)]
+///
+/// Cloning a registration ties the futures created from the clones to the
+/// same `AbortHandle`.
+#[derive(Debug, Clone)]
 pub struct AbortRegistration {
     inner: Arc<AbortInner>,
 }
@@ -78,7 +99,7 @@ impl AbortHandle {
     /// assert_eq!(block_on(future), Err(Aborted));
     pub fn new_pair() -> (Self, AbortRegistration) {
         let inner = Arc::new(AbortInner {
-            waker: AtomicWaker::new(),
+            wakers: Mutex::new(Vec::new()),
             cancel: AtomicBool::new(false),
         });
 
@@ -93,14 +114,26 @@ impl AbortHandle {
     }
 }
 
-// Inner type storing the waker to awaken and a bool indicating that it
-// should be cancelled.
+// Inner type storing the wakers of the registered futures and a bool
+// indicating that they should be cancelled.
 #[derive(Debug)]
 struct AbortInner {
-    waker: AtomicWaker,
+    wakers: Mutex<Vec<Weak<AtomicWaker>>>,
     cancel: AtomicBool,
 }
 
+impl AbortInner {
+    // Adds a waker for a new `Abortable` future
+    fn register(&self) -> Arc<AtomicWaker> {
+        let waker = Arc::new(AtomicWaker::new());
+        let mut wakers = self.wakers.lock().unwrap();
+        // Forget the futures which have been dropped in the meantime
+        wakers.retain(|waker| waker.upgrade().is_some());
+        wakers.push(Arc::downgrade(&waker));
+        waker
+    }
+}
+
 /// Creates a new `Abortable` future and a `AbortHandle` which can be used to stop it.
 ///
 /// This function is a convenient (but less flexible) alternative to calling
@@ -134,7 +167,7 @@ impl<Fut> Future for Abortable<Fut> where Fut: Future {
         }
 
         // Register to receive a wakeup if the future is aborted in the... future
-        self.inner.waker.register(lw);
+        self.waker.register(lw);
 
         // Check to see if the future was aborted between the first check and
         // registration.
@@ -157,6 +190,67 @@ impl AbortHandle {
     /// continue to run until its poll method returns.
     pub fn abort(&self) {
         self.inner.cancel.store(true, Ordering::Relaxed);
-        self.inner.waker.wake();
+        // Collect the wakers first, since waking a task may run code which
+        // aborts again
+        let wakers: Vec<_> = self.inner.wakers.lock().unwrap()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect();
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    /// Returns a guard which aborts the futures associated with this handle
+    /// when it's dropped.
+    ///
+    /// This ties the futures to a scope: they are aborted once the guard goes
+    /// out of scope, including during unwinding, unless the guard has been
+    /// disarmed with [`DropGuard::disarm`] before.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use futures::future::{empty, Abortable, AbortHandle, Aborted};
+    /// use futures::executor::block_on;
+    ///
+    /// let (abort_handle, abort_registration) = AbortHandle::new_pair();
+    /// let first = Abortable::new(empty::<()>(), abort_registration.clone());
+    /// let second = Abortable::new(empty::<()>(), abort_registration);
+    ///
+    /// {
+    ///     let _guard = abort_handle.abort_on_drop_guard();
+    /// }
+    /// assert_eq!(block_on(first), Err(Aborted));
+    /// assert_eq!(block_on(second), Err(Aborted));
+    /// ```
+    pub fn abort_on_drop_guard(self) -> DropGuard {
+        DropGuard { handle: Some(self) }
+    }
+}
+
+/// A guard which aborts the futures associated with an `AbortHandle` when
+/// it's dropped.
+///
+/// This is created by the `AbortHandle::abort_on_drop_guard` method.
+#[derive(Debug)]
+#[must_use = "the futures are aborted as soon as the guard is dropped"]
+pub struct DropGuard {
+    handle: Option<AbortHandle>,
+}
+
+impl DropGuard {
+    /// Disarms the guard, so that the futures aren't aborted, and returns
+    /// the handle it was created from.
+    pub fn disarm(mut self) -> AbortHandle {
+        self.handle.take().unwrap()
+    }
+}
+
+impl Drop for DropGuard {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
     }
 }
@@ -1,6 +1,6 @@
 use futures_core::future::Future;
 use futures_core::task::{LocalWaker, Poll};
-use pin_utils::unsafe_pinned;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
 use std::any::Any;
 use std::pin::Pin;
 use std::panic::{catch_unwind, UnwindSafe, AssertUnwindSafe};
@@ -13,13 +13,15 @@ use std::prelude::v1::*;
 #[must_use = "futures do nothing unless polled"]
 pub struct CatchUnwind<Fut> where Fut: Future {
     future: Fut,
+    panicked: bool,
 }
 
 impl<Fut> CatchUnwind<Fut> where Fut: Future + UnwindSafe {
     unsafe_pinned!(future: Fut);
+    unsafe_unpinned!(panicked: bool);
 
     pub(super) fn new(future: Fut) -> CatchUnwind<Fut> {
-        CatchUnwind { future }
+        CatchUnwind { future, panicked: false }
     }
 }
 
@@ -29,9 +31,17 @@ impl<Fut> Future for CatchUnwind<Fut>
     type Output = Result<Fut::Output, Box<dyn Any + Send>>;
 
     fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Self::Output> {
+        // The future may be left in an inconsistent state by the panic
+        if self.panicked {
+            panic!("`CatchUnwind` polled after a panic was caught");
+        }
+
         match catch_unwind(AssertUnwindSafe(|| self.future().poll(lw))) {
             Ok(res) => res.map(Ok),
-            Err(e) => Poll::Ready(Err(e))
+            Err(e) => {
+                *self.panicked() = true;
+                Poll::Ready(Err(e))
+            }
         }
     }
 }
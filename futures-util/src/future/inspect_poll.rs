@@ -0,0 +1,39 @@
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Do something with the result of every poll of a future.
+///
+/// This is created by the [`super::FutureExt::inspect_poll`] method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct InspectPoll<Fut, F> {
+    future: Fut,
+    f: F,
+}
+
+impl<Fut: Future, F: FnMut(&Poll<Fut::Output>)> InspectPoll<Fut, F> {
+    unsafe_pinned!(future: Fut);
+    unsafe_unpinned!(f: F);
+
+    pub(super) fn new(future: Fut, f: F) -> InspectPoll<Fut, F> {
+        InspectPoll { future, f }
+    }
+}
+
+impl<Fut: Unpin, F> Unpin for InspectPoll<Fut, F> {}
+
+impl<Fut, F> Future for InspectPoll<Fut, F>
+    where Fut: Future,
+          F: FnMut(&Poll<Fut::Output>),
+{
+    type Output = Fut::Output;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Fut::Output> {
+        let poll = self.future().poll(lw);
+        (self.f())(&poll);
+        poll
+    }
+}
@@ -0,0 +1,144 @@
+//! Definition of the `JoinAll` combinator, waiting for all of a list of futures
+//! to finish.
+
+use crate::future::{maybe_done, MaybeDone};
+use crate::stream::{Collect, FuturesOrdered, StreamExt};
+use futures_core::future::Future;
+use futures_core::task::{LocalWaker, Poll};
+use std::fmt;
+use std::iter::FromIterator;
+use std::marker::Unpin;
+use std::mem;
+use std::pin::Pin;
+use std::prelude::v1::*;
+
+// Up to this many futures are polled one after the other on every wakeup,
+// which is cheaper than allocating a task node for each of them.
+const SMALL_LIMIT: usize = 30;
+
+fn iter_pin_mut<T>(slice: Pin<&mut [T]>) -> impl Iterator<Item = Pin<&mut T>> {
+    // Safety: `std` _could_ make this unsound if it were to decide Pin's
+    // invariants aren't required to transmit through slices. Otherwise this has
+    // the same safety as a normal field pin projection.
+    unsafe { Pin::get_mut_unchecked(slice) }
+        .iter_mut()
+        .map(|t| unsafe { Pin::new_unchecked(t) })
+}
+
+enum JoinAllKind<F: Future> {
+    Small {
+        elems: Pin<Box<[MaybeDone<F>]>>,
+    },
+    Big {
+        fut: Collect<FuturesOrdered<F>, Vec<F::Output>>,
+    },
+}
+
+/// A future which takes a list of futures and resolves with a vector of their
+/// outputs.
+///
+/// This future is created with the `join_all` function.
+#[must_use = "futures do nothing unless polled"]
+pub struct JoinAll<F: Future> {
+    kind: JoinAllKind<F>,
+}
+
+// The futures are pinned in a box or by `FuturesOrdered`, and the outputs
+// are never pinned.
+impl<F: Future> Unpin for JoinAll<F> {}
+
+impl<F> fmt::Debug for JoinAll<F>
+    where F: Future + fmt::Debug,
+          F::Output: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            JoinAllKind::Small { elems } => fmt.debug_struct("JoinAll")
+                .field("elems", elems)
+                .finish(),
+            JoinAllKind::Big { fut } => fmt.debug_struct("JoinAll")
+                .field("fut", fut)
+                .finish(),
+        }
+    }
+}
+
+/// Creates a future which represents a collection of the outputs of the
+/// futures given.
+///
+/// The returned future will drive execution for all of its underlying
+/// futures, collecting the outputs into a destination `Vec` in the same order
+/// as they were provided. Like `join!`, it waits for every future to complete
+/// and doesn't short-circuit; use `try_join_all` to stop at the first error
+/// of a list of fallible futures.
+///
+/// Small lists of futures are all polled again whenever the task is woken.
+/// Larger lists are driven by a [`FuturesOrdered`], so that only the futures
+/// which have been woken are polled again.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, join_all};
+///
+/// let futures = vec![future::ready(1), future::ready(2), future::ready(3)];
+///
+/// assert_eq!(await!(join_all(futures)), vec![1, 2, 3]);
+/// # })
+/// ```
+pub fn join_all<I>(i: I) -> JoinAll<I::Item>
+    where I: IntoIterator,
+          I::Item: Future,
+{
+    let iter = i.into_iter();
+    let kind = match iter.size_hint() {
+        (_, Some(max)) if max <= SMALL_LIMIT => {
+            let elems: Box<[_]> = iter.map(maybe_done).collect();
+            JoinAllKind::Small { elems: elems.into() }
+        }
+        _ => JoinAllKind::Big {
+            fut: iter.collect::<FuturesOrdered<_>>().collect(),
+        },
+    };
+    JoinAll { kind }
+}
+
+impl<F> Future for JoinAll<F>
+    where F: Future,
+{
+    type Output = Vec<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Self::Output> {
+        match &mut self.kind {
+            JoinAllKind::Small { elems } => {
+                let mut all_done = true;
+
+                for elem in iter_pin_mut(elems.as_mut()) {
+                    if elem.poll(lw).is_pending() {
+                        all_done = false;
+                    }
+                }
+
+                if all_done {
+                    let empty: Box<[_]> = Box::new([]);
+                    let mut elems = mem::replace(elems, empty.into());
+                    let result = iter_pin_mut(elems.as_mut())
+                        .map(|e| e.take_output().unwrap())
+                        .collect();
+                    Poll::Ready(result)
+                } else {
+                    Poll::Pending
+                }
+            }
+            JoinAllKind::Big { fut } => Pin::new(fut).poll(lw),
+        }
+    }
+}
+
+impl<F: Future> FromIterator<F> for JoinAll<F> {
+    fn from_iter<T: IntoIterator<Item = F>>(iter: T) -> Self {
+        join_all(iter)
+    }
+}
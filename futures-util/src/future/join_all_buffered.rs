@@ -0,0 +1,84 @@
+//! Definition of the `JoinAllBuffered` combinator, waiting for all of a list
+//! of futures to finish while only running a limited number of them at once.
+
+use crate::stream::{self, Buffered, Collect, Iter, StreamExt};
+use futures_core::future::Future;
+use futures_core::task::{LocalWaker, Poll};
+use std::fmt;
+use std::pin::Pin;
+use std::prelude::v1::*;
+
+/// A future which takes a list of futures and resolves with a vector of their
+/// outputs, running at most a fixed number of them at the same time.
+///
+/// This future is created with the `join_all_buffered` function.
+#[must_use = "futures do nothing unless polled"]
+pub struct JoinAllBuffered<I>
+    where I: Iterator,
+          I::Item: Future,
+{
+    inner: Collect<Buffered<Iter<I>>, Vec<<I::Item as Future>::Output>>,
+}
+
+impl<I> fmt::Debug for JoinAllBuffered<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: Future,
+          <I::Item as Future>::Output: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("JoinAllBuffered")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+/// Creates a future which represents a collection of the outputs of the
+/// futures given, running at most `max` of them at the same time.
+///
+/// The futures are taken from `iter` one at a time, whenever fewer than `max`
+/// of them are in flight, so a lazy iterator only creates its futures as they
+/// are needed. The outputs are collected into a `Vec` in the same order as
+/// the futures were provided, regardless of the order in which they
+/// complete.
+///
+/// Like `join`, this waits for every future to complete and doesn't
+/// short-circuit: the outputs of fallible futures are collected as
+/// `Result`s.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, join_all_buffered};
+///
+/// let requests = (1..=5).map(|id| future::ready(id * 10));
+///
+/// assert_eq!(await!(join_all_buffered(requests, 2)), vec![10, 20, 30, 40, 50]);
+/// # })
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if `max` is zero.
+pub fn join_all_buffered<I>(iter: I, max: usize) -> JoinAllBuffered<I::IntoIter>
+    where I: IntoIterator,
+          I::Item: Future,
+{
+    assert!(max > 0, "join_all_buffered needs to run at least one future");
+
+    JoinAllBuffered {
+        inner: stream::iter(iter).buffered(max).collect(),
+    }
+}
+
+impl<I> Future for JoinAllBuffered<I>
+    where I: Iterator,
+          I::Item: Future,
+{
+    type Output = Vec<<I::Item as Future>::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Self::Output> {
+        Pin::new(&mut self.inner).poll(lw)
+    }
+}
@@ -0,0 +1,40 @@
+use core::marker::{PhantomData, Unpin};
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::unsafe_pinned;
+
+/// Future for the [`map_into`](super::FutureExt::map_into) combinator.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct MapInto<Fut, T> {
+    future: Fut,
+    _marker: PhantomData<T>,
+}
+
+impl<Fut: Unpin, T> Unpin for MapInto<Fut, T> {}
+
+impl<Fut, T> MapInto<Fut, T> {
+    unsafe_pinned!(future: Fut);
+
+    pub(super) fn new(future: Fut) -> MapInto<Fut, T> {
+        MapInto {
+            future,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Fut, T> Future for MapInto<Fut, T>
+    where Fut: Future,
+          Fut::Output: Into<T>,
+{
+    type Output = T;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Self::Output> {
+        self.future().poll(lw).map(Into::into)
+    }
+}
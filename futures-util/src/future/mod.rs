@@ -25,9 +25,15 @@ pub use self::option::{OptionFuture};
 mod poll_fn;
 pub use self::poll_fn::{poll_fn, PollFn};
 
+mod poll_immediate;
+pub use self::poll_immediate::{poll_immediate, PollImmediate};
+
 mod ready;
 pub use self::ready::{ready, ok, err, Ready};
 
+mod yield_now;
+pub use self::yield_now::{yield_now, YieldNow};
+
 // Combinators
 mod flatten;
 pub use self::flatten::Flatten;
@@ -47,6 +53,9 @@ pub use self::join::{Join, Join3, Join4, Join5};
 mod map;
 pub use self::map::Map;
 
+mod map_into;
+pub use self::map_into::MapInto;
+
 // Todo
 // mod select;
 // pub use self::select::Select;
@@ -57,6 +66,13 @@ pub use self::then::Then;
 mod inspect;
 pub use self::inspect::Inspect;
 
+mod inspect_poll;
+pub use self::inspect_poll::InspectPoll;
+
+mod timeout;
+pub use self::timeout::{Timeout, TimeoutRecoverable};
+pub use crate::stream::Elapsed;
+
 mod unit_error;
 pub use self::unit_error::UnitError;
 
@@ -66,26 +82,33 @@ pub(crate) use self::chain::Chain;
 
 if_std! {
     mod abortable;
-    pub use self::abortable::{abortable, Abortable, AbortHandle, AbortRegistration, Aborted};
+    pub use self::abortable::{
+        abortable, Abortable, AbortHandle, AbortRegistration, Aborted, DropGuard,
+    };
 
     mod catch_unwind;
     pub use self::catch_unwind::CatchUnwind;
 
     mod remote_handle;
-    pub use self::remote_handle::{Remote, RemoteHandle};
+    pub use self::remote_handle::{Remote, RemoteHandle, SharedRemoteHandle};
+
+    mod join_all;
+    pub use self::join_all::{join_all, JoinAll};
 
-    // ToDo
-    // mod join_all;
-    // pub use self::join_all::{join_all, JoinAll};
+    mod join_all_buffered;
+    pub use self::join_all_buffered::{join_all_buffered, JoinAllBuffered};
 
     // mod select_all;
     // pub use self::select_all::{SelectAll, SelectAllNext, select_all};
 
-    // mod select_ok;
-    // pub use self::select_ok::{SelectOk, select_ok};
+    mod select_ok;
+    pub use self::select_ok::{SelectOk, select_ok};
+
+    mod select_ok_all;
+    pub use self::select_ok_all::{SelectOkAll, select_ok_all};
 
     mod shared;
-    pub use self::shared::Shared;
+    pub use self::shared::{Shared, WeakShared};
 }
 
 impl<T: ?Sized> FutureExt for T where T: Future {}
@@ -123,6 +146,31 @@ pub trait FutureExt: Future {
         assert_future::<U, _>(Map::new(self, f))
     }
 
+    /// Map this future's output to a different type using the `Into` trait.
+    ///
+    /// This is a shorthand for `.map(Into::into)` which lets the compiler
+    /// infer the target type, e.g. to make the outputs of different futures
+    /// agree on a common type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, FutureExt};
+    ///
+    /// let future = future::ready(1u8);
+    /// let new_future = future.map_into::<u32>();
+    /// assert_eq!(await!(new_future), 1u32);
+    /// # });
+    /// ```
+    fn map_into<U>(self) -> MapInto<Self, U>
+        where Self::Output: Into<U>,
+              Self: Sized,
+    {
+        assert_future::<U, _>(MapInto::new(self))
+    }
+
     /// Chain on a computation for when a future finished, passing the result of
     /// the future to the provided closure `f`.
     ///
@@ -529,6 +577,110 @@ pub trait FutureExt: Future {
         assert_future::<Self::Output, _>(Inspect::new(self, f))
     }
 
+    /// Do something with the result of every poll of this future.
+    ///
+    /// The closure is called with a reference to the `Poll` each time the
+    /// future is polled: with `Poll::Pending` while it's still running, and
+    /// with `Poll::Ready` once, when it completes. This can be used to
+    /// instrument a future, e.g. to count how often it's polled or to measure
+    /// the time from its first poll to its completion. The output isn't
+    /// cloned, and is passed on unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, FutureExt};
+    ///
+    /// let mut polls = 0;
+    /// let future = future::ready(1).inspect_poll(|_| polls += 1);
+    /// assert_eq!(await!(future), 1);
+    /// assert_eq!(polls, 1);
+    /// # });
+    /// ```
+    fn inspect_poll<F>(self, f: F) -> InspectPoll<Self, F>
+        where F: FnMut(&Poll<Self::Output>),
+              Self: Sized,
+    {
+        assert_future::<Self::Output, _>(InspectPoll::new(self, f))
+    }
+
+    /// Limits the time this future may take to complete.
+    ///
+    /// This library doesn't provide timers, so the deadline is given as a
+    /// `timer` future which completes once it has passed, e.g. a delay from
+    /// the timer of the runtime in use. The returned future resolves to
+    /// `Ok(output)` if this future completes first, or to `Err(Elapsed)` if
+    /// the timer completes first. On timeout, this future is dropped right
+    /// away instead of being kept around until the returned future is
+    /// dropped.
+    ///
+    /// This future is always polled before the timer, so a future that
+    /// completes on its first poll never times out, even if the timer
+    /// has already completed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, Elapsed, FutureExt};
+    ///
+    /// let future = future::ready(1).timeout(future::ready(()));
+    /// assert_eq!(await!(future), Ok(1));
+    ///
+    /// let future = future::empty::<i32>().timeout(future::ready(()));
+    /// assert_eq!(await!(future), Err(Elapsed));
+    /// # });
+    /// ```
+    fn timeout<T>(self, timer: T) -> Timeout<Self, T>
+        where T: Future<Output = ()>,
+              Self: Sized,
+    {
+        assert_future::<Result<Self::Output, Elapsed>, _>(
+            Timeout::new(self, timer))
+    }
+
+    /// Limits the time this future may take to complete, handing the future
+    /// back if it doesn't complete in time.
+    ///
+    /// This works like [`timeout`](FutureExt::timeout), but instead of
+    /// dropping this future when the `timer` completes first, the returned
+    /// future resolves to `Err(future)` with this future in whatever state
+    /// it has reached. It can then be awaited again, e.g. with a longer
+    /// deadline, without losing the progress it made. Since the future is
+    /// moved out after having been polled, it needs to be `Unpin`; futures
+    /// that aren't can be pinned with `Box::pinned` first.
+    ///
+    /// As with `timeout`, this future is polled before the timer, so it wins
+    /// if both are ready at the same time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::oneshot;
+    /// use futures::future::{self, FutureExt};
+    ///
+    /// let (tx, rx) = oneshot::channel::<i32>();
+    ///
+    /// let rx = await!(rx.timeout_recoverable(future::ready(()))).unwrap_err();
+    ///
+    /// // Try again, with a deadline that's never reached
+    /// tx.send(1).unwrap();
+    /// assert_eq!(await!(rx.timeout_recoverable(future::empty())), Ok(Ok(1)));
+    /// # });
+    /// ```
+    fn timeout_recoverable<T>(self, timer: T) -> TimeoutRecoverable<Self, T>
+        where T: Future<Output = ()>,
+              Self: Sized + Unpin,
+    {
+        assert_future::<Result<Self::Output, Self>, _>(
+            TimeoutRecoverable::new(self, timer))
+    }
+
     /// Catches unwinding panics while polling the future.
     ///
     /// In general, panics within a future can propagate all the way out to the
@@ -542,6 +694,11 @@ pub trait FutureExt: Future {
     /// after-the fact. To assist using this method, the `Future` trait is also
     /// implemented for `AssertUnwindSafe<F>` where `F` implements `Future`.
     ///
+    /// Once a panic has been caught, the returned future has completed with
+    /// the panic's payload and must not be polled again. Polling it again
+    /// panics rather than polling the inner future in whatever state the
+    /// panic left it in.
+    ///
     /// This method is only available when the `std` feature of this
     /// library is activated, and it is activated by default.
     ///
@@ -576,6 +733,19 @@ pub trait FutureExt: Future {
     /// The shared() method provides a method to convert any future into a
     /// cloneable future. It enables a future to be polled by multiple threads.
     ///
+    /// The underlying future is polled by whichever clone is polled, but only
+    /// ever by one of them at a time, and it runs to completion exactly once.
+    /// No clone is special: if the original `Shared` is dropped before the
+    /// future has completed, any remaining clone keeps driving it. Once the
+    /// future has completed, polling any clone, including clones created
+    /// afterwards, immediately returns a clone of the output.
+    ///
+    /// Since every clone receives its own copy of the output, a future that
+    /// resolves to a `Result` hands the same `Ok` or `Err` value to each
+    /// clone, which requires both the success and the error type to be
+    /// `Clone`. Errors that aren't `Clone` can be wrapped in an `Arc` first,
+    /// e.g. using `map_err(Arc::new)`.
+    ///
     /// This method is only available when the `std` feature of this
     /// library is activated, and it is activated by default.
     ///
@@ -655,6 +825,39 @@ pub trait FutureExt: Future {
     {
         Pin::new(self).poll(lw)
     }
+
+    /// Evaluates and consumes the future, returning its output if it's
+    /// ready right away, or `None` if it isn't.
+    ///
+    /// The future is polled a single time with a waker which does nothing
+    /// when woken, and is dropped afterwards. This is meant for futures
+    /// which are expected to be ready already, e.g. to take a value from a
+    /// channel if one has been sent, without needing a task to run on. If
+    /// the future isn't ready, the work it has done so far is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::channel::oneshot;
+    /// use futures::future::{self, FutureExt};
+    ///
+    /// assert_eq!(future::ready(1).now_or_never(), Some(1));
+    ///
+    /// let (_tx, rx) = oneshot::channel::<i32>();
+    /// assert_eq!(rx.now_or_never(), None);
+    /// ```
+    fn now_or_never(self) -> Option<Self::Output>
+        where Self: Sized
+    {
+        let lw = crate::task::noop_local_waker();
+        let mut this = self;
+        // Safety: `this` is shadowed, so it can't be moved after being pinned
+        let this = unsafe { Pin::new_unchecked(&mut this) };
+        match this.poll(&lw) {
+            Poll::Ready(output) => Some(output),
+            Poll::Pending => None,
+        }
+    }
 }
 
 // Just a helper function to ensure the futures we're returning all have the
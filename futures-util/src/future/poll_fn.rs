@@ -36,6 +36,28 @@ impl<F> Unpin for PollFn<F> {}
 /// assert_eq!(await!(read_future), "Hello, World!".to_owned());
 /// # });
 /// ```
+///
+/// The returned future is the named type [`PollFn`], so it can be stored
+/// without boxing. With a function pointer, the type can be written out:
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{poll_fn, PollFn};
+/// use futures::task::{LocalWaker, Poll};
+///
+/// struct Connection {
+///     handshake: PollFn<fn(&LocalWaker) -> Poll<u8>>,
+/// }
+///
+/// fn handshake(_lw: &LocalWaker) -> Poll<u8> {
+///     Poll::Ready(1)
+/// }
+///
+/// let conn = Connection { handshake: poll_fn(handshake) };
+/// assert_eq!(await!(conn.handshake), 1);
+/// # });
+/// ```
 pub fn poll_fn<T, F>(f: F) -> PollFn<F>
 where
     F: FnMut(&LocalWaker) -> Poll<T>
@@ -0,0 +1,109 @@
+//! Definition of the `PollImmediate` adapter
+
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::unsafe_pinned;
+
+/// A future which polls a future or stream once and resolves with the result
+/// of that poll right away.
+///
+/// Created by the [`poll_immediate()`] function.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct PollImmediate<T> {
+    inner: Option<T>,
+}
+
+impl<T: Unpin> Unpin for PollImmediate<T> {}
+
+impl<T> PollImmediate<T> {
+    unsafe_pinned!(inner: Option<T>);
+
+    /// Consumes this adapter, returning the underlying future or stream.
+    ///
+    /// This returns `None` if the underlying future has completed and been
+    /// dropped.
+    pub fn into_inner(self) -> Option<T> {
+        self.inner
+    }
+}
+
+/// Creates a future which polls `f` once and resolves to `Some` with its
+/// output if it's ready, or to `None` if it's pending.
+///
+/// The future that's polled is registered with the task polling the
+/// returned future, like it would be when polled directly. This makes it
+/// possible to check whether a future has completed, e.g. to pick out the
+/// handles of finished work, without waiting for it.
+///
+/// If `f` is pending, it's kept and can be taken back with
+/// [`PollImmediate::into_inner`], or polled again by polling the returned
+/// future again. To keep ownership of `f` altogether, pass `&mut f` for an
+/// `Unpin` future. Once `f` has completed, it's dropped, and polling the
+/// returned future again panics.
+///
+/// The returned value is a stream as well if `f` is a stream, which yields
+/// the result of a single `poll_next` of `f` every time it's polled:
+/// `Poll::Ready(item)` if an item was ready and `Poll::Pending` if not. It
+/// ends when `f` does.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::channel::oneshot;
+/// use futures::future::{self, poll_immediate};
+///
+/// assert_eq!(await!(poll_immediate(future::ready(1))), Some(1));
+///
+/// let (tx, mut rx) = oneshot::channel::<i32>();
+/// assert_eq!(await!(poll_immediate(&mut rx)), None);
+/// tx.send(2).unwrap();
+/// assert_eq!(await!(poll_immediate(&mut rx)), Some(Ok(2)));
+/// # });
+/// ```
+pub fn poll_immediate<T>(f: T) -> PollImmediate<T> {
+    PollImmediate { inner: Some(f) }
+}
+
+impl<T: Future> Future for PollImmediate<T> {
+    type Output = Option<T::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Self::Output> {
+        let inner = self.inner().as_pin_mut()
+            .expect("PollImmediate polled after completion");
+        match inner.poll(lw) {
+            Poll::Ready(output) => {
+                Pin::set(self.inner(), None);
+                Poll::Ready(Some(output))
+            }
+            Poll::Pending => Poll::Ready(None),
+        }
+    }
+}
+
+impl<T: Stream> Stream for PollImmediate<T> {
+    type Item = Poll<T::Item>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<Self::Item>> {
+        let inner = match self.inner().as_pin_mut() {
+            Some(inner) => inner,
+            None => return Poll::Ready(None),
+        };
+        match inner.poll_next(lw) {
+            Poll::Ready(Some(item)) => Poll::Ready(Some(Poll::Ready(item))),
+            Poll::Ready(None) => {
+                Pin::set(self.inner(), None);
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Ready(Some(Poll::Pending)),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    crate::future::{CatchUnwind, FutureExt},
+    crate::future::{CatchUnwind, FutureExt, Shared},
     futures_channel::oneshot::{self, Sender, Receiver},
     futures_core::{
         future::Future,
@@ -22,6 +22,12 @@ use {
 
 /// The handle to a remote future returned by
 /// [`remote_handle`](crate::future::FutureExt::remote_handle).
+///
+/// Dropping the handle cancels the remote future: the task running it is
+/// woken up, even if the future is waiting for something else, and the
+/// future is dropped the next time that task polls it. Use
+/// [`forget`](RemoteHandle::forget) to detach the remote future instead, so
+/// that it keeps running after the handle is gone.
 #[must_use = "futures do nothing unless polled"]
 #[derive(Debug)]
 pub struct RemoteHandle<T> {
@@ -39,6 +45,78 @@ impl<T> RemoteHandle<T> {
     }
 }
 
+impl<T: Send + 'static> RemoteHandle<T> {
+    /// Converts this handle into one that can be cloned.
+    ///
+    /// Every clone of the returned handle resolves to a clone of the remote
+    /// future's output, including clones created after the output has become
+    /// available. Only dropping *all* clones cancels the remote future.
+    ///
+    /// If the remote future panics, the first clone to observe the panic
+    /// resumes it and all other clones panic as well.
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::executor::ThreadPool;
+    /// use futures::future;
+    /// use futures::task::SpawnExt;
+    ///
+    /// let mut executor = ThreadPool::new().unwrap();
+    ///
+    /// let handle = executor.spawn_with_handle(future::ready(1)).unwrap();
+    /// let handle1 = handle.shared_handle();
+    /// let handle2 = handle1.clone();
+    ///
+    /// assert_eq!(executor.run(handle1), 1);
+    /// assert_eq!(executor.run(handle2), 1);
+    /// ```
+    pub fn shared_handle(self) -> SharedRemoteHandle<T>
+    where
+        T: Clone,
+    {
+        SharedRemoteHandle { inner: self.shared() }
+    }
+
+    /// Prepares the blocking closure `f` to be run on some other thread and
+    /// returns a handle to its result.
+    ///
+    /// `f` doesn't run until the returned job is called, which an executor
+    /// does on a thread of its choosing. The job catches a panic in `f` and
+    /// the handle resumes it, like with
+    /// [`remote_handle`](crate::future::FutureExt::remote_handle). Unlike
+    /// with `Remote`, dropping the handle doesn't stop `f`, there's no way to
+    /// interrupt a running closure. If the job is dropped without being
+    /// called, the handle panics when polled, just as if the remote future
+    /// had been dropped.
+    ///
+    /// This is how [`spawn_blocking`](crate::task::SpawnExt::spawn_blocking)
+    /// is implemented, and how executors with a pool of threads for blocking
+    /// work can provide their own version of it.
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::executor::block_on;
+    /// use futures::future::RemoteHandle;
+    /// use std::thread;
+    ///
+    /// let (job, handle) = RemoteHandle::blocking(|| (1..=10).sum::<u32>());
+    /// thread::spawn(job);
+    /// assert_eq!(block_on(handle), 55);
+    /// ```
+    pub fn blocking<F>(f: F) -> (impl FnOnce() + Send + 'static, RemoteHandle<T>)
+    where
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+        let job = move || {
+            // If the receiving end has gone away then that's ok, we just
+            // ignore the send error here.
+            drop(tx.send(panic::catch_unwind(AssertUnwindSafe(f))));
+        };
+        (job, RemoteHandle { rx, keep_running: Arc::new(AtomicBool::new(true)) })
+    }
+}
+
 impl<T: Send + 'static> Future for RemoteHandle<T> {
     type Output = T;
 
@@ -52,6 +130,31 @@ impl<T: Send + 'static> Future for RemoteHandle<T> {
     }
 }
 
+/// A cloneable handle to a remote future, created by
+/// [`RemoteHandle::shared_handle`](RemoteHandle::shared_handle).
+///
+/// The remote future is canceled once all clones of the handle have been
+/// dropped.
+#[must_use = "futures do nothing unless polled"]
+#[derive(Debug)]
+pub struct SharedRemoteHandle<T: Send + 'static> {
+    inner: Shared<RemoteHandle<T>>,
+}
+
+impl<T: Send + 'static> Clone for SharedRemoteHandle<T> {
+    fn clone(&self) -> Self {
+        SharedRemoteHandle { inner: self.inner.clone() }
+    }
+}
+
+impl<T: Clone + Send + 'static> Future for SharedRemoteHandle<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<T> {
+        self.inner.poll_unpin(lw)
+    }
+}
+
 type SendMsg<Fut> = Result<<Fut as Future>::Output, Box<(dyn Any + Send + 'static)>>;
 
 /// A future which sends its output to the corresponding `RemoteHandle`.
@@ -1,11 +1,12 @@
 //! Definition of the `SelectOk` combinator, finding the first successful future
 //! in a list.
 
+use std::marker::Unpin;
 use std::mem;
-use std::prelude::v1::*;
+use std::pin::Pin;
 
-use futures_core::{Future, IntoFuture, Poll, Async};
-use futures_core::task;
+use futures_core::future::{Future, TryFuture};
+use futures_core::task::{LocalWaker, Poll};
 
 /// Future for the `select_ok` combinator, waiting for one of any of a list of
 /// futures to successfully complete. Unlike `select_all`, this future ignores all
@@ -14,10 +15,12 @@ use futures_core::task;
 /// This is created by the `select_ok` function.
 #[derive(Debug)]
 #[must_use = "futures do nothing unless polled"]
-pub struct SelectOk<A> where A: Future {
-    inner: Vec<A>,
+pub struct SelectOk<Fut> {
+    inner: Vec<Fut>,
 }
 
+impl<Fut: Unpin> Unpin for SelectOk<Fut> {}
+
 /// Creates a new future which will select the first successful future over a list of futures.
 ///
 /// The returned future will wait for any future within `iter` to be ready and Ok. Unlike
@@ -25,34 +28,59 @@ pub struct SelectOk<A> where A: Future {
 /// failure. This is useful in contexts where any success is desired and failures
 /// are ignored, unless all the futures fail.
 ///
+/// On success, the futures that haven't completed yet are returned alongside
+/// the output, so that they can be dropped to cancel them or be polled
+/// further. The futures that failed are dropped as soon as they fail.
+///
+/// The futures need to be `Unpin`; futures that aren't can be pinned with
+/// `Box::pinned` first.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, select_ok};
+///
+/// let replicas = vec![
+///     future::err("replica 1 is down"),
+///     future::ok(2),
+///     future::ok(3),
+/// ];
+///
+/// let (first, rest) = await!(select_ok(replicas)).unwrap();
+/// assert_eq!(first, 2);
+/// assert_eq!(rest.len(), 1);
+///
+/// let all_down = vec![future::err::<(), _>(1), future::err(2)];
+/// assert_eq!(await!(select_ok(all_down)).unwrap_err(), 2);
+/// # })
+/// ```
+///
 /// # Panics
 ///
 /// This function will panic if the iterator specified contains no items.
-pub fn select_ok<I>(iter: I) -> SelectOk<<I::Item as IntoFuture>::Future>
+pub fn select_ok<I>(iter: I) -> SelectOk<I::Item>
     where I: IntoIterator,
-          I::Item: IntoFuture,
+          I::Item: TryFuture + Unpin,
 {
     let ret = SelectOk {
-        inner: iter.into_iter()
-                   .map(|a| a.into_future())
-                   .collect(),
+        inner: iter.into_iter().collect(),
     };
-    assert!(ret.inner.len() > 0);
+    assert!(!ret.inner.is_empty(), "iterator provided to select_ok was empty");
     ret
 }
 
-impl<A> Future for SelectOk<A> where A: Future {
-    type Item = (A::Item, Vec<A>);
-    type Error = A::Error;
+impl<Fut: TryFuture + Unpin> Future for SelectOk<Fut> {
+    type Output = Result<(Fut::Ok, Vec<Fut>), Fut::Error>;
 
-    fn poll(&mut self, lw: &LocalWaker) -> Poll<Self::Item, Self::Error> {
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Self::Output> {
         // loop until we've either exhausted all errors, a success was hit, or nothing is ready
         loop {
             let item = self.inner.iter_mut().enumerate().filter_map(|(i, f)| {
-                match f.poll(lw) {
-                    Ok(Async::Pending) => None,
-                    Ok(Async::Ready(e)) => Some((i, Ok(e))),
-                    Err(e) => Some((i, Err(e))),
+                match Pin::new(f).try_poll(lw) {
+                    Poll::Pending => None,
+                    Poll::Ready(res) => Some((i, res)),
                 }
             }).next();
 
@@ -63,18 +91,18 @@ impl<A> Future for SelectOk<A> where A: Future {
                     match res {
                         Ok(e) => {
                             let rest = mem::replace(&mut self.inner, Vec::new());
-                            return Ok(Async::Ready((e, rest)))
+                            return Poll::Ready(Ok((e, rest)))
                         },
                         Err(e) => {
                             if self.inner.is_empty() {
-                                return Err(e)
+                                return Poll::Ready(Err(e))
                             }
                         },
                     }
                 }
                 None => {
                     // based on the filter above, nothing is ready, return
-                    return Ok(Async::Pending)
+                    return Poll::Pending
                 },
             }
         }
@@ -0,0 +1,107 @@
+//! Definition of the `SelectOkAll` combinator, finding the first successful
+//! future in a list and collecting the errors of the others.
+
+use crate::stream::{FuturesUnordered, StreamExt};
+use crate::try_future::{IntoFuture, TryFutureExt};
+use futures_core::future::{Future, TryFuture};
+use futures_core::task::{LocalWaker, Poll};
+use std::fmt;
+use std::marker::Unpin;
+use std::mem;
+use std::pin::Pin;
+use std::prelude::v1::*;
+
+/// Future for the `select_ok_all` combinator, waiting for the first of a list
+/// of futures to complete successfully and collecting the errors of the
+/// futures that failed.
+///
+/// This is created by the `select_ok_all` function.
+#[must_use = "futures do nothing unless polled"]
+pub struct SelectOkAll<Fut: TryFuture> {
+    in_progress: FuturesUnordered<IntoFuture<Fut>>,
+    errors: Vec<Fut::Error>,
+}
+
+// The futures are pinned by `FuturesUnordered`, and the errors are never
+// pinned.
+impl<Fut: TryFuture> Unpin for SelectOkAll<Fut> {}
+
+impl<Fut> fmt::Debug for SelectOkAll<Fut>
+    where Fut: TryFuture,
+          Fut::Error: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("SelectOkAll")
+            .field("in_progress", &self.in_progress.len())
+            .field("errors", &self.errors)
+            .finish()
+    }
+}
+
+/// Creates a new future which will select the first successful future over a
+/// list of futures, or collect all of their errors if none succeeds.
+///
+/// This is like [`select_ok`](super::select_ok), except that no error is
+/// lost: if every future fails, the returned future resolves to `Err` with
+/// the errors of all of them, in the order in which the futures failed. As
+/// soon as one of the futures succeeds, the returned future resolves to its
+/// output and the other futures are dropped, which cancels them.
+///
+/// The futures are driven by a [`FuturesUnordered`], so only the futures
+/// which have been woken are polled again, and they don't need to be `Unpin`.
+///
+/// If the list of futures is empty, the returned future resolves to
+/// `Err(vec![])` right away.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, select_ok_all};
+///
+/// let replicas = vec![future::err("replica 1 is down"), future::ok(2)];
+/// assert_eq!(await!(select_ok_all(replicas)), Ok(2));
+///
+/// let replicas = vec![
+///     future::err::<(), _>("replica 1 is down"),
+///     future::err("replica 2 is down"),
+/// ];
+/// assert_eq!(
+///     await!(select_ok_all(replicas)),
+///     Err(vec!["replica 1 is down", "replica 2 is down"]),
+/// );
+/// # })
+/// ```
+pub fn select_ok_all<I>(iter: I) -> SelectOkAll<I::Item>
+    where I: IntoIterator,
+          I::Item: TryFuture,
+{
+    SelectOkAll {
+        in_progress: iter.into_iter().map(TryFutureExt::into_future).collect(),
+        errors: Vec::new(),
+    }
+}
+
+impl<Fut: TryFuture> Future for SelectOkAll<Fut> {
+    type Output = Result<Fut::Ok, Vec<Fut::Error>>;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Self::Output> {
+        let this = &mut *self;
+
+        loop {
+            match ready!(this.in_progress.poll_next_unpin(lw)) {
+                Some(Ok(output)) => {
+                    // Cancel the other futures right away
+                    this.in_progress = FuturesUnordered::new();
+                    return Poll::Ready(Ok(output));
+                }
+                Some(Err(e)) => this.errors.push(e),
+                None => {
+                    let errors = mem::replace(&mut this.errors, Vec::new());
+                    return Poll::Ready(Err(errors));
+                }
+            }
+        }
+    }
+}
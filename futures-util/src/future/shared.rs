@@ -5,7 +5,7 @@ use std::fmt;
 use std::cell::UnsafeCell;
 use std::marker::Unpin;
 use std::pin::Pin;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, Weak};
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering::SeqCst;
 use std::task::local_waker_from_nonlocal;
@@ -18,6 +18,12 @@ pub struct Shared<Fut: Future> {
     waker_key: usize,
 }
 
+/// A weak reference to a [`Shared`] that can be upgraded much like an `Arc`.
+///
+/// It doesn't keep the underlying future or its output alive. Created by
+/// [`Shared::downgrade`].
+pub struct WeakShared<Fut: Future>(Weak<Inner<Fut>>);
+
 struct Inner<Fut: Future> {
     future_or_output: UnsafeCell<FutureOrOutput<Fut>>,
     notifier: Arc<Notifier>,
@@ -41,6 +47,13 @@ impl<Fut: Future> fmt::Debug for Shared<Fut> {
     }
 }
 
+impl<Fut: Future> fmt::Debug for WeakShared<Fut> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_tuple("WeakShared")
+            .finish()
+    }
+}
+
 impl<Fut: Future> fmt::Debug for Inner<Fut> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt.debug_struct("Inner")
@@ -86,6 +99,34 @@ impl<Fut: Future> Shared<Fut> {
             waker_key: NULL_WAKER_KEY,
         }
     }
+
+    /// Creates a new [`WeakShared`] for this `Shared`.
+    ///
+    /// The weak reference doesn't keep the underlying future alive: once
+    /// every `Shared` for it has been dropped, the future or its output is
+    /// dropped as well and [`WeakShared::upgrade`] returns `None`.
+    pub fn downgrade(&self) -> WeakShared<Fut> {
+        WeakShared(Arc::downgrade(&self.inner))
+    }
+}
+
+impl<Fut: Future> WeakShared<Fut> {
+    /// Attempts to upgrade this weak reference to a [`Shared`].
+    ///
+    /// Returns `None` if every `Shared` for the underlying future has been
+    /// dropped.
+    pub fn upgrade(&self) -> Option<Shared<Fut>> {
+        Some(Shared {
+            inner: self.0.upgrade()?,
+            waker_key: NULL_WAKER_KEY,
+        })
+    }
+}
+
+impl<Fut: Future> Clone for WeakShared<Fut> {
+    fn clone(&self) -> Self {
+        WeakShared(self.0.clone())
+    }
 }
 
 impl<Fut> Shared<Fut>
@@ -96,6 +137,9 @@ where
     /// If any clone of this `Shared` has completed execution, returns its result immediately
     /// without blocking. Otherwise, returns None without triggering the work represented by
     /// this `Shared`.
+    ///
+    /// This doesn't register a waker and doesn't take any locks; checking for
+    /// completion is a single atomic load.
     pub fn peek(&self) -> Option<Fut::Output> {
         match self.inner.notifier.state.load(SeqCst) {
             COMPLETE => Some(unsafe { self.clone_output() }),
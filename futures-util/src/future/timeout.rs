@@ -0,0 +1,83 @@
+use crate::stream::Elapsed;
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::unsafe_pinned;
+
+/// Future for the [`timeout`](super::FutureExt::timeout) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct Timeout<Fut, T> {
+    future: Option<Fut>,
+    timer: T,
+}
+
+impl<Fut: Future, T: Future<Output = ()>> Timeout<Fut, T> {
+    unsafe_pinned!(future: Option<Fut>);
+    unsafe_pinned!(timer: T);
+
+    pub(super) fn new(future: Fut, timer: T) -> Timeout<Fut, T> {
+        Timeout { future: Some(future), timer }
+    }
+}
+
+impl<Fut: Unpin, T: Unpin> Unpin for Timeout<Fut, T> {}
+
+impl<Fut: Future, T: Future<Output = ()>> Future for Timeout<Fut, T> {
+    type Output = Result<Fut::Output, Elapsed>;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Self::Output> {
+        // The future is polled before the timer, so it can't time out if it
+        // completes right away.
+        let output = self.future().as_pin_mut()
+            .expect("Timeout polled after completion")
+            .poll(lw);
+        if let Poll::Ready(output) = output {
+            Pin::set(self.future(), None);
+            return Poll::Ready(Ok(output));
+        }
+
+        ready!(self.timer().poll(lw));
+        Pin::set(self.future(), None);
+        Poll::Ready(Err(Elapsed))
+    }
+}
+
+/// Future for the [`timeout_recoverable`](super::FutureExt::timeout_recoverable)
+/// method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct TimeoutRecoverable<Fut, T> {
+    future: Option<Fut>,
+    timer: T,
+}
+
+impl<Fut: Future + Unpin, T: Future<Output = ()>> TimeoutRecoverable<Fut, T> {
+    unsafe_pinned!(timer: T);
+
+    pub(super) fn new(future: Fut, timer: T) -> TimeoutRecoverable<Fut, T> {
+        TimeoutRecoverable { future: Some(future), timer }
+    }
+}
+
+impl<Fut: Unpin, T: Unpin> Unpin for TimeoutRecoverable<Fut, T> {}
+
+impl<Fut: Future + Unpin, T: Future<Output = ()>> Future for TimeoutRecoverable<Fut, T> {
+    type Output = Result<Fut::Output, Fut>;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Self::Output> {
+        // Safe since `future` is `Unpin` and `timer` is never moved
+        let this = unsafe { Pin::get_mut_unchecked(self.as_mut()) };
+        let future = this.future.as_mut()
+            .expect("TimeoutRecoverable polled after completion");
+        if let Poll::Ready(output) = Pin::new(future).poll(lw) {
+            this.future = None;
+            return Poll::Ready(Ok(output));
+        }
+
+        ready!(self.timer().poll(lw));
+        let this = unsafe { Pin::get_mut_unchecked(self) };
+        Poll::Ready(Err(this.future.take().unwrap()))
+    }
+}
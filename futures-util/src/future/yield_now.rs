@@ -0,0 +1,62 @@
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::task::{LocalWaker, Poll};
+
+/// A future which yields to the executor once before completing.
+///
+/// This is created by the [`yield_now()`] function.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct YieldNow {
+    yielded: bool,
+}
+
+/// Creates a future which gives other tasks a chance to run before it
+/// completes.
+///
+/// The first time it's polled, the returned future wakes the current task
+/// and returns `Poll::Pending`. The executor therefore puts the task back
+/// in line, so that other tasks which are ready get to run in the meantime,
+/// and polls it again later, at which point it completes. This is useful
+/// in long-running computations which would otherwise keep the executor's
+/// thread busy without ever giving other tasks a turn.
+///
+/// The wake-up goes to the `LocalWaker` passed to `poll`, so the future has
+/// to be polled by a task, like any other future. Whether other tasks
+/// actually run before it's polled again is up to the executor; a
+/// single-threaded executor like `LocalPool` polls the other tasks which
+/// are ready first.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::future;
+///
+/// let mut sum = 0u64;
+/// for i in 0..10_000 {
+///     sum += i;
+///     if i % 1_000 == 0 {
+///         await!(future::yield_now());
+///     }
+/// }
+/// assert_eq!(sum, 49_995_000);
+/// # });
+/// ```
+pub fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+        self.yielded = true;
+        lw.wake();
+        Poll::Pending
+    }
+}
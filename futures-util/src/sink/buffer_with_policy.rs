@@ -0,0 +1,279 @@
+use core::fmt;
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::collections::VecDeque;
+use std::marker::Unpin;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// When a [`BufferWithPolicy`] passes its buffered items on to the
+/// underlying sink.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BufferPolicy {
+    /// Flush once the given number of items has been buffered.
+    Count(usize),
+    /// Flush once the given duration has passed since the first item was
+    /// buffered.
+    Duration(Duration),
+    /// Flush once either the given number of items has been buffered or the
+    /// given duration has passed since the first item was buffered, whichever
+    /// comes first.
+    CountOrDuration(usize, Duration),
+}
+
+impl BufferPolicy {
+    fn count(&self) -> Option<usize> {
+        match *self {
+            BufferPolicy::Count(n) | BufferPolicy::CountOrDuration(n, _) => Some(n),
+            BufferPolicy::Duration(_) => None,
+        }
+    }
+
+    fn duration(&self) -> Option<Duration> {
+        match *self {
+            BufferPolicy::Duration(d) | BufferPolicy::CountOrDuration(_, d) => Some(d),
+            BufferPolicy::Count(_) => None,
+        }
+    }
+}
+
+/// Sink for the `Sink::buffer_with_policy` combinator, which holds back
+/// values until its [`BufferPolicy`] decides that they should be flushed to
+/// the underlying sink.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct BufferWithPolicy<Si: Sink, F, T> {
+    sink: Si,
+    buf: VecDeque<Si::SinkItem>,
+    policy: BufferPolicy,
+    make_timer: F,
+    timer: Option<T>,
+    // Whether the buffered items are being passed on to the underlying sink
+    flushing: bool,
+}
+
+impl<Si: Sink + Unpin, F, T: Unpin> Unpin for BufferWithPolicy<Si, F, T> {}
+
+impl<Si, F, T> BufferWithPolicy<Si, F, T>
+    where Si: Sink,
+          F: FnMut(Duration) -> T,
+          T: Future<Output = ()>,
+{
+    unsafe_pinned!(sink: Si);
+    unsafe_unpinned!(buf: VecDeque<Si::SinkItem>);
+    unsafe_unpinned!(make_timer: F);
+    unsafe_pinned!(timer: Option<T>);
+    unsafe_unpinned!(flushing: bool);
+
+    pub(super) fn new(
+        sink: Si,
+        policy: BufferPolicy,
+        make_timer: F,
+    ) -> BufferWithPolicy<Si, F, T> {
+        if policy.count() == Some(0) {
+            panic!("buffer count must be non-zero");
+        }
+
+        BufferWithPolicy {
+            sink,
+            buf: VecDeque::with_capacity(policy.count().unwrap_or(0)),
+            policy,
+            make_timer,
+            timer: None,
+            flushing: false,
+        }
+    }
+
+    /// Returns the policy this sink was created with.
+    pub fn policy(&self) -> BufferPolicy {
+        self.policy
+    }
+
+    /// Get a shared reference to the inner sink.
+    pub fn get_ref(&self) -> &Si {
+        &self.sink
+    }
+
+    /// Get a mutable reference to the inner sink.
+    pub fn get_mut(&mut self) -> &mut Si {
+        &mut self.sink
+    }
+
+    /// Creates a future which flushes the buffered items to the underlying
+    /// sink once the policy's duration has passed since the first of them
+    /// was buffered.
+    ///
+    /// The timer only triggers a flush while the sink is being polled. A
+    /// producer that has stopped sending items for now can await this
+    /// future, e.g. racing it against its next item, so that a partial batch
+    /// is still passed on once its time is up, but not any earlier. The
+    /// future resolves right away if nothing is buffered, and flushes right
+    /// away if the policy has no duration.
+    pub fn timed_flush(&mut self) -> TimedFlush<'_, Si, F, T>
+        where Self: Unpin,
+    {
+        TimedFlush { sink: self }
+    }
+
+    /// Consumes this combinator, returning the underlying sink.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> Si {
+        self.sink
+    }
+
+    // Starts a flush once the timer has fired
+    fn poll_timer(self: &mut Pin<&mut Self>, lw: &LocalWaker) {
+        let poll = match self.timer().as_pin_mut() {
+            Some(timer) => timer.poll(lw),
+            None => return,
+        };
+        if poll.is_ready() {
+            Pin::set(self.timer(), None);
+            *self.flushing() = true;
+        }
+    }
+
+    // Waits for the timer to fire and then flushes the buffered items
+    fn poll_timed_flush(
+        self: &mut Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Result<(), Si::SinkError>> {
+        self.poll_timer(lw);
+        if !self.flushing {
+            if self.buf.is_empty() {
+                return Poll::Ready(Ok(()));
+            }
+            if self.timer.is_some() {
+                return Poll::Pending;
+            }
+            *self.flushing() = true;
+        }
+        self.try_flush_buffer(lw)
+    }
+
+    // Passes all buffered items on to the underlying sink and flushes it
+    fn try_flush_buffer(
+        self: &mut Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Result<(), Si::SinkError>> {
+        while !self.buf.is_empty() {
+            try_ready!(self.sink().poll_ready(lw));
+            let item = self.buf().pop_front().unwrap();
+            if let Err(e) = self.sink().start_send(item) {
+                return Poll::Ready(Err(e));
+            }
+        }
+        try_ready!(self.sink().poll_flush(lw));
+        *self.flushing() = false;
+        Poll::Ready(Ok(()))
+    }
+}
+
+// Forwarding impl of Stream from the underlying sink
+impl<S, F, T> Stream for BufferWithPolicy<S, F, T>
+    where S: Sink + Stream,
+          F: FnMut(Duration) -> T,
+          T: Future<Output = ()>,
+{
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Option<S::Item>> {
+        self.sink().poll_next(lw)
+    }
+}
+
+impl<Si, F, T> Sink for BufferWithPolicy<Si, F, T>
+    where Si: Sink,
+          F: FnMut(Duration) -> T,
+          T: Future<Output = ()>,
+{
+    type SinkItem = Si::SinkItem;
+    type SinkError = Si::SinkError;
+
+    fn poll_ready(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.poll_timer(lw);
+        if self.flushing {
+            try_ready!(self.try_flush_buffer(lw));
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(
+        mut self: Pin<&mut Self>,
+        item: Self::SinkItem,
+    ) -> Result<(), Self::SinkError> {
+        self.buf().push_back(item);
+
+        if Some(self.buf.len()) == self.policy.count() {
+            Pin::set(self.timer(), None);
+            *self.flushing() = true;
+        } else if self.buf.len() == 1 {
+            if let Some(duration) = self.policy.duration() {
+                let timer = (self.make_timer())(duration);
+                Pin::set(self.timer(), Some(timer));
+            }
+        }
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Pin::set(self.timer(), None);
+        *self.flushing() = true;
+        self.try_flush_buffer(lw)
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Pin::set(self.timer(), None);
+        *self.flushing() = true;
+        try_ready!(self.try_flush_buffer(lw));
+        self.sink().poll_close(lw)
+    }
+}
+
+/// Future for the
+/// [`BufferWithPolicy::timed_flush`](BufferWithPolicy::timed_flush) method.
+#[must_use = "futures do nothing unless polled"]
+pub struct TimedFlush<'a, Si: Sink, F, T> {
+    sink: &'a mut BufferWithPolicy<Si, F, T>,
+}
+
+impl<Si: Sink, F, T> Unpin for TimedFlush<'_, Si, F, T> {}
+
+impl<Si, F, T> fmt::Debug for TimedFlush<'_, Si, F, T>
+    where Si: Sink + fmt::Debug,
+          Si::SinkItem: fmt::Debug,
+          F: fmt::Debug,
+          T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TimedFlush")
+            .field("sink", &self.sink)
+            .finish()
+    }
+}
+
+impl<Si, F, T> Future for TimedFlush<'_, Si, F, T>
+    where Si: Sink + Unpin,
+          F: FnMut(Duration) -> T,
+          T: Future<Output = ()> + Unpin,
+{
+    type Output = Result<(), Si::SinkError>;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Self::Output> {
+        Pin::new(&mut *self.sink).poll_timed_flush(lw)
+    }
+}
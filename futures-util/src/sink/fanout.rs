@@ -8,6 +8,10 @@ use pin_utils::unsafe_pinned;
 ///
 /// Backpressure from any downstream sink propagates up, which means that this sink
 /// can only process items as fast as its _slowest_ downstream sink.
+///
+/// An error from either sink is returned right away, and the first sink is
+/// always driven before the second one. If the first sink rejects an item,
+/// the second sink doesn't receive it either.
 pub struct Fanout<Si1: Sink, Si2: Sink> {
     sink1: Si1,
     sink2: Si2
@@ -21,6 +25,16 @@ impl<Si1: Sink, Si2: Sink> Fanout<Si1, Si2> {
         Fanout { sink1, sink2 }
     }
 
+    /// Get a shared reference to the inner sinks.
+    pub fn get_ref(&self) -> (&Si1, &Si2) {
+        (&self.sink1, &self.sink2)
+    }
+
+    /// Get a mutable reference to the inner sinks.
+    pub fn get_mut(&mut self) -> (&mut Si1, &mut Si2) {
+        (&mut self.sink1, &mut self.sink2)
+    }
+
     /// Consumes this combinator, returning the underlying sinks.
     ///
     /// Note that this may discard intermediate state of this combinator,
@@ -119,4 +133,17 @@ mod tests {
         assert_eq!(vec1, expected);
         assert_eq!(vec2, expected);
     }
+
+    #[test]
+    fn error_stops_item() {
+        let (tx1, rx1) = mpsc::channel::<i32>(1);
+        let (tx2, rx2) = mpsc::channel(1);
+        let mut tx = tx1.fanout(tx2);
+
+        drop(rx1);
+        assert!(block_on(tx.send(1)).is_err());
+
+        drop(tx);
+        assert_eq!(block_on(rx2.collect::<Vec<_>>()), vec![]);
+    }
 }
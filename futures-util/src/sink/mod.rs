@@ -45,6 +45,9 @@ pub use self::with_flat_map::WithFlatMap;
 if_std! {
     mod buffer;
     pub use self::buffer::Buffer;
+
+    mod buffer_with_policy;
+    pub use self::buffer_with_policy::{BufferPolicy, BufferWithPolicy, TimedFlush};
 }
 
 impl<T: ?Sized> SinkExt for T where T: Sink {}
@@ -79,7 +82,14 @@ pub trait SinkExt: Sink {
     ///
     /// To process each value, `f` produces a *stream*, of which each value
     /// is passed to the underlying sink. A new value will not be accepted until
-    /// the stream has been drained
+    /// the stream has been drained, i.e. `poll_ready` stays pending until
+    /// every item of the previous expansion was accepted by the underlying
+    /// sink. If `f` returns an empty stream, the value is consumed without
+    /// sending anything.
+    ///
+    /// The stream yields `Result`s so that the expansion can fail with the
+    /// sink's error type; a stream of plain items can be adapted with
+    /// `.map(Ok)`.
     ///
     /// Note that this function consumes the given sink, returning a wrapped
     /// version, much like `Iterator::flat_map`.
@@ -164,6 +174,49 @@ pub trait SinkExt: Sink {
         Buffer::new(self, capacity)
     }
 
+    /// Adds a buffer to the current sink which holds items back until the
+    /// given [`BufferPolicy`] decides to flush them.
+    ///
+    /// Unlike [`buffer`](SinkExt::buffer), which passes items on to the
+    /// underlying sink as soon as it's ready for them, this adapter collects
+    /// items and then hands them to the underlying sink and flushes it in one
+    /// go. That happens once `Count(n)` items have been buffered, or once the
+    /// `Duration(d)` has passed since the first item was buffered, so that a
+    /// few small writes don't sit in the buffer indefinitely. With
+    /// `CountOrDuration(n, d)`, whichever comes first triggers the flush.
+    ///
+    /// Since this library doesn't provide timers, the duration is passed to
+    /// `make_timer`, which has to return a future that completes once the
+    /// duration has passed and wakes the task when it does. The timer is
+    /// polled by `poll_ready`, which starts the flush once it has fired. A
+    /// producer that stops sending items after a partial batch can await
+    /// [`timed_flush`](BufferWithPolicy::timed_flush), which waits for the
+    /// timer and then flushes the batch. While the buffered items are being
+    /// flushed, `poll_ready` returns `Pending`. Calling `flush` or `close`
+    /// flushes the buffer right away.
+    ///
+    /// Note that this function consumes the given sink, returning a wrapped
+    /// version, much like `Iterator::map`.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the policy's count is zero.
+    #[cfg(feature = "std")]
+    fn buffer_with_policy<F, T>(
+        self,
+        policy: BufferPolicy,
+        make_timer: F,
+    ) -> BufferWithPolicy<Self, F, T>
+        where F: FnMut(std::time::Duration) -> T,
+              T: Future<Output = ()>,
+              Self: Sized,
+    {
+        BufferWithPolicy::new(self, policy, make_timer)
+    }
+
     /// Close the sink.
     fn close(&mut self) -> Close<'_, Self>
         where Self: Unpin,
@@ -174,7 +227,11 @@ pub trait SinkExt: Sink {
     /// Fanout items to multiple sinks.
     ///
     /// This adapter clones each incoming item and forwards it to both this as well as
-    /// the other sink at the same time.
+    /// the other sink at the same time. It's only ready for another item once
+    /// both sinks are ready, so the slower sink sets the pace.
+    ///
+    /// Errors are returned as soon as either sink reports one. Since this sink
+    /// is driven first, an item it rejects is not sent to `other`.
     fn fanout<Si>(self, other: Si) -> Fanout<Self, Si>
         where Self: Sized,
               Self::SinkItem: Clone,
@@ -6,7 +6,7 @@ use futures_sink::Sink;
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
 
 /// Sink for the `Sink::with_flat_map` combinator, chaining a computation that
-/// returns an iterator to run prior to pushing a value into the underlying
+/// returns a stream to run prior to pushing a value into the underlying
 /// sink.
 #[derive(Debug)]
 #[must_use = "sinks do nothing unless polled"]
@@ -0,0 +1,170 @@
+use crate::stream::{Fuse, FuturesUnordered};
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::fmt;
+use std::marker::Unpin;
+use std::pin::Pin;
+
+/// An adaptor for a stream of futures to execute the futures concurrently,
+/// pulling new futures from the stream only on demand.
+///
+/// This is created by the
+/// [`buffer_unordered_lazy`](super::StreamExt::buffer_unordered_lazy)
+/// method.
+#[must_use = "streams do nothing unless polled"]
+pub struct BufferUnorderedLazy<St>
+where
+    St: Stream,
+    St::Item: Future,
+{
+    stream: Fuse<St>,
+    in_progress_queue: FuturesUnordered<St::Item>,
+    max: usize,
+    low_watermark: usize,
+    yielded: bool,
+}
+
+impl<St> Unpin for BufferUnorderedLazy<St>
+where
+    St: Stream + Unpin,
+    St::Item: Future,
+{}
+
+impl<St> fmt::Debug for BufferUnorderedLazy<St>
+where
+    St: Stream + fmt::Debug,
+    St::Item: Future,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("BufferUnorderedLazy")
+            .field("stream", &self.stream)
+            .field("in_progress_queue", &self.in_progress_queue)
+            .field("max", &self.max)
+            .field("low_watermark", &self.low_watermark)
+            .field("yielded", &self.yielded)
+            .finish()
+    }
+}
+
+impl<St> BufferUnorderedLazy<St>
+where
+    St: Stream,
+    St::Item: Future,
+{
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_unpinned!(in_progress_queue: FuturesUnordered<St::Item>);
+    unsafe_unpinned!(yielded: bool);
+
+    pub(super) fn new(
+        stream: St,
+        max: usize,
+        low_watermark: usize,
+    ) -> BufferUnorderedLazy<St> {
+        BufferUnorderedLazy {
+            stream: super::Fuse::new(stream),
+            in_progress_queue: FuturesUnordered::new(),
+            max,
+            low_watermark,
+            yielded: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    #[allow(clippy::needless_lifetimes)] // https://github.com/rust-lang/rust/issues/52675
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        unsafe { Pin::map_unchecked_mut(self, |x| x.get_mut()) }
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+
+    // Whether a new future should be pulled from the underlying stream. The
+    // queue is never left empty, even with a low-watermark of zero, as
+    // nothing would wake this stream up again otherwise.
+    fn wants_more(&self) -> bool {
+        let len = self.in_progress_queue.len();
+        len < self.max
+            && (self.yielded || len < self.low_watermark || len == 0)
+    }
+}
+
+impl<St> Stream for BufferUnorderedLazy<St>
+where
+    St: Stream,
+    St::Item: Future,
+{
+    type Item = <St::Item as Future>::Output;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<Self::Item>> {
+        // Only top up the queue if the last item we yielded left room for a
+        // replacement or if we've dropped below the low-watermark.
+        while self.wants_more() {
+            match self.stream().poll_next(lw) {
+                Poll::Ready(Some(fut)) => {
+                    self.in_progress_queue().push(fut);
+                    *self.yielded() = false;
+                }
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+
+        // Attempt to pull the next value from the in_progress_queue
+        match Pin::new(self.in_progress_queue()).poll_next(lw) {
+            Poll::Ready(Some(output)) => {
+                *self.yielded() = true;
+                return Poll::Ready(Some(output));
+            }
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(None) => {}
+        }
+
+        // If more values are still coming from the stream, we're not done yet
+        if self.stream.is_done() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S> Sink for BufferUnorderedLazy<S>
+where
+    S: Stream + Sink,
+    S::Item: Future,
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream);
+}
@@ -98,6 +98,21 @@ impl<St: Stream> Stream for Chunks<St> {
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let cap = self.items.capacity();
+        if cap == 0 {
+            // The last chunk has already been yielded
+            return (0, Some(0));
+        }
+
+        let chunks = |len: usize| len / cap + if len % cap == 0 { 0 } else { 1 };
+        let len = self.items.len();
+        let (lower, upper) = self.stream.size_hint();
+        let lower = chunks(lower.saturating_add(len));
+        let upper = upper.and_then(|x| x.checked_add(len)).map(chunks);
+        (lower, upper)
+    }
 }
 
 /* TODO
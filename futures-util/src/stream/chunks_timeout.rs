@@ -0,0 +1,150 @@
+use crate::stream::Fuse;
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::marker::Unpin;
+use std::mem;
+use std::pin::Pin;
+use std::prelude::v1::*;
+
+/// An adaptor that chunks up elements in a vector, yielding a partial chunk
+/// if its timer fires before the chunk is full.
+///
+/// This is created by the `Stream::chunks_timeout` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ChunksTimeout<St: Stream, F, T> {
+    stream: Fuse<St>,
+    items: Vec<St::Item>,
+    make_timer: F,
+    timer: Option<T>,
+}
+
+impl<St: Unpin + Stream, F, T: Unpin> Unpin for ChunksTimeout<St, F, T> {}
+
+impl<St: Stream, F, T> ChunksTimeout<St, F, T>
+    where F: FnMut() -> T,
+          T: Future<Output = ()>,
+{
+    unsafe_unpinned!(items: Vec<St::Item>);
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_unpinned!(make_timer: F);
+    unsafe_pinned!(timer: Option<T>);
+
+    pub(super) fn new(stream: St, capacity: usize, make_timer: F) -> ChunksTimeout<St, F, T> {
+        assert!(capacity > 0);
+
+        ChunksTimeout {
+            stream: super::Fuse::new(stream),
+            items: Vec::with_capacity(capacity),
+            make_timer,
+            timer: None,
+        }
+    }
+
+    fn take(mut self: Pin<&mut Self>) -> Vec<St::Item> {
+        Pin::set(self.timer(), None);
+        let cap = self.items().capacity();
+        mem::replace(self.items(), Vec::with_capacity(cap))
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+}
+
+impl<St: Stream, F, T> Stream for ChunksTimeout<St, F, T>
+    where F: FnMut() -> T,
+          T: Future<Output = ()>,
+{
+    type Item = Vec<St::Item>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<Self::Item>> {
+        let cap = self.items.capacity();
+        loop {
+            match self.stream().poll_next(lw) {
+                // Push the item into the buffer and check whether it is full.
+                // The first item of a chunk starts its timer.
+                Poll::Ready(Some(item)) => {
+                    if self.items().is_empty() {
+                        let timer = (self.make_timer())();
+                        Pin::set(self.timer(), Some(timer));
+                    }
+                    self.items().push(item);
+                    if self.items().len() >= cap {
+                        return Poll::Ready(Some(self.as_mut().take()))
+                    }
+                }
+
+                // Since the underlying stream ran out of values, return what we
+                // have buffered, if we have anything.
+                Poll::Ready(None) => {
+                    let last = if self.items().is_empty() {
+                        None
+                    } else {
+                        Some(self.as_mut().take())
+                    };
+
+                    return Poll::Ready(last);
+                }
+
+                Poll::Pending => break,
+            }
+        }
+
+        // There's only a timer while items are buffered, so a timer firing
+        // never yields an empty chunk.
+        if let Some(timer) = self.timer().as_pin_mut() {
+            ready!(timer.poll(lw));
+            return Poll::Ready(Some(self.as_mut().take()));
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let cap = self.items.capacity();
+        let chunks = |len: usize| len / cap + if len % cap == 0 { 0 } else { 1 };
+        let len = self.items.len();
+        let (lower, upper) = self.stream.size_hint();
+        let lower = chunks(lower.saturating_add(len));
+        // The timer may split the items into chunks of a single item each
+        let upper = upper.and_then(|x| x.checked_add(len));
+        (lower, upper)
+    }
+}
+
+/* TODO
+// Forwarding impl of Sink from the underlying stream
+impl<S, F, T> Sink for ChunksTimeout<S, F, T>
+    where S: Sink + Stream
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream);
+}
+*/
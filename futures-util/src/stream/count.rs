@@ -0,0 +1,38 @@
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A future which counts the items of a stream.
+///
+/// This future is returned by the `Stream::count` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Count<St> {
+    stream: St,
+    count: usize,
+}
+
+impl<St: Unpin> Unpin for Count<St> {}
+
+impl<St: Stream> Count<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(count: usize);
+
+    pub(super) fn new(stream: St) -> Count<St> {
+        Count { stream, count: 0 }
+    }
+}
+
+impl<St: Stream> Future for Count<St> {
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<usize> {
+        while ready!(self.stream().poll_next(lw)).is_some() {
+            *self.count() += 1;
+        }
+        Poll::Ready(self.count)
+    }
+}
@@ -0,0 +1,147 @@
+use core::marker::Unpin;
+use core::mem;
+use core::pin::Pin;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`cycle`](super::StreamExt::cycle) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Cycle<St> {
+    orig: St,
+    stream: St,
+    // Whether the current pass through the stream has yielded an item
+    yielded: bool,
+}
+
+impl<St: Unpin> Unpin for Cycle<St> {}
+
+impl<St: Stream + Clone> Cycle<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(yielded: bool);
+
+    pub(super) fn new(stream: St) -> Cycle<St> {
+        Cycle {
+            orig: stream.clone(),
+            stream,
+            yielded: false,
+        }
+    }
+}
+
+impl<St: Stream + Clone> Stream for Cycle<St> {
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<St::Item>> {
+        loop {
+            if let Some(item) = ready!(self.stream().poll_next(lw)) {
+                *self.yielded() = true;
+                return Poll::Ready(Some(item));
+            }
+
+            let stream = self.orig.clone();
+            Pin::set(self.stream(), stream);
+            // Stop instead of spinning on a stream that has no items
+            if !mem::replace(self.yielded(), false) {
+                return Poll::Ready(None);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.orig.size_hint() {
+            (0, Some(0)) => (0, Some(0)),
+            (0, _) => (0, None),
+            _ => (usize::max_value(), None),
+        }
+    }
+}
+
+/// Stream for the [`cycle_with`] function.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct CycleWith<F, St> {
+    f: F,
+    stream: Option<St>,
+    // Whether the current pass through the stream has yielded an item
+    yielded: bool,
+}
+
+impl<F, St: Unpin> Unpin for CycleWith<F, St> {}
+
+/// Creates a stream which repeatedly creates a stream with `f` and yields
+/// its items, creating the next one whenever the current one ends.
+///
+/// This is like [`StreamExt::cycle`](super::StreamExt::cycle) for streams
+/// that can't be cloned but can be constructed again. The first stream is
+/// created when the returned stream is first polled.
+///
+/// The returned stream ends once a newly created stream ends without
+/// yielding any items, so an empty stream results in an empty stream rather
+/// than in `f` being called in a loop.
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::stream::{self, StreamExt};
+///
+/// let mut next = 0;
+/// let stream = stream::cycle_with(|| {
+///     next += 1;
+///     stream::iter(vec![next; 2])
+/// });
+///
+/// let result: Vec<_> = block_on(stream.take(5).collect());
+/// assert_eq!(result, vec![1, 1, 2, 2, 3]);
+/// ```
+pub fn cycle_with<F, St>(f: F) -> CycleWith<F, St>
+    where F: FnMut() -> St,
+          St: Stream,
+{
+    CycleWith {
+        f,
+        stream: None,
+        yielded: false,
+    }
+}
+
+impl<F, St> CycleWith<F, St>
+    where F: FnMut() -> St,
+          St: Stream,
+{
+    unsafe_unpinned!(f: F);
+    unsafe_pinned!(stream: Option<St>);
+    unsafe_unpinned!(yielded: bool);
+}
+
+impl<F, St> Stream for CycleWith<F, St>
+    where F: FnMut() -> St,
+          St: Stream,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<St::Item>> {
+        loop {
+            if let Some(stream) = self.stream().as_pin_mut() {
+                if let Some(item) = ready!(stream.poll_next(lw)) {
+                    *self.yielded() = true;
+                    return Poll::Ready(Some(item));
+                }
+                // A pass without any items would repeat forever
+                if !mem::replace(self.yielded(), false) {
+                    Pin::set(self.stream(), None);
+                    return Poll::Ready(None);
+                }
+            }
+
+            let stream = (self.f())();
+            Pin::set(self.stream(), Some(stream));
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A stream which only yields the last item of a burst, once no new item has
+/// arrived for the duration of a timer.
+///
+/// This stream is returned by the `Stream::debounce` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Debounce<St: Stream, F, T> {
+    stream: St,
+    make_timer: F,
+    timer: Option<T>,
+    // The most recent item, waiting for the timer to fire
+    pending: Option<St::Item>,
+    done: bool,
+}
+
+impl<St: Stream + Unpin, F, T: Unpin> Unpin for Debounce<St, F, T> {}
+
+impl<St, F, T> Debounce<St, F, T>
+    where St: Stream,
+          F: FnMut() -> T,
+          T: Future<Output = ()>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(make_timer: F);
+    unsafe_pinned!(timer: Option<T>);
+    unsafe_unpinned!(pending: Option<St::Item>);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, make_timer: F) -> Debounce<St, F, T> {
+        Debounce {
+            stream,
+            make_timer,
+            timer: None,
+            pending: None,
+            done: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, F, T> Stream for Debounce<St, F, T>
+    where St: Stream,
+          F: FnMut() -> T,
+          T: Future<Output = ()>,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<St::Item>> {
+        // Take in everything the stream has ready, restarting the timer for
+        // every item
+        while !self.done {
+            match self.stream().poll_next(lw) {
+                Poll::Ready(Some(item)) => {
+                    *self.pending() = Some(item);
+                    let timer = (self.make_timer())();
+                    Pin::set(self.timer(), Some(timer));
+                }
+                Poll::Ready(None) => *self.done() = true,
+                Poll::Pending => break,
+            }
+        }
+
+        // Flush the last item right away once the stream has ended
+        if self.done {
+            Pin::set(self.timer(), None);
+            return Poll::Ready(self.pending().take());
+        }
+
+        match self.timer().as_pin_mut() {
+            Some(timer) => ready!(timer.poll(lw)),
+            None => return Poll::Pending,
+        }
+        Pin::set(self.timer(), None);
+        Poll::Ready(self.pending().take())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending_len = if self.pending.is_some() { 1 } else { 0 };
+        let (lower, upper) = if self.done {
+            (0, Some(0))
+        } else {
+            self.stream.size_hint()
+        };
+        let lower = if lower > 0 || pending_len > 0 { 1 } else { 0 };
+        let upper = match upper {
+            Some(x) => x.checked_add(pending_len),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+/* TODO
+// Forwarding impl of Sink from the underlying stream
+impl<St, F, T> Sink for Debounce<St, F, T>
+    where St: Sink + Stream
+{
+    type SinkItem = St::SinkItem;
+    type SinkError = St::SinkError;
+
+    delegate_sink!(stream);
+}
+*/
@@ -0,0 +1,311 @@
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+// At least one item is yielded if nothing has been yielded yet and the
+// underlying stream has items left, but any of them may be a duplicate of the
+// last item otherwise.
+fn dedup_size_hint(yielded: bool, hint: (usize, Option<usize>)) -> (usize, Option<usize>) {
+    let (lower, upper) = hint;
+    let lower = if yielded { 0 } else { lower.min(1) };
+    (lower, upper)
+}
+
+/// A stream combinator which skips consecutive duplicate items.
+///
+/// This structure is produced by the `Stream::dedup` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Dedup<St: Stream> {
+    stream: St,
+    last: Option<St::Item>,
+}
+
+impl<St: Stream + Unpin> Unpin for Dedup<St> {}
+
+impl<St: Stream> Dedup<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(last: Option<St::Item>);
+
+    pub(super) fn new(stream: St) -> Dedup<St> {
+        Dedup {
+            stream,
+            last: None,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St> Stream for Dedup<St>
+    where St: Stream,
+          St::Item: PartialEq + Clone,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<St::Item>> {
+        loop {
+            match ready!(self.stream().poll_next(lw)) {
+                Some(item) => {
+                    if self.last.as_ref() == Some(&item) {
+                        continue;
+                    }
+                    *self.last() = Some(item.clone());
+                    return Poll::Ready(Some(item));
+                }
+                None => {
+                    *self.last() = None;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        dedup_size_hint(self.last.is_some(), self.stream.size_hint())
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S> Sink for Dedup<S>
+    where S: Stream + Sink
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream);
+}
+
+/// A stream combinator which skips consecutive items that the given closure
+/// considers to be duplicates.
+///
+/// This structure is produced by the `Stream::dedup_by` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct DedupBy<St: Stream, F> {
+    stream: St,
+    f: F,
+    last: Option<St::Item>,
+}
+
+impl<St: Stream + Unpin, F> Unpin for DedupBy<St, F> {}
+
+impl<St, F> DedupBy<St, F>
+    where St: Stream,
+          F: FnMut(&St::Item, &St::Item) -> bool,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(last: Option<St::Item>);
+
+    pub(super) fn new(stream: St, f: F) -> DedupBy<St, F> {
+        DedupBy {
+            stream,
+            f,
+            last: None,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, F> Stream for DedupBy<St, F>
+    where St: Stream,
+          St::Item: Clone,
+          F: FnMut(&St::Item, &St::Item) -> bool,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<St::Item>> {
+        loop {
+            match ready!(self.stream().poll_next(lw)) {
+                Some(item) => {
+                    let duplicate = match self.last().take() {
+                        Some(last) => {
+                            let duplicate = (self.f())(&last, &item);
+                            *self.last() = Some(last);
+                            duplicate
+                        }
+                        None => false,
+                    };
+                    if duplicate {
+                        continue;
+                    }
+                    *self.last() = Some(item.clone());
+                    return Poll::Ready(Some(item));
+                }
+                None => {
+                    *self.last() = None;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        dedup_size_hint(self.last.is_some(), self.stream.size_hint())
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, F> Sink for DedupBy<S, F>
+    where S: Stream + Sink,
+          F: FnMut(&S::Item, &S::Item) -> bool,
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream);
+}
+
+/// A stream combinator which skips consecutive items that map to the same
+/// key.
+///
+/// This structure is produced by the `Stream::dedup_by_key` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct DedupByKey<St, F, K> {
+    stream: St,
+    f: F,
+    last_key: Option<K>,
+}
+
+impl<St: Unpin, F, K> Unpin for DedupByKey<St, F, K> {}
+
+impl<St, F, K> DedupByKey<St, F, K>
+    where St: Stream,
+          F: FnMut(&St::Item) -> K,
+          K: PartialEq,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(last_key: Option<K>);
+
+    pub(super) fn new(stream: St, f: F) -> DedupByKey<St, F, K> {
+        DedupByKey {
+            stream,
+            f,
+            last_key: None,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, F, K> Stream for DedupByKey<St, F, K>
+    where St: Stream,
+          F: FnMut(&St::Item) -> K,
+          K: PartialEq,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<St::Item>> {
+        loop {
+            match ready!(self.stream().poll_next(lw)) {
+                Some(item) => {
+                    let key = (self.f())(&item);
+                    if self.last_key.as_ref() == Some(&key) {
+                        continue;
+                    }
+                    *self.last_key() = Some(key);
+                    return Poll::Ready(Some(item));
+                }
+                None => {
+                    *self.last_key() = None;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        dedup_size_hint(self.last_key.is_some(), self.stream.size_hint())
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, F, K> Sink for DedupByKey<S, F, K>
+    where S: Stream + Sink,
+          F: FnMut(&S::Item) -> K,
+          K: PartialEq,
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream);
+}
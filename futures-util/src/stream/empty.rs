@@ -21,6 +21,12 @@ pub fn empty<T>() -> Empty<T> {
     }
 }
 
+impl<T> Clone for Empty<T> {
+    fn clone(&self) -> Self {
+        empty()
+    }
+}
+
 impl<T> Unpin for Empty<T> {}
 
 impl<T> Stream for Empty<T> {
@@ -29,4 +35,8 @@ impl<T> Stream for Empty<T> {
     fn poll_next(self: Pin<&mut Self>, _: &LocalWaker) -> Poll<Option<Self::Item>> {
         Poll::Ready(None)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
 }
@@ -0,0 +1,135 @@
+use crate::stream::{Fuse, FuturesUnordered, StreamExt, StreamFuture};
+use core::num::NonZeroUsize;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::fmt;
+use std::marker::Unpin;
+use std::pin::Pin;
+
+/// A stream combinator which flattens a stream of streams, polling the inner
+/// streams concurrently.
+///
+/// This structure is returned by the
+/// [`StreamExt::flatten_unordered`](super::StreamExt::flatten_unordered)
+/// method.
+#[must_use = "streams do nothing unless polled"]
+pub struct FlattenUnordered<St>
+where
+    St: Stream,
+    St::Item: Stream + Unpin,
+{
+    stream: Fuse<St>,
+    in_progress_queue: FuturesUnordered<StreamFuture<St::Item>>,
+    limit: Option<NonZeroUsize>,
+}
+
+impl<St> Unpin for FlattenUnordered<St>
+where
+    St: Stream + Unpin,
+    St::Item: Stream + Unpin,
+{}
+
+impl<St> fmt::Debug for FlattenUnordered<St>
+where
+    St: Stream + fmt::Debug,
+    St::Item: Stream + Unpin + fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("FlattenUnordered")
+            .field("stream", &self.stream)
+            .field("in_progress_queue", &self.in_progress_queue)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<St> FlattenUnordered<St>
+where
+    St: Stream,
+    St::Item: Stream + Unpin,
+{
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_unpinned!(in_progress_queue: FuturesUnordered<StreamFuture<St::Item>>);
+
+    pub(super) fn new(stream: St, limit: Option<usize>) -> FlattenUnordered<St> {
+        FlattenUnordered {
+            stream: super::Fuse::new(stream),
+            in_progress_queue: FuturesUnordered::new(),
+            // Note: `limit` = 0 gets ignored.
+            limit: limit.and_then(NonZeroUsize::new),
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+}
+
+impl<St> Stream for FlattenUnordered<St>
+where
+    St: Stream,
+    St::Item: Stream + Unpin,
+{
+    type Item = <St::Item as Stream>::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            // Take on as many inner streams as the limit allows
+            loop {
+                let current_len = self.in_progress_queue.len();
+                if !self.limit.map(|limit| limit.get() > current_len).unwrap_or(true) {
+                    break;
+                }
+                match self.stream().poll_next(lw) {
+                    Poll::Ready(Some(inner)) => {
+                        self.in_progress_queue().push(inner.into_future())
+                    }
+                    Poll::Ready(None) | Poll::Pending => break,
+                }
+            }
+
+            match Pin::new(self.in_progress_queue()).poll_next(lw) {
+                Poll::Ready(Some((Some(item), inner))) => {
+                    // Requeue the inner stream to wait for its next item
+                    self.in_progress_queue().push(inner.into_future());
+                    return Poll::Ready(Some(item));
+                }
+                // An inner stream has ended, which may leave room for
+                // another one
+                Poll::Ready(Some((None, _))) => continue,
+                Poll::Ready(None) => break,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        // If more inner streams are still coming, we're not done yet
+        if self.stream.is_done() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Whether a [`FoldWhile`] should keep on folding, returned by its closure.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ControlFlow<T> {
+    /// Continue with the next item, using the given accumulated value.
+    Continue(T),
+    /// Stop right away and resolve to the given accumulated value.
+    Break(T),
+}
+
+/// A future used to collect the results of a stream into one generic type
+/// until the accumulating closure breaks off.
+///
+/// This future is returned by the `Stream::fold_while` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct FoldWhile<St, Fut, T, F> {
+    stream: St,
+    f: F,
+    accum: Option<T>,
+    future: Option<Fut>,
+}
+
+impl<St: Unpin, Fut: Unpin, T, F> Unpin for FoldWhile<St, Fut, T, F> {}
+
+impl<St, Fut, T, F> FoldWhile<St, Fut, T, F>
+where St: Stream,
+      F: FnMut(T, St::Item) -> Fut,
+      Fut: Future<Output = ControlFlow<T>>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(accum: Option<T>);
+    unsafe_pinned!(future: Option<Fut>);
+
+    pub(super) fn new(stream: St, f: F, t: T) -> FoldWhile<St, Fut, T, F> {
+        FoldWhile {
+            stream,
+            f,
+            accum: Some(t),
+            future: None,
+        }
+    }
+}
+
+impl<St, Fut, T, F> Future for FoldWhile<St, Fut, T, F>
+    where St: Stream,
+          F: FnMut(T, St::Item) -> Fut,
+          Fut: Future<Output = ControlFlow<T>>,
+{
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<T> {
+        loop {
+            // we're currently processing a future to produce a new accum value
+            if self.accum().is_none() {
+                let flow = ready!(self.future().as_pin_mut()
+                    .expect("FoldWhile polled after completion")
+                    .poll(lw));
+                Pin::set(self.future(), None);
+                match flow {
+                    ControlFlow::Continue(accum) => *self.accum() = Some(accum),
+                    // The stream isn't polled for any further items
+                    ControlFlow::Break(accum) => return Poll::Ready(accum),
+                }
+            }
+
+            let item = ready!(self.stream().poll_next(lw));
+            let accum = self.accum().take().unwrap();
+
+            if let Some(e) = item {
+                let future = (self.f())(accum, e);
+                Pin::set(self.future(), Some(future));
+            } else {
+                return Poll::Ready(accum)
+            }
+        }
+    }
+}
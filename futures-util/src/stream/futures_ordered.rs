@@ -14,7 +14,8 @@ use std::pin::Pin;
 #[derive(Debug)]
 struct OrderWrapper<T> {
     data: T, // A future or a future's output
-    index: usize,
+    // Can be negative after `push_front`
+    index: isize,
 }
 
 impl<T> PartialEq for OrderWrapper<T> {
@@ -70,21 +71,28 @@ impl<T> Future for OrderWrapper<T>
 /// large numbers of futures.
 ///
 /// When a `FuturesOrdered` is first created, it does not contain any futures.
-/// Calling `poll` in this state will result in `Poll::Ready(None))` to be
+/// Calling `poll_next` in this state will result in `Poll::Ready(None)` to be
 /// returned. Futures are submitted to the queue using `push`; however, the
 /// future will **not** be polled at this point. `FuturesOrdered` will only
-/// poll managed futures when `FuturesOrdered::poll` is called. As such, it
-/// is important to call `poll` after pushing new futures.
+/// poll managed futures when `FuturesOrdered::poll_next` is called. As such,
+/// it is important to call `poll_next` after pushing new futures.
 ///
-/// If `FuturesOrdered::poll` returns `Poll::Ready(None)` this means that
+/// If `FuturesOrdered::poll_next` returns `Poll::Ready(None)` this means that
 /// the queue is currently not managing any futures. A future may be submitted
 /// to the queue at a later time. At that point, a call to
-/// `FuturesOrdered::poll` will either return the future's resolved value
+/// `FuturesOrdered::poll_next` will either return the future's resolved value
 /// **or** `Poll::Pending` if the future has not yet completed. When
-/// multiple futures are submitted to the queue, `FuturesOrdered::poll` will
-/// return `Poll::Pending` until the first future completes, even if
+/// multiple futures are submitted to the queue, `FuturesOrdered::poll_next`
+/// will return `Poll::Pending` until the first future completes, even if
 /// some of the later futures have already completed.
 ///
+/// Outputs of futures that complete out of order are kept in an internal
+/// buffer until all earlier futures have completed. This means that a single
+/// slow future at the front of the queue can make the buffer grow up to the
+/// number of futures in the queue, and each buffered output is held in memory
+/// until it's yielded. [`len`](FuturesOrdered::len) includes those buffered
+/// outputs.
+///
 /// Note that you can create a ready-made `FuturesOrdered` via the
 /// `futures_ordered` function in the `stream` module, or you can start with an
 /// empty queue with the `FuturesOrdered::new` constructor.
@@ -92,8 +100,8 @@ impl<T> Future for OrderWrapper<T>
 pub struct FuturesOrdered<T: Future> {
     in_progress_queue: FuturesUnordered<OrderWrapper<T>>,
     queued_outputs: BinaryHeap<OrderWrapper<T::Output>>,
-    next_incoming_index: usize,
-    next_outgoing_index: usize,
+    next_incoming_index: isize,
+    next_outgoing_index: isize,
 }
 
 impl<T: Future> Unpin for FuturesOrdered<T> {}
@@ -121,7 +129,7 @@ impl<Fut: Future> FuturesOrdered<Fut> {
     /// Constructs a new, empty `FuturesOrdered`
     ///
     /// The returned `FuturesOrdered` does not contain any futures and, in this
-    /// state, `FuturesOrdered::poll` will return `Ok(Async::Ready(None))`.
+    /// state, `FuturesOrdered::poll_next` will return `Poll::Ready(None)`.
     pub fn new() -> FuturesOrdered<Fut> {
         FuturesOrdered {
             in_progress_queue: FuturesUnordered::new(),
@@ -147,11 +155,19 @@ impl<Fut: Future> FuturesOrdered<Fut> {
 
     /// Push a future into the queue.
     ///
+    /// This is the same as [`push_back`](FuturesOrdered::push_back).
+    pub fn push(&mut self, future: Fut) {
+        self.push_back(future)
+    }
+
+    /// Push a future to the back of the queue, so that its output is yielded
+    /// after the outputs of all futures currently in the queue.
+    ///
     /// This function submits the given future to the internal set for managing.
     /// This function will not call `poll` on the submitted future. The caller
-    /// must ensure that `FuturesOrdered::poll` is called in order to receive
+    /// must ensure that `FuturesOrdered::poll_next` is called in order to receive
     /// task notifications.
-    pub fn push(&mut self, future: Fut) {
+    pub fn push_back(&mut self, future: Fut) {
         let wrapped = OrderWrapper {
             data: future,
             index: self.next_incoming_index,
@@ -159,6 +175,27 @@ impl<Fut: Future> FuturesOrdered<Fut> {
         self.next_incoming_index += 1;
         self.in_progress_queue.push(wrapped);
     }
+
+    /// Push a future to the front of the queue, so that its output is yielded
+    /// before the outputs of all futures currently in the queue.
+    ///
+    /// The position only affects the order of the outputs, not the order in
+    /// which the futures are polled. If futures further back complete before
+    /// this one, their outputs are buffered until this future has completed
+    /// and its output has been yielded, as for any other future at the front
+    /// of the queue.
+    ///
+    /// This function will not call `poll` on the submitted future. The caller
+    /// must ensure that `FuturesOrdered::poll_next` is called in order to
+    /// receive task notifications.
+    pub fn push_front(&mut self, future: Fut) {
+        self.next_outgoing_index -= 1;
+        let wrapped = OrderWrapper {
+            data: future,
+            index: self.next_outgoing_index,
+        };
+        self.in_progress_queue.push(wrapped);
+    }
 }
 
 impl<Fut: Future> Default for FuturesOrdered<Fut> {
@@ -0,0 +1,61 @@
+use std::fmt;
+use std::sync::Weak;
+use std::sync::atomic::Ordering::SeqCst;
+
+use super::task::Task;
+
+/// A handle to a future in a [`FuturesUnordered`](super::FuturesUnordered)
+/// which allows canceling it.
+///
+/// This is created by the
+/// [`FuturesUnordered::push_cancelable`](super::FuturesUnordered::push_cancelable)
+/// method.
+pub struct CancelHandle<Fut> {
+    task: Weak<Task<Fut>>,
+}
+
+// The handle only touches the atomics of the task and never the future
+// itself, so it can be sent across threads regardless of `Fut`.
+unsafe impl<Fut> Send for CancelHandle<Fut> {}
+unsafe impl<Fut> Sync for CancelHandle<Fut> {}
+
+impl<Fut> CancelHandle<Fut> {
+    pub(super) fn new(task: Weak<Task<Fut>>) -> CancelHandle<Fut> {
+        CancelHandle { task }
+    }
+
+    /// Cancels the future associated with this handle.
+    ///
+    /// The future no longer counts towards the set's
+    /// [`len`](super::FuturesUnordered::len) once this returns. It's dropped,
+    /// and removed from the set, the next time the set is polled. This wakes
+    /// up the task polling the set so that this happens promptly. If the
+    /// future has already completed, or the set has been dropped, this does
+    /// nothing.
+    pub fn cancel(&self) {
+        let task = match self.task.upgrade() {
+            Some(task) => task,
+            None => return,
+        };
+        let queue = match task.ready_to_run_queue.upgrade() {
+            Some(queue) => queue,
+            None => return,
+        };
+
+        // Count the task before marking it, so that the set never uncounts
+        // it first. If it was marked already, it was either canceled before
+        // or has been released by the set.
+        queue.canceled.fetch_add(1, SeqCst);
+        if task.canceled.swap(true, SeqCst) {
+            queue.canceled.fetch_sub(1, SeqCst);
+        } else {
+            task.wake();
+        }
+    }
+}
+
+impl<Fut> fmt::Debug for CancelHandle<Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CancelHandle").finish()
+    }
+}
@@ -51,3 +51,73 @@ impl<'a, Fut: Unpin> Iterator for IterMut<'a, Fut> {
 }
 
 impl<Fut: Unpin> ExactSizeIterator for IterMut<'_, Fut> {}
+
+#[derive(Debug)]
+/// Immutable iterator over all futures in the unordered set.
+pub struct Iter<'a, Fut: 'a> {
+    pub(super) task: *const Task<Fut>,
+    pub(super) len: usize,
+    pub(super) _marker: PhantomData<&'a FuturesUnordered<Fut>>
+}
+
+impl<'a, Fut> Iterator for Iter<'a, Fut> {
+    type Item = &'a Fut;
+
+    fn next(&mut self) -> Option<&'a Fut> {
+        if self.task.is_null() {
+            return None;
+        }
+        unsafe {
+            let future = (*(*self.task).future.get()).as_ref().unwrap();
+            let next = *(*self.task).next_all.get();
+            self.task = next;
+            self.len -= 1;
+            Some(future)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<Fut> ExactSizeIterator for Iter<'_, Fut> {}
+
+#[derive(Debug)]
+/// Draining iterator over all futures in the unordered set.
+///
+/// This is created by the
+/// [`FuturesUnordered::drain`](super::FuturesUnordered::drain) method.
+pub struct Drain<'a, Fut: Unpin + 'a> {
+    pub(super) queue: &'a mut FuturesUnordered<Fut>,
+}
+
+impl<'a, Fut: Unpin> Iterator for Drain<'a, Fut> {
+    type Item = Fut;
+
+    fn next(&mut self) -> Option<Fut> {
+        let head = self.queue.head_all;
+        if head.is_null() {
+            return None;
+        }
+        unsafe {
+            let task = self.queue.unlink(head);
+            // Moving the future out is fine because it's `Unpin`.
+            let future = (*task.future.get()).take();
+            self.queue.release_task(task);
+            future
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.queue.len, Some(self.queue.len))
+    }
+}
+
+impl<Fut: Unpin> ExactSizeIterator for Drain<'_, Fut> {}
+
+impl<Fut: Unpin> Drop for Drain<'_, Fut> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
@@ -11,19 +11,26 @@ use std::marker::{PhantomData, Unpin};
 use std::mem;
 use std::pin::Pin;
 use std::ptr;
-use std::sync::atomic::Ordering::SeqCst;
-use std::sync::atomic::{AtomicPtr, AtomicBool};
+use std::sync::atomic::Ordering::{Relaxed, SeqCst};
+use std::sync::atomic::{AtomicPtr, AtomicBool, AtomicUsize};
 use std::sync::{Arc, Weak};
 use std::usize;
+use std::vec;
 
 mod abort;
 
 mod iter;
-use self::iter::{IterMut, IterPinMut};
+use self::iter::{Drain, Iter, IterMut, IterPinMut};
 
 mod task;
 use self::task::Task;
 
+mod cancel_handle;
+pub use self::cancel_handle::CancelHandle;
+
+mod queue_handle;
+pub use self::queue_handle::QueueHandle;
+
 mod ready_to_run_queue;
 use self::ready_to_run_queue::{ReadyToRunQueue, Dequeue};
 
@@ -44,13 +51,34 @@ use self::ready_to_run_queue::{ReadyToRunQueue, Dequeue};
 /// Note that you can create a ready-made [`FuturesUnordered`] via the
 /// [`futures_unordered`](futures_unordered()) function, or you can start with
 /// an empty set with the [`FuturesUnordered::new`] constructor.
+///
+/// # Panics
+///
+/// If a future panics while it's being polled, the panic is propagated to the
+/// caller of [`poll_next`](Stream::poll_next). Before that, the panicking
+/// future is dropped and removed from the set, so the set remains usable and
+/// the remaining futures can still be polled to completion.
 #[must_use = "streams do nothing unless polled"]
 pub struct FuturesUnordered<Fut> {
     ready_to_run_queue: Arc<ReadyToRunQueue<Fut>>,
     len: usize,
     head_all: *const Task<Fut>,
+    next_key: usize,
+    capacity: usize,
+    poll_budget: Option<usize>,
+    is_terminated: bool,
+    fused: bool,
 }
 
+/// An opaque key identifying a future pushed into a [`FuturesUnordered`].
+///
+/// Keys are returned by
+/// [`push_with_key`](FuturesUnordered::push_with_key) and handed back
+/// together with the future's output by
+/// [`poll_next_with_key`](FuturesUnordered::poll_next_with_key).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QueueKey(usize);
+
 unsafe impl<Fut: Send> Send for FuturesUnordered<Fut> {}
 unsafe impl<Fut: Sync> Sync for FuturesUnordered<Fut> {}
 impl<Fut> Unpin for FuturesUnordered<Fut> {}
@@ -110,6 +138,9 @@ impl<Fut: Future> FuturesUnordered<Fut> {
             next_ready_to_run: AtomicPtr::new(ptr::null_mut()),
             queued: AtomicBool::new(true),
             ready_to_run_queue: Weak::new(),
+            key: 0,
+            canceled: AtomicBool::new(false),
+            woke_itself: AtomicBool::new(false),
         });
         let stub_ptr = &*stub as *const Task<Fut>;
         let ready_to_run_queue = Arc::new(ReadyToRunQueue {
@@ -117,16 +148,411 @@ impl<Fut: Future> FuturesUnordered<Fut> {
             head: AtomicPtr::new(stub_ptr as *mut _),
             tail: UnsafeCell::new(stub_ptr),
             stub,
+            injected: AtomicPtr::new(ptr::null_mut()),
+            canceled: AtomicUsize::new(0),
         });
 
         FuturesUnordered {
             len: 0,
             head_all: ptr::null_mut(),
             ready_to_run_queue,
+            next_key: 0,
+            capacity: usize::MAX,
+            poll_budget: None,
+            is_terminated: false,
+            fused: false,
+        }
+    }
+
+    /// Constructs a new, empty [`FuturesUnordered`] which can't be refilled
+    /// once it has terminated.
+    ///
+    /// A regular [`FuturesUnordered`] may be reused after
+    /// [`poll_next`](Stream::poll_next) returned
+    /// [`Poll::Ready(None)`](Poll::Ready): pushing another future makes it
+    /// yield items again. This is a problem for code that stops polling a
+    /// stream after it ended. A fused set keeps returning
+    /// [`Poll::Ready(None)`](Poll::Ready) once it has terminated, and rejects
+    /// all further futures: [`try_push`](FuturesUnordered::try_push) hands
+    /// them back to the caller, the other methods that add futures panic,
+    /// and futures pushed through a [`QueueHandle`] are dropped.
+    pub fn fused() -> FuturesUnordered<Fut> {
+        let mut set = FuturesUnordered::new();
+        set.fused = true;
+        set
+    }
+
+    /// Constructs a new, empty [`FuturesUnordered`] which admits at most
+    /// `capacity` futures through
+    /// [`try_push`](FuturesUnordered::try_push).
+    ///
+    /// This makes it possible to apply backpressure to producers of futures
+    /// without keeping a separate counter. Note that
+    /// [`push`](FuturesUnordered::push) and the other infallible methods for
+    /// adding futures don't check the bound.
+    pub fn bounded(capacity: usize) -> FuturesUnordered<Fut> {
+        let mut set = FuturesUnordered::new();
+        set.capacity = capacity;
+        set
+    }
+
+    /// Constructs a new, empty [`FuturesUnordered`] which polls at most
+    /// `budget` futures per call to [`poll_next`](Stream::poll_next).
+    ///
+    /// By default, [`poll_next`](Stream::poll_next) keeps polling futures
+    /// until one of them completes or no futures are ready anymore. When many
+    /// futures are ready at once, or when futures keep waking themselves,
+    /// this can starve other tasks running on the same thread. With a budget,
+    /// once `budget` futures returned [`Poll::Pending`] in a single call, the
+    /// current task is woken and [`Poll::Pending`] is returned. The remaining
+    /// ready futures are polled on the next call.
+    ///
+    /// The budget can also be changed later with
+    /// [`set_poll_budget`](FuturesUnordered::set_poll_budget).
+    pub fn with_poll_budget(budget: usize) -> FuturesUnordered<Fut> {
+        let mut set = FuturesUnordered::new();
+        set.poll_budget = Some(budget);
+        set
+    }
+
+    /// Attempt to pull out the next output of the set, together with the
+    /// [`QueueKey`] of the future that produced it.
+    ///
+    /// This behaves exactly like [`poll_next`](Stream::poll_next), except
+    /// that each output is paired with the key that was returned by
+    /// [`push_with_key`](FuturesUnordered::push_with_key) when its future
+    /// was added. Futures added through [`push`](FuturesUnordered::push) are
+    /// assigned a key as well, it's simply not returned to the caller.
+    pub fn poll_next_with_key(mut self: Pin<&mut Self>, lw: &LocalWaker)
+        -> Poll<Option<(QueueKey, Fut::Output)>>
+    {
+        // Ensure `parent` is correctly set.
+        self.ready_to_run_queue.waker.register(lw);
+
+        self.push_injected();
+
+        // Number of futures polled in this call that didn't complete
+        let mut polled = 0;
+
+        loop {
+            // Safety: &mut self guarantees the mutual exclusion `dequeue`
+            // expects
+            let task = match unsafe { self.ready_to_run_queue.dequeue() } {
+                Dequeue::Empty => {
+                    if self.is_empty() {
+                        self.is_terminated = true;
+                        return Poll::Ready(None);
+                    } else {
+                        return Poll::Pending;
+                    }
+                }
+                Dequeue::Inconsistent => {
+                    // At this point, it may be worth yielding the thread &
+                    // spinning a few times... but for now, just yield using the
+                    // task system.
+                    lw.wake();
+                    return Poll::Pending;
+                }
+                Dequeue::Data(task) => task,
+            };
+
+            // Safety: `task` was just dequeued
+            match unsafe { self.poll_dequeued(task) } {
+                PollTask::Released => continue,
+                PollTask::Pending { .. } => {
+                    // If we're out of budget, yield back to the executor. The
+                    // remaining tasks are still in the ready to run queue, so
+                    // waking ourselves ensures that they get polled later.
+                    polled += 1;
+                    let budget = self.poll_budget;
+                    if budget.map_or(false, |budget| polled >= budget) {
+                        lw.wake();
+                        return Poll::Pending;
+                    }
+                    continue
+                }
+                PollTask::Ready(key, output) => {
+                    return Poll::Ready(Some((key, output)))
+                }
+            }
+        }
+    }
+
+    /// Polls each future that is ready to run right now exactly once,
+    /// passing the outputs of completed futures to `f`, and returns how many
+    /// futures completed.
+    ///
+    /// Unlike [`poll_next`](Stream::poll_next), this doesn't register a waker
+    /// for the current task, which makes it possible to drive the set from a
+    /// custom event loop that tracks readiness by other means. Wake-up
+    /// notifications still go to the waker registered by the last call to
+    /// [`poll_next`](Stream::poll_next), if there was one.
+    ///
+    /// The set of futures to poll is determined when this method is called.
+    /// Futures that receive a wake-up notification while this method is
+    /// running, including futures that were already polled during this call,
+    /// are not polled again. They are polled by the next call to this method
+    /// or to [`poll_next`](Stream::poll_next).
+    pub fn poll_ready_once<F>(&mut self, f: F) -> usize
+        where F: FnMut(Fut::Output)
+    {
+        self.poll_ready_once_counted(f).0
+    }
+
+    /// Polls each future that is ready to run right now exactly once, like
+    /// [`poll_ready_once`](FuturesUnordered::poll_ready_once), and returns
+    /// how many of the polled futures made progress.
+    ///
+    /// A future makes progress if it completes, or if it was woken up by
+    /// something other than itself, such as another future or an external
+    /// event. A future that wakes itself up during a poll and returns
+    /// `Pending` doesn't count as having made progress the next time it's
+    /// polled, unless it completes. Executors can use this to tell tasks that
+    /// are busy yielding to themselves apart from tasks that are being driven
+    /// by each other. A wake-up that arrives from another thread while the
+    /// future is being polled is mistaken for the future waking itself.
+    pub fn poll_ready_once_progress<F>(&mut self, f: F) -> usize
+        where F: FnMut(Fut::Output)
+    {
+        self.poll_ready_once_counted(f).1
+    }
+
+    // Returns how many futures completed and how many made progress
+    fn poll_ready_once_counted<F>(&mut self, mut f: F) -> (usize, usize)
+        where F: FnMut(Fut::Output)
+    {
+        // Take all tasks out of the ready to run queue up front. Tasks that
+        // are woken from now on are enqueued again and will be left alone by
+        // this call. If a concurrent enqueue leaves the queue in an
+        // inconsistent state, the affected tasks are picked up next time.
+        self.push_injected();
+        let mut ready = Vec::new();
+        loop {
+            // Safety: &mut self guarantees the mutual exclusion `dequeue`
+            // expects
+            match unsafe { self.ready_to_run_queue.dequeue() } {
+                Dequeue::Data(task) => ready.push(task),
+                Dequeue::Empty | Dequeue::Inconsistent => break,
+            }
+        }
+
+        // If polling a future (or `f`) panics, the tasks we haven't gotten
+        // to yet still have their `queued` flag set. Put them back into the
+        // ready to run queue, otherwise they would never be polled again.
+        struct Requeue<Fut> {
+            queue: Arc<ReadyToRunQueue<Fut>>,
+            tasks: vec::IntoIter<*const Task<Fut>>,
+        }
+
+        impl<Fut> Drop for Requeue<Fut> {
+            fn drop(&mut self) {
+                for task in &mut self.tasks {
+                    self.queue.enqueue(task);
+                }
+            }
+        }
+
+        let mut requeue = Requeue {
+            queue: self.ready_to_run_queue.clone(),
+            tasks: ready.into_iter(),
+        };
+
+        let (mut completed, mut progressed) = (0, 0);
+        while let Some(task) = requeue.tasks.next() {
+            // Safety: `task` was dequeued above
+            match unsafe { self.poll_dequeued(task) } {
+                PollTask::Released => {}
+                PollTask::Pending { self_woken } => {
+                    if !self_woken {
+                        progressed += 1;
+                    }
+                }
+                PollTask::Ready(_key, output) => {
+                    completed += 1;
+                    progressed += 1;
+                    f(output);
+                }
+            }
+        }
+        (completed, progressed)
+    }
+
+    /// Polls a single future that is ready to run right now, if there is
+    /// one.
+    ///
+    /// Returns `None` if no future is ready, and otherwise the result of
+    /// polling that future: [`Poll::Ready`] with its output if it completed,
+    /// [`Poll::Pending`] if it didn't. This makes it possible to step through
+    /// the futures one at a time.
+    ///
+    /// Like [`poll_next`](Stream::poll_next), this registers `lw` to be woken
+    /// up once a future in the set is woken up, so a caller that got `None`
+    /// can wait for that instead of calling this method in a loop.
+    pub fn poll_one_ready(&mut self, lw: &LocalWaker) -> Option<Poll<Fut::Output>> {
+        self.ready_to_run_queue.waker.register(lw);
+        self.push_injected();
+        loop {
+            // Safety: &mut self guarantees the mutual exclusion `dequeue`
+            // expects
+            let task = match unsafe { self.ready_to_run_queue.dequeue() } {
+                Dequeue::Data(task) => task,
+                // An inconsistent queue is picked up by the next call
+                Dequeue::Empty | Dequeue::Inconsistent => return None,
+            };
+
+            // Safety: `task` was just dequeued
+            match unsafe { self.poll_dequeued(task) } {
+                PollTask::Released => continue,
+                PollTask::Pending { .. } => return Some(Poll::Pending),
+                PollTask::Ready(_key, output) => return Some(Poll::Ready(output)),
+            }
+        }
+    }
+
+    /// Adds the futures that were pushed through a `QueueHandle` to the set.
+    fn push_injected(&mut self) {
+        let injected = self.ready_to_run_queue.take_injected();
+        if self.fused && self.is_terminated {
+            return;
+        }
+        for future in injected {
+            self.push(future);
+        }
+    }
+
+    /// Polls the future of a task that was just taken out of the ready to run
+    /// queue.
+    ///
+    /// This method is unsafe because `task` must be a valid pointer that was
+    /// returned by `dequeue`.
+    unsafe fn poll_dequeued(&mut self, task: *const Task<Fut>)
+        -> PollTask<Fut::Output>
+    {
+        debug_assert!(task != self.ready_to_run_queue.stub());
+
+        // Safety:
+        // - `task` is a valid pointer.
+        // - We are the only thread that accesses the `UnsafeCell` that
+        //   contains the future
+        let future = match &mut *(*task).future.get() {
+            Some(future) => future,
+
+            // If the future has already gone away then we're just
+            // cleaning out this task. See the comment in
+            // `release_task` for more information, but we're basically
+            // just taking ownership of our reference count here.
+            None => {
+                // This case only happens when `release_task` was called
+                // for this task before and couldn't drop the task
+                // because it was already enqueued in the ready to run
+                // queue.
+
+                // Safety: `task` is a valid pointer
+                let task = Arc::from_raw(task);
+
+                // Double check that the call to `release_task` really
+                // happened. Calling it required the task to be unlinked.
+                debug_assert!((*task.next_all.get()).is_null());
+                debug_assert!((*task.prev_all.get()).is_null());
+                return PollTask::Released
+            }
+        };
+
+        // Safety: `task` is a valid pointer
+        let task = self.unlink(task);
+
+        // Unset queued flag: This must be done before polling to ensure
+        // that the future's task gets rescheduled if it sends a wake-up
+        // notification **during** the call to `poll`.
+        let prev = task.queued.swap(false, SeqCst);
+        assert!(prev);
+        let self_woken = task.woke_itself.swap(false, Relaxed);
+
+        // A `CancelHandle` woke us up to get rid of this future. Drop it
+        // without polling it again.
+        if task.canceled.load(SeqCst) {
+            self.release_task(task);
+            return PollTask::Released
+        }
+
+        let key = QueueKey(task.key);
+
+        // We're going to need to be very careful if the `poll`
+        // method below panics. We need to (a) not leak memory and
+        // (b) ensure that we still don't have any use-after-frees. To
+        // manage this we do a few things:
+        //
+        // * A "bomb" is created which if dropped abnormally will call
+        //   `release_task`. That way we'll be sure the memory management
+        //   of the `task` is managed correctly. In particular
+        //   `release_task` will drop the future. This ensures that it is
+        //   dropped on this thread and not accidentally on a different
+        //   thread (bad).
+        // * We unlink the task from our internal queue to preemptively
+        //   assume it'll panic, in which case we'll want to discard it
+        //   regardless.
+        struct Bomb<'a, Fut: 'a> {
+            queue: &'a mut FuturesUnordered<Fut>,
+            task: Option<Arc<Task<Fut>>>,
+        }
+
+        impl<'a, Fut> Drop for Bomb<'a, Fut> {
+            fn drop(&mut self) {
+                if let Some(task) = self.task.take() {
+                    self.queue.release_task(task);
+                }
+            }
+        }
+
+        let mut bomb = Bomb {
+            task: Some(task),
+            queue: self,
+        };
+
+        // Poll the underlying future with the appropriate waker
+        // implementation. This is where a large bit of the unsafety
+        // starts to stem from internally. The waker is basically just
+        // our `Arc<Task<Fut>>` and can schedule the future for polling by
+        // enqueuing itself in the ready to run queue.
+        //
+        // Critically though `Task<Fut>` won't actually access `Fut`, the
+        // future, while it's floating around inside of wakers.
+        // These structs will basically just use `Fut` to size
+        // the internal allocation, appropriately accessing fields and
+        // deallocating the task if need be.
+        let res = {
+            let lw = bomb.task.as_ref().unwrap().local_waker();
+
+            // Safety: We won't move the future ever again
+            let future = Pin::new_unchecked(future);
+
+            future.poll(&lw)
+        };
+
+        match res {
+            Poll::Pending => {
+                let task = bomb.task.take().unwrap();
+                // Being queued already means the future woke itself up
+                task.woke_itself.store(task.queued.load(SeqCst), Relaxed);
+                bomb.queue.link(task);
+                PollTask::Pending { self_woken }
+            }
+            Poll::Ready(output) => PollTask::Ready(key, output),
         }
     }
 }
 
+/// The outcome of `FuturesUnordered::poll_dequeued`.
+enum PollTask<T> {
+    /// The task didn't have a future anymore, or its future was canceled.
+    Released,
+    /// The future was polled and is not done yet. `self_woken` is set if it
+    /// was only polled because it woke itself up during its previous poll.
+    Pending { self_woken: bool },
+    /// The future completed with the given output.
+    Ready(QueueKey, T),
+}
+
 impl<Fut: Future> Default for FuturesUnordered<Fut> {
     fn default() -> FuturesUnordered<Fut> {
         FuturesUnordered::new()
@@ -136,14 +562,81 @@ impl<Fut: Future> Default for FuturesUnordered<Fut> {
 impl<Fut> FuturesUnordered<Fut> {
     /// Returns the number of futures contained in the set.
     ///
-    /// This represents the total number of in-flight futures.
+    /// This represents the total number of in-flight futures. Futures that
+    /// were canceled through a [`CancelHandle`] aren't counted anymore, even
+    /// before they're dropped.
     pub fn len(&self) -> usize {
-        self.len
+        let canceled = self.ready_to_run_queue.canceled.load(SeqCst);
+        self.len.saturating_sub(canceled)
     }
 
     /// Returns `true` if the set contains no futures.
     pub fn is_empty(&self) -> bool {
-        self.len == 0
+        self.len() == 0
+    }
+
+    /// Sets the maximum number of futures polled per call to
+    /// [`poll_next`](Stream::poll_next) without one of them completing.
+    ///
+    /// By default this is unbounded. See
+    /// [`with_poll_budget`](FuturesUnordered::with_poll_budget) for details.
+    /// The budget is only checked after a future has been polled, so running
+    /// out of it never leaves a future behind that was taken out of the
+    /// ready to run queue without being polled.
+    pub fn set_poll_budget(&mut self, budget: usize) {
+        self.poll_budget = Some(budget);
+    }
+
+    /// Returns `true` if [`poll_next`](Stream::poll_next) returned
+    /// [`Poll::Ready(None)`](Poll::Ready) and no future has been pushed
+    /// since.
+    ///
+    /// Unless the set was created with [`fused`](FuturesUnordered::fused),
+    /// pushing a future resets this, and the set can be polled again.
+    pub fn is_terminated(&self) -> bool {
+        self.is_terminated
+    }
+
+    /// Returns how many more futures can be added through
+    /// [`try_push`](FuturesUnordered::try_push) before it starts rejecting
+    /// them.
+    ///
+    /// For sets that were not created with
+    /// [`bounded`](FuturesUnordered::bounded) this is effectively unlimited.
+    pub fn available_capacity(&self) -> usize {
+        self.capacity.saturating_sub(self.len())
+    }
+
+    /// Push a future into the set if doing so doesn't exceed its capacity.
+    ///
+    /// If the set already contains as many futures as it was
+    /// [`bounded`](FuturesUnordered::bounded) to, or if it's a
+    /// [`fused`](FuturesUnordered::fused) set that has terminated, the future
+    /// is handed back in the `Err` variant. Otherwise this behaves like
+    /// [`push`](FuturesUnordered::push).
+    pub fn try_push(&mut self, future: Fut) -> Result<(), Fut> {
+        if self.len() >= self.capacity || (self.fused && self.is_terminated) {
+            return Err(future);
+        }
+        self.push(future);
+        Ok(())
+    }
+
+    /// Returns a handle which can be used to push futures into the set from
+    /// other threads.
+    ///
+    /// Futures pushed through the handle are added to the set the next time
+    /// it's polled, so they aren't counted by [`len`](FuturesUnordered::len)
+    /// until then. The first future pushed through a handle after the set was
+    /// polled wakes up the task polling the set.
+    ///
+    /// Futures pushed through the handle are held in a queue that is shared
+    /// with the set's wakers, and may be dropped by whichever thread releases
+    /// that queue last, which is why they have to be `Send`.
+    pub fn handle(&self) -> QueueHandle<Fut>
+        where Fut: Send,
+    {
+        QueueHandle::new(Arc::downgrade(&self.ready_to_run_queue))
     }
 
     /// Push a future into the set.
@@ -152,7 +645,85 @@ impl<Fut> FuturesUnordered<Fut> {
     /// call [`poll`](Future::poll) on the submitted future. The caller must
     /// ensure that [`FuturesUnordered::poll_next`](Stream::poll_next) is called
     /// in order to receive wake-up notifications for the given future.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the set was created with
+    /// [`fused`](FuturesUnordered::fused) and has already terminated.
     pub fn push(&mut self, future: Fut) {
+        self.push_with_key(future);
+    }
+
+    /// Push a future into the set, returning a [`QueueKey`] identifying it.
+    ///
+    /// This works like [`push`](FuturesUnordered::push), but the returned
+    /// key is yielded again alongside the future's output by
+    /// [`poll_next_with_key`](FuturesUnordered::poll_next_with_key). This
+    /// lets callers correlate outputs with the futures that produced them
+    /// without having to wrap each future. Keys are handed out sequentially
+    /// and are not reused unless the internal counter wraps around.
+    pub fn push_with_key(&mut self, future: Fut) -> QueueKey {
+        let (ptr, key) = self.link_new(future);
+
+        // We'll need to get the future "into the system" to start tracking it,
+        // e.g. getting its wake-up notifications going to us tracking which
+        // futures are ready. To do that we unconditionally enqueue it for
+        // polling here.
+        self.ready_to_run_queue.enqueue(ptr);
+
+        key
+    }
+
+    /// Push a future into the set, returning a [`CancelHandle`] which can be
+    /// used to cancel it.
+    ///
+    /// Calling [`cancel`](CancelHandle::cancel) on the returned handle
+    /// schedules the future for removal: the next call to
+    /// [`poll_next`](Stream::poll_next) drops the future without polling it
+    /// again and removes it from the set.
+    /// [`len`](FuturesUnordered::len) stops counting the future as soon as
+    /// it's canceled. Canceling a future that has already completed does
+    /// nothing.
+    pub fn push_cancelable(&mut self, future: Fut) -> CancelHandle<Fut> {
+        let (ptr, _key) = self.link_new(future);
+
+        // Safety: `ptr` was just linked, so the list holds a reference count
+        // on it. Take a weak reference without consuming that one.
+        let task = unsafe { Arc::from_raw(ptr) };
+        let handle = CancelHandle::new(Arc::downgrade(&task));
+        mem::forget(task);
+
+        self.ready_to_run_queue.enqueue(ptr);
+
+        handle
+    }
+
+    /// Push a future into the set, ahead of all futures that are currently
+    /// waiting to be polled.
+    ///
+    /// The next call to [`poll_next`](Stream::poll_next) polls this future
+    /// before any future whose wake-up notification is already pending. If
+    /// `push_front` is called several times in a row, the futures are polled
+    /// in reverse order of insertion. Like [`push`](FuturesUnordered::push),
+    /// this method does not call [`poll`](Future::poll) on the future.
+    pub fn push_front(&mut self, future: Fut) {
+        let (ptr, _key) = self.link_new(future);
+
+        // Safety: &mut self guarantees the mutual exclusion `enqueue_front`
+        // expects
+        unsafe { self.ready_to_run_queue.enqueue_front(ptr) };
+    }
+
+    /// Allocates a task for `future` and inserts it into the list of all
+    /// tasks. The task still has to be put into the ready to run queue.
+    fn link_new(&mut self, future: Fut) -> (*const Task<Fut>, QueueKey) {
+        assert!(!(self.fused && self.is_terminated),
+                "pushed a future into a terminated fused FuturesUnordered");
+        self.is_terminated = false;
+
+        let key = self.next_key;
+        self.next_key = self.next_key.wrapping_add(1);
+
         let task = Arc::new(Task {
             future: UnsafeCell::new(Some(future)),
             next_all: UnsafeCell::new(ptr::null_mut()),
@@ -160,26 +731,106 @@ impl<Fut> FuturesUnordered<Fut> {
             next_ready_to_run: AtomicPtr::new(ptr::null_mut()),
             queued: AtomicBool::new(true),
             ready_to_run_queue: Arc::downgrade(&self.ready_to_run_queue),
+            key,
+            canceled: AtomicBool::new(false),
+            woke_itself: AtomicBool::new(false),
         });
 
         // Right now our task has a strong reference count of 1. We transfer
         // ownership of this reference count to our internal linked list
         // and we'll reclaim ownership through the `unlink` method below.
-        let ptr = self.link(task);
+        (self.link(task), QueueKey(key))
+    }
 
-        // We'll need to get the future "into the system" to start tracking it,
-        // e.g. getting its wake-up notifications going to us tracking which
-        // futures are ready. To do that we unconditionally enqueue it for
-        // polling here.
-        self.ready_to_run_queue.enqueue(ptr);
+    /// Drops all futures in the set without polling them.
+    ///
+    /// The set is empty afterwards, but remains usable: futures can be pushed
+    /// into it again, and the waker registered by the last call to
+    /// [`poll_next`](Stream::poll_next) is kept. Wake-up notifications for
+    /// the dropped futures that are still queued up are discarded on the next
+    /// call to [`poll_next`](Stream::poll_next).
+    pub fn clear(&mut self) {
+        unsafe {
+            while !self.head_all.is_null() {
+                let head = self.head_all;
+                let task = self.unlink(head);
+                self.release_task(task);
+            }
+        }
+
+        // Stale tasks in the ready to run queue can't be unlinked from there
+        // here, because other threads may be enqueuing concurrently. Their
+        // futures are gone though, so `poll_next` simply frees them.
+        debug_assert_eq!(self.len, 0);
+    }
+
+    /// Removes the future identified by `key` from the set and returns it.
+    ///
+    /// Returns `None` if the future has already completed or was removed
+    /// before. This walks the list of all futures in the set, so it takes
+    /// time proportional to [`len`](FuturesUnordered::len). A pending wake-up
+    /// notification for the removed future is discarded by the next call to
+    /// [`poll_next`](Stream::poll_next).
+    pub fn remove(&mut self, key: QueueKey) -> Option<Fut> where Fut: Unpin {
+        let mut task = self.head_all;
+        while !task.is_null() {
+            unsafe {
+                if (*task).key == key.0 {
+                    let task = self.unlink(task);
+                    // Moving the future out is fine because it's `Unpin`.
+                    let future = (*task.future.get()).take();
+                    self.release_task(task);
+                    return future;
+                }
+                task = *(*task).next_all.get();
+            }
+        }
+        None
+    }
+
+    /// Removes all futures from the set and returns them in an iterator.
+    ///
+    /// This hands back the futures that haven't completed yet, instead of
+    /// dropping them like [`clear`](FuturesUnordered::clear) does. The set
+    /// is left empty and can be reused afterwards. If the iterator is dropped
+    /// before it's exhausted, the remaining futures are dropped as well.
+    /// Wake-up notifications for the drained futures that are still queued
+    /// up are discarded by the next call to [`poll_next`](Stream::poll_next).
+    pub fn drain(&mut self) -> Drain<Fut> where Fut: Unpin {
+        Drain { queue: self }
+    }
+
+    /// Returns an iterator over all futures in the set.
+    ///
+    /// Like [`iter_mut`](FuturesUnordered::iter_mut), this only yields
+    /// futures that haven't completed yet and doesn't touch the ready to run
+    /// queue.
+    pub fn iter(&self) -> Iter<Fut> {
+        Iter {
+            task: self.head_all,
+            len: self.len,
+            _marker: PhantomData
+        }
     }
 
     /// Returns an iterator that allows modifying each future in the set.
+    ///
+    /// Only futures that haven't completed yet are yielded, and the ready to
+    /// run queue is left untouched, so iterating doesn't affect which futures
+    /// are polled next. Since the iterator borrows the set mutably, the set
+    /// can't be polled or modified while the iteration is in progress. Wake-up
+    /// notifications that arrive in the meantime from other threads are
+    /// still recorded and will be processed by the next call to
+    /// [`poll_next`](Stream::poll_next).
     pub fn iter_mut(&mut self) -> IterMut<Fut> where Fut: Unpin {
         IterMut(Pin::new(self).iter_pin_mut())
     }
 
     /// Returns an iterator that allows modifying each future in the set.
+    ///
+    /// This is the pinned counterpart of
+    /// [`iter_mut`](FuturesUnordered::iter_mut), which works for futures
+    /// that don't implement [`Unpin`].
     #[allow(clippy::needless_lifetimes)] // https://github.com/rust-lang/rust/issues/52675
     pub fn iter_pin_mut<'a>(self: Pin<&'a mut Self>) -> IterPinMut<'a, Fut> {
         IterPinMut {
@@ -203,6 +854,13 @@ impl<Fut> FuturesUnordered<Fut> {
         // `wake` from doing any work in the future
         let prev = task.queued.swap(true, SeqCst);
 
+        // Mark the task as canceled so that a `CancelHandle` can't count it
+        // anymore. If a handle counted it already, the set no longer holds
+        // it, so it's uncounted again.
+        if task.canceled.swap(true, SeqCst) {
+            self.ready_to_run_queue.canceled.fetch_sub(1, SeqCst);
+        }
+
         // Drop the future, even if it hasn't finished yet. This is safe
         // because we're dropping the future on the thread that owns
         // `FuturesUnordered`, which correctly tracks `Fut`'s lifetimes and
@@ -272,143 +930,33 @@ impl<Fut> FuturesUnordered<Fut> {
 impl<Fut: Future> Stream for FuturesUnordered<Fut> {
     type Item = Fut::Output;
 
-    fn poll_next(mut self: Pin<&mut Self>, lw: &LocalWaker)
+    fn poll_next(self: Pin<&mut Self>, lw: &LocalWaker)
         -> Poll<Option<Self::Item>>
     {
-        // Ensure `parent` is correctly set.
-        self.ready_to_run_queue.waker.register(lw);
-
-        loop {
-            // Safety: &mut self guarantees the mutual exclusion `dequeue`
-            // expects
-            let task = match unsafe { self.ready_to_run_queue.dequeue() } {
-                Dequeue::Empty => {
-                    if self.is_empty() {
-                        return Poll::Ready(None);
-                    } else {
-                        return Poll::Pending;
-                    }
-                }
-                Dequeue::Inconsistent => {
-                    // At this point, it may be worth yielding the thread &
-                    // spinning a few times... but for now, just yield using the
-                    // task system.
-                    lw.wake();
-                    return Poll::Pending;
-                }
-                Dequeue::Data(task) => task,
-            };
-
-            debug_assert!(task != self.ready_to_run_queue.stub());
-
-            // Safety:
-            // - `task` is a valid pointer.
-            // - We are the only thread that accesses the `UnsafeCell` that
-            //   contains the future
-            let future = match unsafe { &mut *(*task).future.get() } {
-                Some(future) => future,
-
-                // If the future has already gone away then we're just
-                // cleaning out this task. See the comment in
-                // `release_task` for more information, but we're basically
-                // just taking ownership of our reference count here.
-                None => {
-                    // This case only happens when `release_task` was called
-                    // for this task before and couldn't drop the task
-                    // because it was already enqueued in the ready to run
-                    // queue.
-
-                    // Safety: `task` is a valid pointer
-                    let task = unsafe { Arc::from_raw(task) };
-
-                    // Double check that the call to `release_task` really
-                    // happened. Calling it required the task to be unlinked.
-                    unsafe {
-                        debug_assert!((*task.next_all.get()).is_null());
-                        debug_assert!((*task.prev_all.get()).is_null());
-                    }
-                    continue
-                }
-            };
-
-            // Safety: `task` is a valid pointer
-            let task = unsafe { self.unlink(task) };
-
-            // Unset queued flag: This must be done before polling to ensure
-            // that the future's task gets rescheduled if it sends a wake-up
-            // notification **during** the call to `poll`.
-            let prev = task.queued.swap(false, SeqCst);
-            assert!(prev);
-
-            // We're going to need to be very careful if the `poll`
-            // method below panics. We need to (a) not leak memory and
-            // (b) ensure that we still don't have any use-after-frees. To
-            // manage this we do a few things:
-            //
-            // * A "bomb" is created which if dropped abnormally will call
-            //   `release_task`. That way we'll be sure the memory management
-            //   of the `task` is managed correctly. In particular
-            //   `release_task` will drop the future. This ensures that it is
-            //   dropped on this thread and not accidentally on a different
-            //   thread (bad).
-            // * We unlink the task from our internal queue to preemptively
-            //   assume it'll panic, in which case we'll want to discard it
-            //   regardless.
-            struct Bomb<'a, Fut: 'a> {
-                queue: &'a mut FuturesUnordered<Fut>,
-                task: Option<Arc<Task<Fut>>>,
-            }
-
-            impl<'a, Fut> Drop for Bomb<'a, Fut> {
-                fn drop(&mut self) {
-                    if let Some(task) = self.task.take() {
-                        self.queue.release_task(task);
-                    }
-                }
-            }
-
-            let mut bomb = Bomb {
-                task: Some(task),
-                queue: &mut *self,
-            };
-
-            // Poll the underlying future with the appropriate waker
-            // implementation. This is where a large bit of the unsafety
-            // starts to stem from internally. The waker is basically just
-            // our `Arc<Task<Fut>>` and can schedule the future for polling by
-            // enqueuing itself in the ready to run queue.
-            //
-            // Critically though `Task<Fut>` won't actually access `Fut`, the
-            // future, while it's floating around inside of wakers.
-            // These structs will basically just use `Fut` to size
-            // the internal allocation, appropriately accessing fields and
-            // deallocating the task if need be.
-            let res = {
-                let lw = bomb.task.as_ref().unwrap().local_waker();
-
-                // Safety: We won't move the future ever again
-                let future = unsafe { Pin::new_unchecked(future) };
-
-                future.poll(&lw)
-            };
-
-            match res {
-                Poll::Pending => {
-                    let task = bomb.task.take().unwrap();
-                    bomb.queue.link(task);
-                    continue
-                }
-                Poll::Ready(output) => {
-                    return Poll::Ready(Some(output))
-                }
-            }
-        }
+        self.poll_next_with_key(lw)
+            .map(|item| item.map(|(_key, output)| output))
     }
 }
 
 impl<Fut> Debug for FuturesUnordered<Fut> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        write!(fmt, "FuturesUnordered {{ ... }}")
+        // The number of futures waiting to be polled is only a snapshot, as
+        // other threads may wake up futures while we're counting.
+        let mut ready = 0;
+        let mut task = self.head_all;
+        while !task.is_null() {
+            unsafe {
+                if (*task).queued.load(Relaxed) {
+                    ready += 1;
+                }
+                task = *(*task).next_all.get();
+            }
+        }
+
+        fmt.debug_struct("FuturesUnordered")
+            .field("len", &self.len())
+            .field("ready", &ready)
+            .finish()
     }
 }
 
@@ -418,13 +966,7 @@ impl<Fut> Drop for FuturesUnordered<Fut> {
         // associated with it. At the same time though there may be tons of
         // wakers flying around which contain `Task<Fut>` references
         // inside them. We'll let those naturally get deallocated.
-        unsafe {
-            while !self.head_all.is_null() {
-                let head = self.head_all;
-                let task = self.unlink(head);
-                self.release_task(task);
-            }
-        }
+        self.clear();
 
         // Note that at this point we could still have a bunch of tasks in the
         // ready to run queue. None of those tasks, however, have futures
@@ -451,6 +993,17 @@ impl<Fut: Future> FromIterator<Fut> for FuturesUnordered<Fut> {
     }
 }
 
+impl<Fut: Future> Extend<Fut> for FuturesUnordered<Fut> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Fut>,
+    {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
 /// Converts a list of futures into a [`Stream`] of outputs from the futures.
 ///
 /// This function will take an list of futures (e.g. a [`Vec`], an [`Iterator`],
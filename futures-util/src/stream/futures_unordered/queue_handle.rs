@@ -0,0 +1,56 @@
+use std::fmt;
+use std::sync::Weak;
+
+use super::ready_to_run_queue::ReadyToRunQueue;
+
+/// A handle for pushing futures into a
+/// [`FuturesUnordered`](super::FuturesUnordered) from other threads.
+///
+/// This is created by the
+/// [`FuturesUnordered::handle`](super::FuturesUnordered::handle) method.
+/// The handle doesn't keep the set alive: once the set has been dropped,
+/// [`push`](QueueHandle::push) hands the future back to the caller.
+pub struct QueueHandle<Fut> {
+    queue: Weak<ReadyToRunQueue<Fut>>,
+}
+
+// Pushing only touches the atomic stack of injected futures, so the handle can
+// be shared with any thread that the futures themselves can be sent to.
+unsafe impl<Fut: Send> Send for QueueHandle<Fut> {}
+unsafe impl<Fut: Send> Sync for QueueHandle<Fut> {}
+
+impl<Fut> QueueHandle<Fut> {
+    pub(super) fn new(queue: Weak<ReadyToRunQueue<Fut>>) -> QueueHandle<Fut> {
+        QueueHandle { queue }
+    }
+
+    /// Push a future into the set this handle belongs to.
+    ///
+    /// The future is added to the set the next time the set is polled. If
+    /// the set has already been dropped, the future is returned in the `Err`
+    /// variant. If the set is dropped while this method is running, the
+    /// future may be dropped without ever being polled.
+    pub fn push(&self, future: Fut) -> Result<(), Fut> {
+        let queue = match self.queue.upgrade() {
+            Some(queue) => queue,
+            None => return Err(future),
+        };
+
+        if queue.inject(future) {
+            queue.waker.wake();
+        }
+        Ok(())
+    }
+}
+
+impl<Fut> Clone for QueueHandle<Fut> {
+    fn clone(&self) -> QueueHandle<Fut> {
+        QueueHandle { queue: self.queue.clone() }
+    }
+}
+
+impl<Fut> fmt::Debug for QueueHandle<Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QueueHandle").finish()
+    }
+}
@@ -2,7 +2,7 @@ use crate::task::AtomicWaker;
 use std::cell::UnsafeCell;
 use std::ptr;
 use std::sync::Arc;
-use std::sync::atomic::AtomicPtr;
+use std::sync::atomic::{AtomicPtr, AtomicUsize};
 use std::sync::atomic::Ordering::{Relaxed, Acquire, Release, AcqRel};
 
 use super::abort::abort;
@@ -22,6 +22,19 @@ pub(super) struct ReadyToRunQueue<Fut> {
     pub(super) head: AtomicPtr<Task<Fut>>,
     pub(super) tail: UnsafeCell<*const Task<Fut>>,
     pub(super) stub: Arc<Task<Fut>>,
+
+    // Stack of futures pushed through a `QueueHandle` which haven't been
+    // added to the set yet
+    pub(super) injected: AtomicPtr<Injected<Fut>>,
+
+    // Futures canceled through a `CancelHandle` which are still in the set
+    // until it's polled again. `len` doesn't count them.
+    pub(super) canceled: AtomicUsize,
+}
+
+pub(super) struct Injected<Fut> {
+    pub(super) future: Fut,
+    pub(super) next: *mut Injected<Fut>,
 }
 
 /// An MPSC queue into which the tasks containing the futures are inserted
@@ -42,6 +55,20 @@ impl<Fut> ReadyToRunQueue<Fut> {
         }
     }
 
+    /// Inserts a task at the consumer end of the queue, so that it is the next
+    /// task returned by `dequeue`.
+    ///
+    /// Note that this is unsafe as it requires the same mutual exclusion as
+    /// `dequeue`: producers only ever touch `head`, so the consumer is free
+    /// to prepend to `tail`.
+    pub(super) unsafe fn enqueue_front(&self, task: *const Task<Fut>) {
+        debug_assert!((*task).queued.load(Relaxed));
+
+        let tail = *self.tail.get();
+        (*task).next_ready_to_run.store(tail as *mut _, Relaxed);
+        *self.tail.get() = task;
+    }
+
     /// The dequeue function from the 1024cores intrusive MPSC queue algorithm
     ///
     /// Note that this is unsafe as it required mutual exclusion (only one
@@ -82,6 +109,40 @@ impl<Fut> ReadyToRunQueue<Fut> {
         Dequeue::Inconsistent
     }
 
+    /// Pushes a future onto the stack of injected futures. Returns `true` if
+    /// the stack was empty before.
+    pub(super) fn inject(&self, future: Fut) -> bool {
+        let node = Box::into_raw(Box::new(Injected {
+            future,
+            next: ptr::null_mut(),
+        }));
+
+        let mut head = self.injected.load(Relaxed);
+        loop {
+            unsafe { (*node).next = head };
+            match self.injected.compare_exchange_weak(
+                head, node, Release, Relaxed,
+            ) {
+                Ok(_) => return head.is_null(),
+                Err(actual) => head = actual,
+            }
+        }
+    }
+
+    /// Takes all injected futures, in the order they were injected.
+    pub(super) fn take_injected(&self) -> Vec<Fut> {
+        let mut node = self.injected.swap(ptr::null_mut(), Acquire);
+        let mut futures = Vec::new();
+        while !node.is_null() {
+            let injected = unsafe { Box::from_raw(node) };
+            node = injected.next;
+            futures.push(injected.future);
+        }
+        // The stack hands out the most recently injected future first
+        futures.reverse();
+        futures
+    }
+
     pub(super) fn stub(&self) -> *const Task<Fut> {
         &*self.stub
     }
@@ -105,5 +166,9 @@ impl<Fut> Drop for ReadyToRunQueue<Fut> {
                 }
             }
         }
+
+        // Futures that were injected after the `FuturesUnordered` went away
+        // are simply dropped.
+        drop(self.take_injected());
     }
 }
@@ -30,6 +30,15 @@ pub(super) struct Task<Fut> {
 
     // Whether or not this task is currently in the ready to run queue
     pub(super) queued: AtomicBool,
+
+    // Key identifying this task's future, see `QueueKey`
+    pub(super) key: usize,
+
+    // Whether the future was canceled through a `CancelHandle`
+    pub(super) canceled: AtomicBool,
+
+    // Whether the future woke itself up during its last poll
+    pub(super) woke_itself: AtomicBool,
 }
 
 impl<Fut> Task<Fut> {
@@ -60,6 +69,10 @@ impl<Fut> Task<Fut> {
 
     /// Returns a waker.
     pub(super) fn waker(self: &Arc<Task<Fut>>) -> Waker {
+        // Reference counting is left to `Arc`, which aborts the process
+        // instead of panicking if the count overflows. Panicking here would
+        // be unsound, as this may run in an arbitrary `UnsafeWake::clone_raw`
+        // call on another thread.
         let clone = self.clone();
 
         // Safety: This is save because an `Arc` is a struct which contains
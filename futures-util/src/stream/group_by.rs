@@ -0,0 +1,280 @@
+use crate::stream::{Fuse, StreamExt};
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll, Waker};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+use std::marker::Unpin;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// Stream for the [`group_by`](super::StreamExt::group_by) method, yielding a
+/// [`Group`] for every distinct key.
+#[must_use = "streams do nothing unless polled"]
+pub struct GroupBy<St: Stream, K: Eq + Hash, F> {
+    inner: Arc<Mutex<Inner<St, K, F>>>,
+}
+
+/// One of the streams yielded by [`GroupBy`], yielding the items which share
+/// its key.
+#[must_use = "streams do nothing unless polled"]
+pub struct Group<St: Stream, K: Eq + Hash, F> {
+    inner: Arc<Mutex<Inner<St, K, F>>>,
+    key: K,
+}
+
+// The key is never pinned
+impl<St: Stream, K: Eq + Hash, F> Unpin for Group<St, K, F> {}
+
+struct Inner<St: Stream, K: Eq + Hash, F> {
+    stream: Fuse<St>,
+    key_fn: F,
+    buffer: usize,
+    groups: HashMap<K, GroupState<St::Item>>,
+    // Keys of the groups which have been created but not yielded yet
+    new_groups: VecDeque<K>,
+    waker: Option<Waker>,
+    dropped: bool,
+}
+
+struct GroupState<T> {
+    items: VecDeque<T>,
+    waker: Option<Waker>,
+    dropped: bool,
+}
+
+impl<St: Stream, K: Eq + Hash, F> Inner<St, K, F> {
+    // Wakes every task waiting for this stream, since any of them may now be
+    // able to poll the underlying stream
+    fn wake_all(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+        for group in self.groups.values_mut() {
+            if let Some(waker) = group.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl<St, K, F> Inner<St, K, F>
+    where St: Stream,
+          K: Eq + Hash + Clone,
+          F: FnMut(&St::Item) -> K,
+{
+    // Whether the underlying stream mustn't be polled until some of the
+    // buffered items or groups have been taken
+    fn is_full(&self) -> bool {
+        self.new_groups.len() >= self.buffer ||
+            self.groups.values().any(|group| group.items.len() >= self.buffer)
+    }
+
+    fn poll_stream(&mut self, lw: &LocalWaker) -> Poll<Option<(K, St::Item)>> {
+        if self.stream.is_done() {
+            return Poll::Ready(None);
+        }
+        if self.is_full() {
+            return Poll::Pending;
+        }
+        // Safety: The stream lives behind the `Arc` and is never moved out
+        // of it, so it stays pinned until it is dropped.
+        let stream = unsafe { Pin::new_unchecked(&mut self.stream) };
+        match stream.poll_next(lw) {
+            Poll::Ready(Some(item)) => {
+                let key = (self.key_fn)(&item);
+                Poll::Ready(Some((key, item)))
+            }
+            Poll::Ready(None) => {
+                self.wake_all();
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    // Buffers an item for a group other than the one being polled, creating
+    // the group if the key hasn't been seen before
+    fn route(&mut self, key: K, item: St::Item) {
+        if let Some(group) = self.groups.get_mut(&key) {
+            if !group.dropped {
+                group.items.push_back(item);
+                if let Some(waker) = group.waker.take() {
+                    waker.wake();
+                }
+            }
+            return;
+        }
+        if self.dropped {
+            // Nobody is left to take the new group
+            return;
+        }
+        let mut items = VecDeque::new();
+        items.push_back(item);
+        self.groups.insert(key.clone(), GroupState { items, waker: None, dropped: false });
+        self.new_groups.push_back(key);
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+pub(super) fn new<St, K, F>(stream: St, buffer: usize, key_fn: F) -> GroupBy<St, K, F>
+    where St: Stream,
+          K: Eq + Hash + Clone,
+          F: FnMut(&St::Item) -> K,
+{
+    assert!(buffer > 0, "group_by buffer must be non-zero");
+
+    GroupBy {
+        inner: Arc::new(Mutex::new(Inner {
+            stream: stream.fuse(),
+            key_fn,
+            buffer,
+            groups: HashMap::new(),
+            new_groups: VecDeque::new(),
+            waker: None,
+            dropped: false,
+        })),
+    }
+}
+
+impl<St, K, F> Stream for GroupBy<St, K, F>
+    where St: Stream,
+          K: Eq + Hash + Clone,
+          F: FnMut(&St::Item) -> K,
+{
+    type Item = (K, Group<St, K, F>);
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<Self::Item>> {
+        let mut inner = self.inner.lock().unwrap();
+        let inner = &mut *inner;
+
+        loop {
+            if let Some(key) = inner.new_groups.pop_front() {
+                // The groups may have been held back by the queue of new ones
+                inner.wake_all();
+                let group = Group { inner: self.inner.clone(), key: key.clone() };
+                return Poll::Ready(Some((key, group)));
+            }
+
+            match inner.poll_stream(lw) {
+                Poll::Ready(Some((key, item))) => inner.route(key, item),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => {
+                    inner.waker = Some(lw.clone().into_waker());
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+impl<St, K, F> Stream for Group<St, K, F>
+    where St: Stream,
+          K: Eq + Hash + Clone,
+          F: FnMut(&St::Item) -> K,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<St::Item>> {
+        let mut inner = self.inner.lock().unwrap();
+        let inner = &mut *inner;
+
+        let buffer = inner.buffer;
+        let group = inner.groups.get_mut(&self.key).unwrap();
+        if let Some(item) = group.items.pop_front() {
+            if group.items.len() + 1 == buffer {
+                // Taking an item out of a full buffer unblocks the stream
+                inner.wake_all();
+            }
+            return Poll::Ready(Some(item));
+        }
+
+        loop {
+            match inner.poll_stream(lw) {
+                Poll::Ready(Some((key, item))) => {
+                    if key == self.key {
+                        // The stream now wakes our task only. Let the others
+                        // know so that they can poll the stream themselves.
+                        inner.wake_all();
+                        return Poll::Ready(Some(item));
+                    }
+                    inner.route(key, item);
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => {
+                    let group = inner.groups.get_mut(&self.key).unwrap();
+                    group.waker = Some(lw.clone().into_waker());
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+impl<St: Stream, K: Eq + Hash, F> Group<St, K, F> {
+    /// Returns the key shared by the items of this group.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+}
+
+impl<St: Stream, K: Eq + Hash, F> Drop for GroupBy<St, K, F> {
+    fn drop(&mut self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            let inner = &mut *inner;
+            inner.dropped = true;
+            inner.waker = None;
+            // The groups which were never yielded can't be polled anymore.
+            // Their entries stay around so that their items are discarded.
+            for key in inner.new_groups.drain(..) {
+                if let Some(group) = inner.groups.get_mut(&key) {
+                    group.dropped = true;
+                    group.items.clear();
+                }
+            }
+            // The groups may have been held back by the queue of new ones
+            inner.wake_all();
+        }
+    }
+}
+
+impl<St: Stream, K: Eq + Hash, F> Drop for Group<St, K, F> {
+    fn drop(&mut self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            let inner = &mut *inner;
+            if let Some(group) = inner.groups.get_mut(&self.key) {
+                group.dropped = true;
+                group.items.clear();
+                group.waker = None;
+            }
+            // The others may be blocked on our buffer or rely on us polling
+            // the stream.
+            inner.wake_all();
+        }
+    }
+}
+
+impl<St: Stream, K: Eq + Hash, F> fmt::Debug for GroupBy<St, K, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupBy").finish()
+    }
+}
+
+impl<St, K, F> fmt::Debug for Group<St, K, F>
+    where St: Stream,
+          K: Eq + Hash + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Group")
+            .field("key", &self.key)
+            .finish()
+    }
+}
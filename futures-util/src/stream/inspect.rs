@@ -67,6 +67,10 @@ impl<St, F> Stream for Inspect<St, F>
             e
         }))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
 }
 
 /* TODO
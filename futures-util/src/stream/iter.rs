@@ -6,7 +6,7 @@ use futures_core::task::{LocalWaker, Poll};
 /// A stream which is just a shim over an underlying instance of `Iterator`.
 ///
 /// This stream will never block and is always ready.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 #[must_use = "streams do nothing unless polled"]
 pub struct Iter<I> {
     iter: I,
@@ -43,4 +43,8 @@ impl<I> Stream for Iter<I>
     fn poll_next(mut self: Pin<&mut Self>, _: &LocalWaker) -> Poll<Option<I::Item>> {
         Poll::Ready(self.iter.next())
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
@@ -0,0 +1,97 @@
+use crate::stream::{StreamExt, Fuse};
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`merge`](super::StreamExt::merge) combinator.
+///
+/// Unlike [`Select`](super::Select), which keeps polling a stream first for
+/// as long as it yields items, `Merge` strictly alternates which stream is
+/// polled first. If both streams are always ready, their items are
+/// interleaved one by one.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Merge<St1, St2> {
+    stream1: Fuse<St1>,
+    stream2: Fuse<St2>,
+    flag: bool,
+}
+
+impl<St1: Unpin, St2: Unpin> Unpin for Merge<St1, St2> {}
+
+impl<St1, St2> Merge<St1, St2>
+    where St1: Stream,
+          St2: Stream<Item = St1::Item>
+{
+    unsafe_pinned!(stream1: Fuse<St1>);
+    unsafe_pinned!(stream2: Fuse<St2>);
+    unsafe_unpinned!(flag: bool);
+
+    pub(super) fn new(stream1: St1, stream2: St2) -> Merge<St1, St2> {
+        Merge {
+            stream1: stream1.fuse(),
+            stream2: stream2.fuse(),
+            flag: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying streams that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> (&St1, &St2) {
+        (self.stream1.get_ref(), self.stream2.get_ref())
+    }
+
+    /// Acquires a mutable reference to the underlying streams that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> (&mut St1, &mut St2) {
+        (self.stream1.get_mut(), self.stream2.get_mut())
+    }
+
+    /// Consumes this combinator, returning the underlying streams.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> (St1, St2) {
+        (self.stream1.into_inner(), self.stream2.into_inner())
+    }
+}
+
+impl<St1, St2> Stream for Merge<St1, St2>
+    where St1: Stream,
+          St2: Stream<Item = St1::Item>
+{
+    type Item = St1::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker
+    ) -> Poll<Option<St1::Item>> {
+        for _ in 0..2 {
+            // Whichever stream is polled now goes last next time, so that a
+            // stream yielding an item hands priority over to the other one.
+            let second_first = *self.flag();
+            *self.flag() = !second_first;
+
+            let poll = if second_first {
+                self.stream2().poll_next(lw)
+            } else {
+                self.stream1().poll_next(lw)
+            };
+
+            if let Poll::Ready(Some(item)) = poll {
+                return Poll::Ready(Some(item));
+            }
+        }
+
+        if self.stream1.is_done() && self.stream2.is_done() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
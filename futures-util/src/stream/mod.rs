@@ -23,9 +23,24 @@ pub use self::chain::Chain;
 mod concat;
 pub use self::concat::Concat;
 
+mod count;
+pub use self::count::Count;
+
+mod cycle;
+pub use self::cycle::{cycle_with, Cycle, CycleWith};
+
+mod debounce;
+pub use self::debounce::Debounce;
+
+mod dedup;
+pub use self::dedup::{Dedup, DedupBy, DedupByKey};
+
 mod empty;
 pub use self::empty::{empty, Empty};
 
+mod enumerate;
+pub use self::enumerate::Enumerate;
+
 mod filter;
 pub use self::filter::Filter;
 
@@ -38,6 +53,9 @@ pub use self::flatten::Flatten;
 mod fold;
 pub use self::fold::Fold;
 
+mod fold_while;
+pub use self::fold_while::{ControlFlow, FoldWhile};
+
 mod forward;
 pub use self::forward::Forward;
 
@@ -56,6 +74,9 @@ pub use self::inspect::Inspect;
 mod map;
 pub use self::map::Map;
 
+mod merge;
+pub use self::merge::Merge;
+
 mod next;
 pub use self::next::Next;
 
@@ -63,35 +84,59 @@ mod once;
 pub use self::once::{once, Once};
 
 mod peek;
-pub use self::peek::Peekable;
+pub use self::peek::{Peekable, PeekMut, NextIf, NextIfEq};
 
 mod poll_fn;
 pub use self::poll_fn::{poll_fn, PollFn};
 
+mod scan_async;
+pub use self::scan_async::ScanAsync;
+
 mod select;
 pub use self::select::Select;
 
+mod select_with_strategy;
+pub use self::select_with_strategy::{select_with_strategy, PollNext, SelectWithStrategy};
+
 mod skip;
 pub use self::skip::Skip;
 
 mod skip_while;
 pub use self::skip_while::SkipWhile;
 
+mod switch_map;
+pub use self::switch_map::SwitchMap;
+
 mod take;
 pub use self::take::Take;
 
+mod take_until;
+pub use self::take_until::TakeUntil;
+
 mod take_while;
 pub use self::take_while::TakeWhile;
 
 mod then;
 pub use self::then::Then;
 
+mod throttle;
+pub use self::throttle::Throttle;
+
+mod timeout;
+pub use self::timeout::{Timeout, Elapsed};
+
 mod unfold;
 pub use self::unfold::{unfold, Unfold};
 
+mod unzip;
+pub use self::unzip::Unzip;
+
 mod zip;
 pub use self::zip::Zip;
 
+mod zip_longest;
+pub use self::zip_longest::{EitherOrBoth, ZipLongest};
+
 if_std! {
     use std;
     use std::iter::Extend;
@@ -99,6 +144,9 @@ if_std! {
     mod buffer_unordered;
     pub use self::buffer_unordered::BufferUnordered;
 
+    mod buffer_unordered_lazy;
+    pub use self::buffer_unordered_lazy::BufferUnorderedLazy;
+
     mod buffered;
     pub use self::buffered::Buffered;
 
@@ -108,9 +156,18 @@ if_std! {
     mod chunks;
     pub use self::chunks::Chunks;
 
+    mod chunks_timeout;
+    pub use self::chunks_timeout::ChunksTimeout;
+
     mod collect;
     pub use self::collect::Collect;
 
+    mod flat_map_unordered;
+    pub use self::flat_map_unordered::FlatMapUnordered;
+
+    mod flatten_unordered;
+    pub use self::flatten_unordered::FlattenUnordered;
+
     mod for_each_concurrent;
     pub use self::for_each_concurrent::ForEachConcurrent;
 
@@ -118,14 +175,24 @@ if_std! {
     pub use self::futures_ordered::{futures_ordered, FuturesOrdered};
 
     mod futures_unordered;
-    pub use self::futures_unordered::{futures_unordered, FuturesUnordered};
+    pub use self::futures_unordered::{
+        futures_unordered, FuturesUnordered, QueueKey, CancelHandle, QueueHandle,
+    };
+
+    mod group_by;
+    pub use self::group_by::{Group, GroupBy};
+
+    mod partition;
+    pub use self::partition::Partition;
+
+    mod ready_chunks;
+    pub use self::ready_chunks::ReadyChunks;
+
+    mod select_all;
+    pub use self::select_all::{select_all, SelectAll, SelectAllHandle};
 
     mod split;
     pub use self::split::{SplitStream, SplitSink, ReuniteError};
-
-    // ToDo
-    // mod select_all;
-    // pub use self::select_all::{select_all, SelectAll};
 }
 
 impl<T: ?Sized> StreamExt for T where T: Stream {}
@@ -292,6 +359,90 @@ pub trait StreamExt: Stream {
         FilterMap::new(self, f)
     }
 
+    /// Creates a stream which skips items that are equal to the item
+    /// yielded right before them.
+    ///
+    /// Like `Vec::dedup`, only consecutive duplicates are removed. A clone of
+    /// the last yielded item is kept to compare the following items against,
+    /// so the first item is always passed through. A run of duplicates that
+    /// is already available is skipped within a single call to `poll_next`.
+    /// The stored item is dropped once the underlying stream has ended.
+    ///
+    /// If this stream is also a sink, the returned stream is a sink as well
+    /// and forwards items sent to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 1, 2, 2, 2, 1, 3, 3]).dedup();
+    ///
+    /// assert_eq!(vec![1, 2, 1, 3], block_on(stream.collect::<Vec<_>>()));
+    /// ```
+    fn dedup(self) -> Dedup<Self>
+        where Self::Item: PartialEq + Clone,
+              Self: Sized
+    {
+        Dedup::new(self)
+    }
+
+    /// Creates a stream which skips items that the closure `f` considers to
+    /// be duplicates of the item yielded right before them.
+    ///
+    /// `f` is called with the last yielded item and the new item, and the
+    /// new item is skipped if it returns `true`. Otherwise this behaves like
+    /// [`dedup`](StreamExt::dedup).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec!["a", "A", "b", "B", "a"])
+    ///     .dedup_by(|last, item| last.eq_ignore_ascii_case(item));
+    ///
+    /// assert_eq!(vec!["a", "b", "a"], block_on(stream.collect::<Vec<_>>()));
+    /// ```
+    fn dedup_by<F>(self, f: F) -> DedupBy<Self, F>
+        where F: FnMut(&Self::Item, &Self::Item) -> bool,
+              Self::Item: Clone,
+              Self: Sized
+    {
+        DedupBy::new(self, f)
+    }
+
+    /// Creates a stream which skips items that map to the same key as the
+    /// item yielded right before them.
+    ///
+    /// Only the key of the last yielded item is kept, so unlike
+    /// [`dedup`](StreamExt::dedup) the items don't need to be `Clone`.
+    /// Otherwise this behaves like `dedup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![(1, 'a'), (1, 'b'), (2, 'c'), (1, 'd')])
+    ///     .dedup_by_key(|&(version, _)| version);
+    ///
+    /// assert_eq!(
+    ///     vec![(1, 'a'), (2, 'c'), (1, 'd')],
+    ///     block_on(stream.collect::<Vec<_>>()),
+    /// );
+    /// ```
+    fn dedup_by_key<F, K>(self, f: F) -> DedupByKey<Self, F, K>
+        where F: FnMut(&Self::Item) -> K,
+              K: PartialEq,
+              Self: Sized
+    {
+        DedupByKey::new(self, f)
+    }
+
     /// Computes from this stream's items new items of a different type using
     /// an asynchronous closure.
     ///
@@ -322,6 +473,53 @@ pub trait StreamExt: Stream {
         Then::new(self, f)
     }
 
+    /// Computes a new item from each item of this stream using an
+    /// asynchronous closure, abandoning the computation for the previous
+    /// item as soon as a new one arrives.
+    ///
+    /// This is like [`then`](StreamExt::then), except that the stream keeps
+    /// being polled while a future returned by `f` is running. When the
+    /// stream produces another item, the running future is dropped right
+    /// away and replaced by the future for the new item, so only outputs of
+    /// the future for the most recent item are yielded. If a new item and
+    /// the running future's output are ready at the same time, the new item
+    /// wins and the output is discarded.
+    ///
+    /// The returned stream ends once this stream has ended and the future for
+    /// its last item has completed.
+    ///
+    /// Note that this function consumes the stream passed into it and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::future;
+    /// use futures::stream::StreamExt;
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    /// tx.unbounded_send("fu").unwrap();
+    /// tx.unbounded_send("fut").unwrap();
+    /// tx.unbounded_send("futures").unwrap();
+    /// drop(tx);
+    ///
+    /// // Look up suggestions only for the latest query
+    /// let results = rx.switch_map(|query| future::ready(query.len()));
+    ///
+    /// assert_eq!(await!(results.collect::<Vec<_>>()), vec![7]);
+    /// # });
+    /// ```
+    fn switch_map<Fut, F>(self, f: F) -> SwitchMap<Self, Fut, F>
+        where F: FnMut(Self::Item) -> Fut,
+              Fut: Future,
+              Self: Sized
+    {
+        SwitchMap::new(self, f)
+    }
+
     /// Collect all of the values of this stream into a vector, returning a
     /// future representing the result of that computation.
     ///
@@ -356,6 +554,35 @@ pub trait StreamExt: Stream {
         Collect::new(self)
     }
 
+    /// Converts a stream of pairs into a future, which resolves to a pair of
+    /// containers.
+    ///
+    /// `unzip()` consumes an entire stream of pairs, producing two
+    /// collections: one from the left elements of the pairs, and one from the
+    /// right elements, just like `Iterator::unzip`.
+    ///
+    /// The returned future will be resolved when the stream terminates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+    ///
+    /// let (numbers, letters): (Vec<_>, String) = block_on(stream.unzip());
+    /// assert_eq!(numbers, vec![1, 2, 3]);
+    /// assert_eq!(letters, "abc");
+    /// ```
+    fn unzip<A, B, FromA, FromB>(self) -> Unzip<Self, FromA, FromB>
+        where FromA: Default + Extend<A>,
+              FromB: Default + Extend<B>,
+              Self: Sized + Stream<Item = (A, B)>,
+    {
+        Unzip::new(self)
+    }
+
     /// Concatenate all items of a stream into a single extendable
     /// destination, returning a future representing the end result.
     ///
@@ -423,6 +650,63 @@ pub trait StreamExt: Stream {
         Fold::new(self, f, init)
     }
 
+    /// Execute an accumulating asynchronous computation over a stream until
+    /// the closure decides to stop.
+    ///
+    /// This works like [`fold`](StreamExt::fold), except that the closure's
+    /// future resolves to a [`ControlFlow`]. With `Continue(state)`, the fold
+    /// goes on with the next item. With `Break(state)`, the returned future
+    /// resolves to `state` right away, without polling this stream for any
+    /// further items. If the stream ends first, the future resolves to the
+    /// last state, as with `fold`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::future;
+    /// use futures::stream::{self, ControlFlow, StreamExt};
+    ///
+    /// let number_stream = stream::iter(1..)
+    ///     .inspect(|x| assert!(*x <= 5, "polled one item too many"));
+    /// let sum = number_stream.fold_while(0, |acc, x| {
+    ///     let acc = acc + x;
+    ///     future::ready(if acc >= 10 {
+    ///         ControlFlow::Break(acc)
+    ///     } else {
+    ///         ControlFlow::Continue(acc)
+    ///     })
+    /// });
+    /// assert_eq!(block_on(sum), 15);
+    /// ```
+    fn fold_while<T, Fut, F>(self, init: T, f: F) -> FoldWhile<Self, Fut, T, F>
+        where F: FnMut(T, Self::Item) -> Fut,
+              Fut: Future<Output = ControlFlow<T>>,
+              Self: Sized
+    {
+        FoldWhile::new(self, f, init)
+    }
+
+    /// Drains the stream, returning a future which resolves to the number of
+    /// items it yielded.
+    ///
+    /// The items are dropped as soon as they have been counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec!['a', 'b', 'c']);
+    /// assert_eq!(block_on(stream.count()), 3);
+    /// ```
+    fn count(self) -> Count<Self>
+        where Self: Sized
+    {
+        Count::new(self)
+    }
+
     /// Flattens a stream of streams into just one continuous stream.
     ///
     /// # Examples
@@ -460,6 +744,103 @@ pub trait StreamExt: Stream {
         Flatten::new(self)
     }
 
+    /// Flattens a stream of streams into just one stream, polling up to
+    /// `limit` inner streams concurrently.
+    ///
+    /// Unlike [`flatten`](StreamExt::flatten), which exhausts each inner
+    /// stream before moving on to the next one, this takes on new inner
+    /// streams from this stream as long as fewer than `limit` of them are
+    /// in progress, and yields items from whichever inner stream is ready
+    /// first. A `limit` of `None` or `0` doesn't limit the number of inner
+    /// streams polled at once.
+    ///
+    /// The inner streams are independent of each other: items, including
+    /// `Err` items of streams of `Result`s, are passed through as they are,
+    /// and an inner stream yielding an error keeps being polled just like
+    /// the other ones. The returned stream ends once this stream and all
+    /// inner streams have ended.
+    ///
+    /// The inner streams need to be `Unpin`; streams that aren't can be
+    /// pinned with `Box::pinned` first.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (tx1, rx1) = mpsc::unbounded();
+    /// let (tx2, rx2) = mpsc::unbounded();
+    ///
+    /// let mut flattened = stream::iter(vec![rx1, rx2]).flatten_unordered(None);
+    ///
+    /// tx2.unbounded_send(1).unwrap();
+    /// assert_eq!(await!(flattened.next()), Some(1));
+    ///
+    /// tx1.unbounded_send(2).unwrap();
+    /// assert_eq!(await!(flattened.next()), Some(2));
+    ///
+    /// drop((tx1, tx2));
+    /// assert_eq!(await!(flattened.next()), None);
+    /// # })
+    /// ```
+    #[cfg(feature = "std")]
+    fn flatten_unordered(self, limit: impl Into<Option<usize>>) -> FlattenUnordered<Self>
+        where Self::Item: Stream + Unpin,
+              Self: Sized
+    {
+        FlattenUnordered::new(self, limit.into())
+    }
+
+    /// Maps this stream's items to streams and flattens them, polling up to
+    /// `limit` inner streams concurrently.
+    ///
+    /// This is the same as calling [`map`](StreamExt::map) followed by
+    /// [`flatten_unordered`](StreamExt::flatten_unordered): once `limit`
+    /// inner streams are in progress, no further items are pulled from this
+    /// stream until one of them has ended. Items are yielded in the order in
+    /// which the inner streams produce them, and the returned stream ends once
+    /// this stream and all inner streams have ended. A `limit` of `None` or
+    /// `0` doesn't limit the number of inner streams polled at once.
+    ///
+    /// The streams returned by `f` need to be `Unpin`.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=3)
+    ///     .flat_map_unordered(2, |x| stream::iter(vec![x; x]));
+    ///
+    /// let mut items = await!(stream.collect::<Vec<_>>());
+    /// items.sort();
+    /// assert_eq!(items, vec![1, 2, 2, 3, 3, 3]);
+    /// # })
+    /// ```
+    #[cfg(feature = "std")]
+    fn flat_map_unordered<U, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> FlatMapUnordered<Self, U, F>
+        where F: FnMut(Self::Item) -> U,
+              U: Stream + Unpin,
+              Self: Sized
+    {
+        FlatMapUnordered::new(self, limit.into(), f)
+    }
+
     /// Skip elements on this stream while the provided asynchronous predicate
     /// resolves to `true`.
     ///
@@ -517,6 +898,109 @@ pub trait StreamExt: Stream {
         TakeWhile::new(self, f)
     }
 
+    /// Take elements from this stream until the provided future resolves.
+    ///
+    /// The returned stream yields the items of this stream until `fut`
+    /// resolves, at which point it ends. `fut` is polled each time the
+    /// returned stream is, and is dropped as soon as it has resolved. Its
+    /// output can then be retrieved with
+    /// [`TakeUntil::take_result`](TakeUntil::take_result).
+    ///
+    /// If this stream produces an item in the same poll in which `fut`
+    /// resolves, that item is still yielded and the stream ends on the
+    /// following poll.
+    ///
+    /// This stream is dropped along with `fut` as soon as `fut` has resolved,
+    /// so that its resources are released even if the returned stream is
+    /// kept around. Any items it still held are dropped with it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::{mpsc, oneshot};
+    /// use futures::stream::StreamExt;
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    /// let (stop_tx, stop_rx) = oneshot::channel();
+    /// let mut stream = rx.take_until(stop_rx);
+    ///
+    /// tx.unbounded_send(1).unwrap();
+    /// assert_eq!(await!(stream.next()), Some(1));
+    ///
+    /// stop_tx.send("shutdown").unwrap();
+    /// assert_eq!(await!(stream.next()), None);
+    /// assert_eq!(stream.take_result(), Some(Ok("shutdown")));
+    /// # })
+    /// ```
+    fn take_until<Fut>(self, fut: Fut) -> TakeUntil<Self, Fut>
+        where Fut: Future,
+              Self: Sized
+    {
+        TakeUntil::new(self, fut)
+    }
+
+    /// Threads an asynchronously computed state through the items of this
+    /// stream, yielding the outputs of `f`.
+    ///
+    /// For each item, `f` is called with a mutable reference to the state and
+    /// the item and returns a future. The state is held by the combinator
+    /// until that future has resolved, and only one such future is polled at
+    /// a time. When it resolves to `Some(output)`, `output` is yielded; when
+    /// it resolves to `None` the stream ends, without pulling any further
+    /// items from the underlying stream.
+    ///
+    /// As the returned future can't borrow the state, any part of it that's
+    /// needed by the future has to be cloned or moved into it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10);
+    ///
+    /// let stream = stream.scan_async(0, |sum, x| {
+    ///     *sum += x;
+    ///     future::ready(if *sum < 20 { Some(*sum) } else { None })
+    /// });
+    ///
+    /// assert_eq!(vec![1, 3, 6, 10, 15], block_on(stream.collect::<Vec<_>>()));
+    /// ```
+    ///
+    /// The future returned by `f` may itself await, e.g. to check each running
+    /// total against some asynchronously provided limit:
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let limit = || future::ready(10);
+    ///
+    /// let stream = stream::iter(vec![2, 3, 4, 5]).scan_async(0, |sum, x| {
+    ///     *sum += x;
+    ///     let sum = *sum;
+    ///     async move {
+    ///         if sum <= await!(limit()) { Some(sum) } else { None }
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(await!(stream.collect::<Vec<_>>()), vec![2, 5, 9]);
+    /// # });
+    /// ```
+    fn scan_async<S, B, Fut, F>(self, initial_state: S, f: F) -> ScanAsync<Self, S, Fut, F>
+        where F: FnMut(&mut S, Self::Item) -> Fut,
+              Fut: Future<Output = Option<B>>,
+              Self: Sized
+    {
+        ScanAsync::new(self, initial_state, f)
+    }
+
     /// Runs this stream to completion, executing the provided asynchronous
     /// closure for each element on the stream.
     ///
@@ -637,6 +1121,39 @@ pub trait StreamExt: Stream {
         Take::new(self, n)
     }
 
+    /// Creates a stream which gives the current iteration count as well as
+    /// the next value.
+    ///
+    /// The stream returned yields pairs `(i, val)`, where `i` is the current
+    /// index of iteration and `val` is the value returned by the underlying
+    /// stream, just like `Iterator::enumerate`.
+    ///
+    /// The count is a `usize` and isn't guarded against overflow, so
+    /// enumerating more than `usize::MAX` items panics if overflow checks
+    /// are enabled, and wraps around otherwise.
+    ///
+    /// If the underlying stream is also a sink, the returned stream is a sink
+    /// as well and forwards items sent to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec!['a', 'b', 'c']).enumerate();
+    ///
+    /// assert_eq!(
+    ///     vec![(0, 'a'), (1, 'b'), (2, 'c')],
+    ///     block_on(stream.collect::<Vec<_>>()),
+    /// );
+    /// ```
+    fn enumerate(self) -> Enumerate<Self>
+        where Self: Sized
+    {
+        Enumerate::new(self)
+    }
+
     /// Creates a new stream which skips `n` items of the underlying stream.
     ///
     /// Once `n` items have been skipped from this stream then it will always
@@ -794,10 +1311,38 @@ pub trait StreamExt: Stream {
     /// buffered at any point in time, and less than `n` may also be buffered
     /// depending on the state of each future.
     ///
-    /// The returned stream will be a stream of each future's output.
+    /// The returned stream will be a stream of each future's output. Outputs
+    /// of futures that complete early are held back until the outputs of all
+    /// futures before them have been yielded. Dropping the returned stream
+    /// drops all futures that are still in flight. Errors aren't treated
+    /// specially: if the futures resolve to `Result`s, an `Err` is yielded
+    /// like any other output and the remaining futures keep running.
     ///
     /// This method is only available when the `std` feature of this
     /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::oneshot;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (send_one, recv_one) = oneshot::channel();
+    /// let (send_two, recv_two) = oneshot::channel();
+    ///
+    /// let stream_of_futures = stream::iter(vec![recv_one, recv_two]);
+    /// let mut buffered = stream_of_futures.buffered(10);
+    ///
+    /// send_two.send(2i32);
+    /// send_one.send(1i32);
+    /// assert_eq!(await!(buffered.next()), Some(Ok(1i32)));
+    /// assert_eq!(await!(buffered.next()), Some(Ok(2i32)));
+    ///
+    /// assert_eq!(await!(buffered.next()), None);
+    /// # })
+    /// ```
     #[cfg(feature = "std")]
     fn buffered(self, n: usize) -> Buffered<Self>
         where Self::Item: Future,
@@ -850,11 +1395,65 @@ pub trait StreamExt: Stream {
         BufferUnordered::new(self, n)
     }
 
+    /// An adaptor for creating a buffered list of pending futures (unordered)
+    /// that only pulls new futures from this stream on demand.
+    ///
+    /// Like [`buffer_unordered`](StreamExt::buffer_unordered), this runs up
+    /// to `max` futures concurrently and yields their outputs in the order in
+    /// which they complete. However, instead of polling this stream whenever
+    /// there's room for more futures, a new future is only pulled:
+    ///
+    /// * once per output yielded, when the returned stream is polled again
+    ///   after yielding it, or
+    /// * while fewer than `low_watermark` futures are in flight. At least one
+    ///   future is always kept in flight, even if `low_watermark` is zero.
+    ///
+    /// This way, the consumer of the returned stream decides how fast futures
+    /// are pulled, which matters if polling this stream has side effects such
+    /// as issuing requests.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream_of_futures = stream::iter(1..=4).map(future::ready);
+    /// let mut buffered = stream_of_futures.buffer_unordered_lazy(10, 2);
+    ///
+    /// // Only `low_watermark` futures are pulled up front, and one more for
+    /// // every output yielded after that.
+    /// assert_eq!(await!(buffered.next()), Some(1));
+    /// assert_eq!(await!(buffered.next()), Some(2));
+    /// assert_eq!(await!(buffered.next()), Some(3));
+    /// assert_eq!(await!(buffered.next()), Some(4));
+    /// assert_eq!(await!(buffered.next()), None);
+    /// # })
+    /// ```
+    #[cfg(feature = "std")]
+    fn buffer_unordered_lazy(
+        self,
+        max: usize,
+        low_watermark: usize,
+    ) -> BufferUnorderedLazy<Self>
+        where Self::Item: Future,
+              Self: Sized
+    {
+        BufferUnorderedLazy::new(self, max, low_watermark)
+    }
+
     /// An adapter for zipping two streams together.
     ///
     /// The zipped stream waits for both streams to produce an item, and then
-    /// returns that pair. If either stream ends then the zipped stream will
-    /// also end.
+    /// returns that pair. As soon as either stream ends, the zipped stream
+    /// ends as well, without polling the other stream again. An item that the
+    /// other stream has already produced is dropped, since it can't be paired
+    /// anymore.
     ///
     /// # Examples
     ///
@@ -877,6 +1476,39 @@ pub trait StreamExt: Stream {
         Zip::new(self, other)
     }
 
+    /// An adapter for zipping two streams together, continuing until both of
+    /// them have ended.
+    ///
+    /// While both streams have items left, this waits for both of them to
+    /// produce an item and yields [`EitherOrBoth::Both`] with that pair. An
+    /// item is never yielded on its own because the other stream is merely
+    /// pending. Once one stream has ended, the remaining items of the other
+    /// stream are yielded as [`EitherOrBoth::Left`] or
+    /// [`EitherOrBoth::Right`], which tells which stream ran out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, EitherOrBoth, StreamExt};
+    ///
+    /// let stream1 = stream::iter(1..=3);
+    /// let stream2 = stream::iter(vec!['a']);
+    ///
+    /// let vec = block_on(stream1.zip_longest(stream2).collect::<Vec<_>>());
+    /// assert_eq!(vec, vec![
+    ///     EitherOrBoth::Both(1, 'a'),
+    ///     EitherOrBoth::Left(2),
+    ///     EitherOrBoth::Left(3),
+    /// ]);
+    /// ```
+    fn zip_longest<St>(self, other: St) -> ZipLongest<Self, St>
+        where St: Stream,
+              Self: Sized,
+    {
+        ZipLongest::new(self, other)
+    }
+
     /// Adapter for chaining two stream.
     ///
     /// The resulting stream emits elements from the first stream, and when
@@ -906,6 +1538,162 @@ pub trait StreamExt: Stream {
         Chain::new(self, other)
     }
 
+    /// Repeats the stream endlessly.
+    ///
+    /// Since a stream can only be iterated once, the stream is cloned before
+    /// it's first polled, and each time it ends, it's replaced by another
+    /// clone of that original, which starts over from the beginning.
+    ///
+    /// The returned stream ends if the stream ends without having yielded any
+    /// items, so cycling an empty stream results in an empty stream. Streams
+    /// that can't be cloned can be repeated with
+    /// [`stream::cycle_with`](cycle_with) instead.
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 2, 3]).cycle();
+    ///
+    /// let result: Vec<_> = block_on(stream.take(7).collect());
+    /// assert_eq!(result, vec![1, 2, 3, 1, 2, 3, 1]);
+    /// ```
+    fn cycle(self) -> Cycle<Self>
+        where Self: Sized + Clone,
+    {
+        Cycle::new(self)
+    }
+
+    /// Wraps the stream so that it yields an error if the next item doesn't
+    /// arrive in time.
+    ///
+    /// This library doesn't provide timers, so the timeout is given as a
+    /// closure `make_timer` which creates a timer future, e.g. a delay from
+    /// the timer of the runtime in use. Whenever the stream is polled and
+    /// doesn't have an item ready, a timer is created unless one is already
+    /// running. If the timer completes before the stream produces an item,
+    /// `Err(Elapsed)` is yielded and the timer is reset. The stream may still
+    /// produce items afterwards, so the consumer decides whether a timeout
+    /// ends the stream. Every item that is produced resets the timer as well.
+    ///
+    /// The timeout applies to the first item too. Use
+    /// [`Timeout::exempt_first_item`] to wait for the first item without
+    /// a timeout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::future;
+    /// use futures::stream::{Elapsed, StreamExt};
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    /// tx.unbounded_send(1).unwrap();
+    ///
+    /// // A timer that fires right away, for the sake of the example
+    /// let mut stream = rx.timeout(|| future::ready(()));
+    ///
+    /// assert_eq!(await!(stream.next()), Some(Ok(1)));
+    /// assert_eq!(await!(stream.next()), Some(Err(Elapsed)));
+    /// # drop(tx);
+    /// # });
+    /// ```
+    fn timeout<F, T>(self, make_timer: F) -> Timeout<Self, F, T>
+        where F: FnMut() -> T,
+              T: Future<Output = ()>,
+              Self: Sized
+    {
+        Timeout::new(self, make_timer)
+    }
+
+    /// Rate-limits this stream by waiting for a cooldown after each item.
+    ///
+    /// As with [`timeout`](StreamExt::timeout), the cooldown is given as a
+    /// closure `make_timer` which creates a timer future, since this library
+    /// doesn't provide timers. A timer is created as soon as an item is
+    /// yielded, and the next item is only yielded once that timer has
+    /// completed. The timer is responsible for waking the task when it
+    /// fires.
+    ///
+    /// Items are delayed but never dropped. No items are buffered either:
+    /// the underlying stream is left unpolled until the cooldown is over, so
+    /// a stream with backpressure, such as a bounded channel, pushes back on
+    /// its producer in the meantime.
+    ///
+    /// The first item passes through right away, and an idle period doesn't
+    /// let the stream build up credit for a burst of items afterwards. Use
+    /// [`Throttle::burst`] to allow several items to pass back to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// // A timer that fires right away, for the sake of the example
+    /// let stream = stream::iter(1..=3).throttle(|| future::ready(()));
+    ///
+    /// assert_eq!(await!(stream.collect::<Vec<_>>()), vec![1, 2, 3]);
+    /// # });
+    /// ```
+    fn throttle<F, T>(self, make_timer: F) -> Throttle<Self, F, T>
+        where F: FnMut() -> T,
+              T: Future<Output = ()>,
+              Self: Sized
+    {
+        Throttle::new(self, make_timer)
+    }
+
+    /// Coalesces bursts of items, only yielding the last item of each burst
+    /// once the stream has been quiet for a while.
+    ///
+    /// As with [`throttle`](StreamExt::throttle), the quiet period is given
+    /// as a closure `make_timer` which creates a timer future, since this
+    /// library doesn't provide timers. Every item that arrives replaces the
+    /// previously held item, which is dropped, and restarts the timer. Once
+    /// the timer fires without a new item having arrived, the held item is
+    /// yielded. The timer is responsible for waking the task when it fires.
+    ///
+    /// The underlying stream is drained as items arrive, so it doesn't see
+    /// any backpressure. When it ends, the item being held back, if any, is
+    /// yielded right away without waiting for the timer, and the stream ends
+    /// after that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::future;
+    /// use futures::stream::StreamExt;
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    /// tx.unbounded_send("config v1").unwrap();
+    /// tx.unbounded_send("config v2").unwrap();
+    /// tx.unbounded_send("config v3").unwrap();
+    ///
+    /// // A timer that fires right away, for the sake of the example
+    /// let mut stream = rx.debounce(|| future::ready(()));
+    ///
+    /// assert_eq!(await!(stream.next()), Some("config v3"));
+    ///
+    /// drop(tx);
+    /// assert_eq!(await!(stream.next()), None);
+    /// # });
+    /// ```
+    fn debounce<F, T>(self, make_timer: F) -> Debounce<Self, F, T>
+        where F: FnMut() -> T,
+              T: Future<Output = ()>,
+              Self: Sized
+    {
+        Debounce::new(self, make_timer)
+    }
+
     /// Creates a new stream which exposes a `peek` method.
     ///
     /// Calling `peek` returns a reference to the next item in the stream.
@@ -940,6 +1728,98 @@ pub trait StreamExt: Stream {
         Chunks::new(self, capacity)
     }
 
+    /// An adaptor for chunking up items that are available immediately inside
+    /// a vector.
+    ///
+    /// Unlike [`chunks`](StreamExt::chunks), this combinator doesn't wait for
+    /// a chunk to fill up. It pulls items from this stream for as long as the
+    /// stream is ready to produce them, and yields the buffered items as soon
+    /// as the stream returns [`Poll::Pending`] or `capacity` items have been
+    /// buffered. The yielded vectors are never empty. If the underlying stream
+    /// ends, the remaining buffered items are yielded before the end of the
+    /// stream.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(futures_api)]
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=5).ready_chunks(2);
+    /// let chunks = block_on(stream.collect::<Vec<_>>());
+    /// assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    #[cfg(feature = "std")]
+    fn ready_chunks(self, capacity: usize) -> ReadyChunks<Self>
+        where Self: Sized
+    {
+        ReadyChunks::new(self, capacity)
+    }
+
+    /// An adaptor for chunking up items of the stream inside a vector, which
+    /// yields a partial chunk if it isn't filled in time.
+    ///
+    /// Like [`chunks`](StreamExt::chunks), this buffers at most `capacity`
+    /// items and yields them once the buffer is full. In addition, a timer is
+    /// started when the first item of a chunk arrives, and if it fires before
+    /// the chunk is full, the items buffered so far are yielded. As with
+    /// [`timeout`](StreamExt::timeout), the timer is created by the
+    /// `make_timer` closure since this library doesn't provide timers.
+    ///
+    /// Chunks are never empty: no timer runs while nothing is buffered. When
+    /// the underlying stream ends, the final partial chunk is yielded.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::future;
+    /// use futures::stream::StreamExt;
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    ///
+    /// // A timer that fires right away, for the sake of the example
+    /// let mut stream = rx.chunks_timeout(3, || future::ready(()));
+    ///
+    /// tx.unbounded_send(1).unwrap();
+    /// assert_eq!(await!(stream.next()), Some(vec![1]));
+    ///
+    /// for i in 2..=5 {
+    ///     tx.unbounded_send(i).unwrap();
+    /// }
+    /// assert_eq!(await!(stream.next()), Some(vec![2, 3, 4]));
+    ///
+    /// drop(tx);
+    /// assert_eq!(await!(stream.next()), Some(vec![5]));
+    /// assert_eq!(await!(stream.next()), None);
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn chunks_timeout<F, T>(self, capacity: usize, make_timer: F) -> ChunksTimeout<Self, F, T>
+        where F: FnMut() -> T,
+              T: Future<Output = ()>,
+              Self: Sized
+    {
+        ChunksTimeout::new(self, capacity, make_timer)
+    }
+
     /// This combinator will attempt to pull items from both streams. Each
     /// stream will be polled in a round-robin fashion, and whenever a stream is
     /// ready to yield an item that item is yielded.
@@ -957,6 +1837,43 @@ pub trait StreamExt: Stream {
         Select::new(self, other)
     }
 
+    /// Merges this stream with another one, alternating fairly between the
+    /// two.
+    ///
+    /// Both streams are polled in a strict round-robin fashion: after one
+    /// stream has yielded an item, the other stream gets the first chance to
+    /// yield the next one. If both streams are always ready, their items are
+    /// therefore interleaved one by one rather than one stream being drained
+    /// first. The returned stream completes when both input streams have
+    /// completed.
+    ///
+    /// This combinator doesn't inspect the items it yields, so an `Err` item
+    /// from a stream of `Result`s is passed through like any other item and
+    /// both streams keep being polled. Use
+    /// [`TryStreamExt::try_merge`](crate::try_stream::TryStreamExt::try_merge)
+    /// to end the merged stream after the first error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream1 = stream::iter(vec![1, 3, 5, 7]);
+    /// let stream2 = stream::iter(vec![2, 4]);
+    ///
+    /// let merged = stream1.merge(stream2);
+    /// assert_eq!(await!(merged.collect::<Vec<_>>()), vec![1, 2, 3, 4, 5, 7]);
+    /// # });
+    /// ```
+    fn merge<St>(self, other: St) -> Merge<Self, St>
+        where St: Stream<Item = Self::Item>,
+              Self: Sized,
+    {
+        Merge::new(self, other)
+    }
+
     /// A future that completes after the given stream has been fully processed
     /// into the sink, including flushing.
     ///
@@ -994,6 +1911,107 @@ pub trait StreamExt: Stream {
         split::split(self)
     }
 
+    /// Splits this stream into two streams based on a predicate.
+    ///
+    /// Items for which `pred` returns `true` are yielded by the first of the
+    /// returned streams, all other items by the second one. Both halves pull
+    /// items from this stream as they are polled.
+    ///
+    /// Only a single item is buffered for each half. While an item is
+    /// waiting to be taken by one half, the other half doesn't pull any
+    /// further items, so a slow consumer of one half slows down the whole
+    /// stream instead of making the buffer grow. Both halves therefore need
+    /// to be polled for either of them to make progress. Once a half has been
+    /// dropped, the items meant for it are discarded and the other half
+    /// continues on its own.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (mut evens, mut odds) = stream::iter(1..=4).partition(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(await!(odds.next()), Some(1));
+    /// assert_eq!(await!(evens.next()), Some(2));
+    /// assert_eq!(await!(odds.next()), Some(3));
+    ///
+    /// // The remaining even number is discarded
+    /// drop(evens);
+    /// assert_eq!(await!(odds.next()), None);
+    /// # })
+    /// ```
+    #[cfg(feature = "std")]
+    fn partition<P>(self, pred: P) -> (Partition<Self, P>, Partition<Self, P>)
+        where P: FnMut(&Self::Item) -> bool,
+              Self: Sized
+    {
+        partition::new(self, pred)
+    }
+
+    /// Splits this stream into groups of items which share a key.
+    ///
+    /// The returned stream yields a `(key, group)` pair the first time
+    /// `key_fn` returns a key, where `group` is a stream of the items with
+    /// that key, in the order in which they were pulled from this stream.
+    /// Items of different groups may be interleaved in this stream, so the
+    /// returned stream and each of the groups pull items from this stream as
+    /// they are polled and buffer the ones meant for the others.
+    ///
+    /// At most `buffer` items are buffered for each group, and at most
+    /// `buffer` new groups are waiting to be yielded. Once either limit is
+    /// reached, no further items are pulled until the items or groups have
+    /// been taken, so a slow group slows down the whole stream. The returned
+    /// stream and all of the groups therefore need to be polled concurrently
+    /// for them to make progress, or be dropped.
+    ///
+    /// Once a group has been dropped, its buffered items and all further
+    /// items with its key are discarded, and no new group is created for the
+    /// key. Once the returned stream has been dropped, the items of keys
+    /// which haven't been seen yet are discarded, while the existing groups
+    /// continue on their own. A key is remembered until all of the streams
+    /// have been dropped.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut groups = stream::iter(vec![1, 2, 3, 4]).group_by(4, |x| x % 2);
+    ///
+    /// let (key, odds) = await!(groups.next()).unwrap();
+    /// assert_eq!(key, 1);
+    /// let (key, evens) = await!(groups.next()).unwrap();
+    /// assert_eq!(key, 0);
+    /// assert!(await!(groups.next()).is_none());
+    ///
+    /// assert_eq!(await!(odds.collect::<Vec<_>>()), vec![1, 3]);
+    /// assert_eq!(await!(evens.collect::<Vec<_>>()), vec![2, 4]);
+    /// # })
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `buffer` is zero.
+    #[cfg(feature = "std")]
+    fn group_by<K, F>(self, buffer: usize, key_fn: F) -> GroupBy<Self, K, F>
+        where K: Eq + std::hash::Hash + Clone,
+              F: FnMut(&Self::Item) -> K,
+              Self: Sized
+    {
+        group_by::new(self, buffer, key_fn)
+    }
+
     /// Do something with each item of this stream, afterwards passing it on.
     ///
     /// This is similar to the `Iterator::inspect` method in the standard
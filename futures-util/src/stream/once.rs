@@ -50,4 +50,12 @@ impl<Fut: Future> Stream for Once<Fut> {
         Pin::set(self.future(), None);
         Poll::Ready(Some(val))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.future.is_some() {
+            (1, Some(1))
+        } else {
+            (0, Some(0))
+        }
+    }
 }
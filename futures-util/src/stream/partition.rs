@@ -0,0 +1,138 @@
+use crate::stream::{Fuse, StreamExt};
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll, Waker};
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+const LEFT: usize = 0;
+const RIGHT: usize = 1;
+
+/// One of the two streams returned by the
+/// [`partition`](super::StreamExt::partition) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct Partition<St: Stream, P> {
+    inner: Arc<Mutex<Inner<St, P>>>,
+    side: usize,
+}
+
+struct Inner<St: Stream, P> {
+    stream: Fuse<St>,
+    pred: P,
+    sides: [Side<St::Item>; 2],
+}
+
+struct Side<T> {
+    // An item pulled by the other half which this half hasn't yielded yet
+    item: Option<T>,
+    // The task waiting for this half, if any
+    waker: Option<Waker>,
+    dropped: bool,
+}
+
+impl<T> Side<T> {
+    fn new() -> Side<T> {
+        Side { item: None, waker: None, dropped: false }
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+pub(super) fn new<St, P>(stream: St, pred: P) -> (Partition<St, P>, Partition<St, P>)
+    where St: Stream,
+          P: FnMut(&St::Item) -> bool,
+{
+    let inner = Arc::new(Mutex::new(Inner {
+        stream: stream.fuse(),
+        pred,
+        sides: [Side::new(), Side::new()],
+    }));
+    let left = Partition { inner: inner.clone(), side: LEFT };
+    let right = Partition { inner, side: RIGHT };
+    (left, right)
+}
+
+impl<St, P> Stream for Partition<St, P>
+    where St: Stream,
+          P: FnMut(&St::Item) -> bool,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<St::Item>> {
+        let (this, other) = (self.side, 1 - self.side);
+        let mut inner = self.inner.lock().unwrap();
+        let inner = &mut *inner;
+
+        if let Some(item) = inner.sides[this].item.take() {
+            // The other half may be waiting for our slot to be emptied
+            inner.sides[other].wake();
+            return Poll::Ready(Some(item));
+        }
+
+        loop {
+            // Only one item is buffered per half, so if the other half
+            // hasn't taken its item yet, we have to wait for it.
+            if inner.sides[other].item.is_some() {
+                inner.sides[this].waker = Some(lw.clone().into_waker());
+                return Poll::Pending;
+            }
+
+            // Safety: The stream lives behind the `Arc` and is never moved
+            // out of it, so it stays pinned until it is dropped.
+            let stream = unsafe { Pin::new_unchecked(&mut inner.stream) };
+            match stream.poll_next(lw) {
+                Poll::Ready(Some(item)) => {
+                    let side = if (inner.pred)(&item) { LEFT } else { RIGHT };
+                    if side == this {
+                        // The stream now wakes our task only. Let the other
+                        // half know so that it can poll the stream itself.
+                        inner.sides[other].wake();
+                        return Poll::Ready(Some(item));
+                    }
+                    if !inner.sides[other].dropped {
+                        inner.sides[other].item = Some(item);
+                        inner.sides[other].wake();
+                    }
+                }
+                Poll::Ready(None) => {
+                    inner.sides[other].wake();
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => {
+                    inner.sides[this].waker = Some(lw.clone().into_waker());
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+impl<St: Stream, P> Drop for Partition<St, P> {
+    fn drop(&mut self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            let other = 1 - self.side;
+            let side = &mut inner.sides[self.side];
+            side.dropped = true;
+            side.item = None;
+            side.waker = None;
+            // The other half may be blocked on our slot or rely on us
+            // polling the stream.
+            inner.sides[other].wake();
+        }
+    }
+}
+
+impl<St: Stream, P> fmt::Debug for Partition<St, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Partition")
+            .field("side", &if self.side == LEFT { "left" } else { "right" })
+            .finish()
+    }
+}
@@ -1,6 +1,8 @@
 use crate::stream::{StreamExt, Fuse};
+use core::fmt;
 use core::marker::Unpin;
 use core::pin::Pin;
+use futures_core::future::Future;
 use futures_core::stream::Stream;
 use futures_core::task::{LocalWaker, Poll};
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
@@ -32,8 +34,11 @@ impl<St: Stream> Peekable<St> {
 
     /// Peek retrieves a reference to the next item in the stream.
     ///
-    /// This method polls the underlying stream and return either a reference
-    /// to the next item if the stream is ready or passes through any errors.
+    /// If no item is buffered yet, this method polls the underlying stream
+    /// and buffers the item it produces, returning `Pending` if there is none
+    /// yet. The buffered item is kept until it's returned by `poll_next`, so
+    /// peeking repeatedly returns the same item. Once the stream has ended,
+    /// `Ready(None)` is returned.
     pub fn peek<'a>(
         self: &'a mut Pin<&mut Self>,
         lw: &LocalWaker,
@@ -49,6 +54,125 @@ impl<St: Stream> Peekable<St> {
             }
         }
     }
+
+    /// Creates a future which resolves to a mutable reference to the next
+    /// item in the stream.
+    ///
+    /// This works like [`peek`](Peekable::peek), but allows modifying the
+    /// peeked item before it is returned by a subsequent call to `poll`. The
+    /// future resolves to `None` if the stream has ended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// // Parses words, turning the first letter of each into upper case
+    /// let mut stream = stream::iter("to be".chars()).peekable();
+    ///
+    /// let mut words = Vec::new();
+    /// while let Some(first) = await!(stream.peek_mut()) {
+    ///     *first = first.to_ascii_uppercase();
+    ///     let mut word = String::new();
+    ///     while let Some(c) = await!(stream.next_if(|c| *c != ' ')) {
+    ///         word.push(c);
+    ///     }
+    ///     await!(stream.next_if_eq(&' '));
+    ///     words.push(word);
+    /// }
+    ///
+    /// assert_eq!(words, vec!["To", "Be"]);
+    /// # })
+    /// ```
+    pub fn peek_mut(&mut self) -> PeekMut<'_, St>
+        where Self: Unpin,
+    {
+        PeekMut { stream: Some(self) }
+    }
+
+    /// Creates a future which resolves to the next item in the stream if
+    /// `func` returns `true` for it.
+    ///
+    /// If `func` returns `false`, the future resolves to `None` and the item
+    /// stays in place, to be returned by the next call to `poll` or `peek`.
+    /// The future also resolves to `None` if the stream has ended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// // Parses a number followed by a unit
+    /// let mut stream = stream::iter("42km".chars()).peekable();
+    ///
+    /// let mut number = 0;
+    /// while let Some(digit) = await!(stream.next_if(|c| c.is_digit(10))) {
+    ///     number = number * 10 + digit.to_digit(10).unwrap();
+    /// }
+    /// let unit: String = await!(stream.collect());
+    ///
+    /// assert_eq!((number, unit.as_str()), (42, "km"));
+    /// # })
+    /// ```
+    pub fn next_if<F>(&mut self, func: F) -> NextIf<'_, St, F>
+        where F: FnOnce(&St::Item) -> bool,
+              Self: Unpin,
+    {
+        NextIf { stream: self, func: Some(func) }
+    }
+
+    /// Creates a future which resolves to the next item in the stream if it
+    /// is equal to `expected`.
+    ///
+    /// This is the same as [`next_if`](Peekable::next_if) with a predicate
+    /// comparing the item to `expected`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// // Skips an optional sign in front of a number
+    /// let mut stream = stream::iter("-12".chars()).peekable();
+    ///
+    /// let negative = await!(stream.next_if_eq(&'-')).is_some();
+    /// let positive = await!(stream.next_if_eq(&'+')).is_some();
+    /// let digits: String = await!(stream.collect());
+    ///
+    /// assert_eq!((negative, positive, digits.as_str()), (true, false, "12"));
+    /// # })
+    /// ```
+    pub fn next_if_eq<'a, T>(&'a mut self, expected: &'a T) -> NextIfEq<'a, St, T>
+        where T: ?Sized,
+              St::Item: PartialEq<T>,
+              Self: Unpin,
+    {
+        NextIfEq { stream: self, expected }
+    }
+
+    fn poll_next_if<F>(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+        func: F,
+    ) -> Poll<Option<St::Item>>
+        where F: FnOnce(&St::Item) -> bool,
+    {
+        let matches = match ready!(self.peek(lw)) {
+            Some(item) => func(item),
+            None => return Poll::Ready(None),
+        };
+        if matches {
+            Poll::Ready(self.peeked().take())
+        } else {
+            Poll::Ready(None)
+        }
+    }
 }
 
 impl<S: Stream> Stream for Peekable<S> {
@@ -63,6 +187,133 @@ impl<S: Stream> Stream for Peekable<S> {
         }
         self.stream().poll_next(lw)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peek_len = if self.peeked.is_some() { 1 } else { 0 };
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(peek_len);
+        let upper = match upper {
+            Some(x) => x.checked_add(peek_len),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+/// Future for the [`Peekable::peek_mut`](Peekable::peek_mut) method.
+#[must_use = "futures do nothing unless polled"]
+pub struct PeekMut<'a, St: Stream> {
+    stream: Option<&'a mut Peekable<St>>,
+}
+
+impl<St: Stream> Unpin for PeekMut<'_, St> {}
+
+impl<St> fmt::Debug for PeekMut<'_, St>
+    where St: Stream + fmt::Debug,
+          St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PeekMut")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<'a, St> Future for PeekMut<'a, St>
+    where St: Stream + Unpin,
+{
+    type Output = Option<&'a mut St::Item>;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Self::Output> {
+        {
+            let stream = self.stream.as_mut().expect("PeekMut polled after completion");
+            ready!(Pin::new(&mut **stream).peek(lw));
+        }
+        // The item is buffered now, unless the stream has ended
+        let stream = self.stream.take().unwrap();
+        Poll::Ready(stream.peeked.as_mut())
+    }
+}
+
+/// Future for the [`Peekable::next_if`](Peekable::next_if) method.
+#[must_use = "futures do nothing unless polled"]
+pub struct NextIf<'a, St: Stream, F> {
+    stream: &'a mut Peekable<St>,
+    func: Option<F>,
+}
+
+// The predicate is never pinned
+impl<St: Stream, F> Unpin for NextIf<'_, St, F> {}
+
+impl<St, F> fmt::Debug for NextIf<'_, St, F>
+    where St: Stream + fmt::Debug,
+          St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NextIf")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<St, F> Future for NextIf<'_, St, F>
+    where St: Stream + Unpin,
+          F: FnOnce(&St::Item) -> bool,
+{
+    type Output = Option<St::Item>;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let func = &mut this.func;
+        Pin::new(&mut *this.stream).poll_next_if(lw, |item| {
+            (func.take().expect("NextIf polled after completion"))(item)
+        })
+    }
+}
+
+/// Future for the [`Peekable::next_if_eq`](Peekable::next_if_eq) method.
+#[must_use = "futures do nothing unless polled"]
+pub struct NextIfEq<'a, St: Stream, T: ?Sized> {
+    stream: &'a mut Peekable<St>,
+    expected: &'a T,
+}
+
+impl<St: Stream, T: ?Sized> Unpin for NextIfEq<'_, St, T> {}
+
+impl<St, T> fmt::Debug for NextIfEq<'_, St, T>
+    where St: Stream + fmt::Debug,
+          St::Item: fmt::Debug,
+          T: fmt::Debug + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NextIfEq")
+            .field("stream", &self.stream)
+            .field("expected", &self.expected)
+            .finish()
+    }
+}
+
+impl<St, T> Future for NextIfEq<'_, St, T>
+    where St: Stream + Unpin,
+          St::Item: PartialEq<T>,
+          T: ?Sized,
+{
+    type Output = Option<St::Item>;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Self::Output> {
+        let expected = self.expected;
+        Pin::new(&mut *Pin::get_mut(self).stream)
+            .poll_next_if(lw, |item| item == expected)
+    }
 }
 
 /* TODO
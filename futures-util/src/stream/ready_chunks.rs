@@ -0,0 +1,118 @@
+use crate::stream::Fuse;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::marker::Unpin;
+use std::mem;
+use std::pin::Pin;
+use std::prelude::v1::*;
+
+/// An adaptor that chunks up elements that are available immediately in a
+/// vector.
+///
+/// This adaptor will buffer up items of the stream as long as the stream
+/// keeps producing them without blocking, and pass on the vector used for
+/// buffering once the stream isn't ready or the capacity has been reached.
+/// A buffer is only allocated once an item is available, so polling a stream
+/// that isn't ready doesn't allocate. This is created by the
+/// `Stream::ready_chunks` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ReadyChunks<St: Stream> {
+    stream: Fuse<St>,
+    items: Vec<St::Item>,
+    cap: usize,
+}
+
+impl<St: Unpin + Stream> Unpin for ReadyChunks<St> {}
+
+impl<St: Stream> ReadyChunks<St> where St: Stream {
+    unsafe_unpinned!(items:  Vec<St::Item>);
+    unsafe_pinned!(stream: Fuse<St>);
+
+    pub(super) fn new(stream: St, capacity: usize) -> ReadyChunks<St> {
+        assert!(capacity > 0);
+
+        ReadyChunks {
+            stream: super::Fuse::new(stream),
+            items: Vec::new(),
+            cap: capacity,
+        }
+    }
+
+    fn take(mut self: Pin<&mut Self>) -> Vec<St::Item> {
+        mem::replace(self.items(), Vec::new())
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+}
+
+impl<St: Stream> Stream for ReadyChunks<St> {
+    type Item = Vec<St::Item>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<Self::Item>> {
+        let cap = self.cap;
+        loop {
+            match self.stream().poll_next(lw) {
+                // The stream isn't ready, so hand out what we have so far
+                // instead of waiting for more items.
+                Poll::Pending => {
+                    return if self.items().is_empty() {
+                        Poll::Pending
+                    } else {
+                        Poll::Ready(Some(self.take()))
+                    }
+                }
+
+                // Push the item into the buffer and check whether it is full.
+                // If so, replace our buffer with a new and empty one and return
+                // the full one.
+                Poll::Ready(Some(item)) => {
+                    if self.items().is_empty() {
+                        self.items().reserve_exact(cap);
+                    }
+                    self.items().push(item);
+                    if self.items().len() >= cap {
+                        return Poll::Ready(Some(self.take()))
+                    }
+                }
+
+                // Since the underlying stream ran out of values, return what we
+                // have buffered, if we have anything.
+                Poll::Ready(None) => {
+                    let last = if self.items().is_empty() {
+                        None
+                    } else {
+                        Some(self.take())
+                    };
+
+                    return Poll::Ready(last);
+                }
+            }
+        }
+    }
+}
@@ -6,7 +6,7 @@ use futures_core::task::{LocalWaker, Poll};
 /// Stream that produces the same element repeatedly.
 ///
 /// This structure is created by the `stream::repeat` function.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 #[must_use = "streams do nothing unless polled"]
 pub struct Repeat<T> {
     item: T,
@@ -41,4 +41,8 @@ impl<T> Stream for Repeat<T>
     fn poll_next(self: Pin<&mut Self>, _: &LocalWaker) -> Poll<Option<Self::Item>> {
         Poll::Ready(Some(self.item.clone()))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::max_value(), None)
+    }
 }
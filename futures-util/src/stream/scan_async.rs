@@ -0,0 +1,117 @@
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`scan_async`](super::StreamExt::scan_async) combinator.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ScanAsync<St, S, Fut, F> {
+    stream: St,
+    state: S,
+    f: F,
+    future: Option<Fut>,
+    done: bool,
+}
+
+impl<St: Unpin, S, Fut: Unpin, F> Unpin for ScanAsync<St, S, Fut, F> {}
+
+impl<St, S, Fut, F> ScanAsync<St, S, Fut, F>
+    where St: Stream,
+          F: FnMut(&mut S, St::Item) -> Fut,
+          Fut: Future,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(state: S);
+    unsafe_unpinned!(f: F);
+    unsafe_pinned!(future: Option<Fut>);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, initial_state: S, f: F) -> ScanAsync<St, S, Fut, F> {
+        ScanAsync {
+            stream,
+            state: initial_state,
+            f,
+            future: None,
+            done: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, S, Fut, F, B> Stream for ScanAsync<St, S, Fut, F>
+    where St: Stream,
+          F: FnMut(&mut S, St::Item) -> Fut,
+          Fut: Future<Output = Option<B>>,
+{
+    type Item = B;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<B>> {
+        if *self.done() {
+            return Poll::Ready(None);
+        }
+
+        if self.future.is_none() {
+            let item = match ready!(self.stream().poll_next(lw)) {
+                Some(e) => e,
+                None => {
+                    *self.done() = true;
+                    return Poll::Ready(None);
+                }
+            };
+            let fut = {
+                let ScanAsync { state, f, .. } =
+                    unsafe { Pin::get_mut_unchecked(self.as_mut()) };
+                f(state, item)
+            };
+            Pin::set(self.future(), Some(fut));
+        }
+
+        let output = ready!(self.future().as_pin_mut().unwrap().poll(lw));
+        Pin::set(self.future(), None);
+
+        if output.is_none() {
+            *self.done() = true;
+        }
+        Poll::Ready(output)
+    }
+}
+
+/* TODO
+// Forwarding impl of Sink from the underlying stream
+impl<S, St, Fut, F> Sink for ScanAsync<St, S, Fut, F>
+    where St: Sink + Stream
+{
+    type SinkItem = St::SinkItem;
+    type SinkError = St::SinkError;
+
+    delegate_sink!(stream);
+}
+*/
@@ -0,0 +1,198 @@
+//! An unbounded set of streams
+
+use std::fmt::{self, Debug};
+use std::marker::Unpin;
+use std::pin::Pin;
+
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+
+use crate::stream::{StreamExt, StreamFuture, FuturesUnordered, QueueHandle};
+
+/// An unbounded set of streams
+///
+/// This "combinator" provides the ability to maintain a set of streams
+/// and drive them all to completion.
+///
+/// Streams are pushed into this set and their realized values are
+/// yielded as they become ready. Streams will only be polled when they
+/// generate notifications. This allows to coordinate a large number of streams.
+///
+/// Note that you can create a ready-made `SelectAll` via the
+/// [`select_all`](select_all()) function in the `stream` module, or you can
+/// start with an empty set with the [`SelectAll::new`] constructor.
+///
+/// Like [`FuturesUnordered`], a `SelectAll` yields `None` whenever it runs
+/// out of streams, but it can be reused afterwards: streams that are pushed
+/// into it later on are polled by the next call to
+/// [`poll_next`](Stream::poll_next).
+#[must_use = "streams do nothing unless polled"]
+pub struct SelectAll<St> {
+    inner: FuturesUnordered<StreamFuture<St>>,
+}
+
+impl<St: Debug> Debug for SelectAll<St> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("SelectAll")
+            .field("len", &self.inner.len())
+            .finish()
+    }
+}
+
+impl<St: Stream + Unpin> SelectAll<St> {
+    /// Constructs a new, empty `SelectAll`
+    ///
+    /// The returned `SelectAll` does not contain any streams and, in this
+    /// state, [`SelectAll::poll_next`](Stream::poll_next) will return
+    /// [`Poll::Ready(None)`](Poll::Ready).
+    pub fn new() -> SelectAll<St> {
+        SelectAll { inner: FuturesUnordered::new() }
+    }
+
+    /// Returns the number of streams contained in the set.
+    ///
+    /// This represents the total number of in-flight streams.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the set contains no streams
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Push a stream into the set.
+    ///
+    /// This function submits the given stream to the set for managing. This
+    /// function will not call `poll` on the submitted stream. The caller must
+    /// ensure that `SelectAll::poll_next` is called in order to receive task
+    /// notifications.
+    pub fn push(&mut self, stream: St) {
+        self.inner.push(stream.into_future());
+    }
+
+    /// Returns a handle which can be used to push streams into the set while
+    /// it is being polled, e.g. from within a
+    /// [`for_each`](super::StreamExt::for_each) that consumes the set.
+    ///
+    /// Streams pushed through the handle are added to the set the next time
+    /// it's polled, so they aren't counted by [`len`](SelectAll::len) until
+    /// then. Note that a handle can't revive a set whose consumer has
+    /// already seen it end.
+    pub fn handle(&self) -> SelectAllHandle<St>
+        where St: Send,
+    {
+        SelectAllHandle { inner: self.inner.handle() }
+    }
+}
+
+impl<St: Stream + Unpin> Default for SelectAll<St> {
+    fn default() -> SelectAll<St> {
+        SelectAll::new()
+    }
+}
+
+impl<St: Stream + Unpin> Stream for SelectAll<St> {
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(self.inner.poll_next_unpin(lw)) {
+                Some((Some(item), remaining)) => {
+                    self.push(remaining);
+                    return Poll::Ready(Some(item));
+                }
+                // This stream has ended, but the others may still have items
+                Some((None, _)) => {}
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// A handle for pushing streams into a [`SelectAll`] while it is being
+/// polled.
+///
+/// This is created by the [`SelectAll::handle`] method.
+pub struct SelectAllHandle<St> {
+    inner: QueueHandle<StreamFuture<St>>,
+}
+
+impl<St: Stream + Unpin> SelectAllHandle<St> {
+    /// Push a stream into the set this handle belongs to.
+    ///
+    /// The stream is added to the set the next time the set is polled. If
+    /// the set has already been dropped, the stream is returned in the `Err`
+    /// variant.
+    pub fn push(&self, stream: St) -> Result<(), St> {
+        self.inner.push(stream.into_future())
+            .map_err(|future| future.into_inner().unwrap())
+    }
+}
+
+impl<St> Clone for SelectAllHandle<St> {
+    fn clone(&self) -> SelectAllHandle<St> {
+        SelectAllHandle { inner: self.inner.clone() }
+    }
+}
+
+impl<St> Debug for SelectAllHandle<St> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("SelectAllHandle").finish()
+    }
+}
+
+/// Convert a list of streams into a `Stream` of results from the streams.
+///
+/// This essentially takes a list of streams (e.g. a vector, an iterator, etc.)
+/// and bundles them together into a single stream.
+/// The stream will yield items as they become available on the underlying
+/// streams internally, in the order they become available.
+///
+/// Note that the returned set can also be used to dynamically push more
+/// streams into the set as they become available.
+///
+/// The streams need to be `Unpin`; streams that aren't can be pinned with
+/// `Box::pinned` first.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro)]
+/// # futures::executor::block_on(async {
+/// use futures::channel::mpsc;
+/// use futures::stream::{self, StreamExt};
+///
+/// let (tx1, rx1) = mpsc::unbounded();
+/// let (tx2, rx2) = mpsc::unbounded();
+/// let mut merged = stream::select_all(vec![rx1]);
+///
+/// tx1.unbounded_send(1).unwrap();
+/// assert_eq!(await!(merged.next()), Some(1));
+///
+/// // Streams can be added while the set is in use
+/// merged.push(rx2);
+/// assert_eq!(merged.len(), 2);
+/// tx2.unbounded_send(2).unwrap();
+/// assert_eq!(await!(merged.next()), Some(2));
+///
+/// drop((tx1, tx2));
+/// assert_eq!(await!(merged.next()), None);
+/// assert!(merged.is_empty());
+/// # })
+/// ```
+pub fn select_all<I>(streams: I) -> SelectAll<I::Item>
+    where I: IntoIterator,
+          I::Item: Stream + Unpin
+{
+    let mut set = SelectAll::new();
+
+    for stream in streams {
+        set.push(stream);
+    }
+
+    set
+}
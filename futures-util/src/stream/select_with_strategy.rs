@@ -0,0 +1,207 @@
+use crate::stream::{StreamExt, Fuse};
+use core::fmt;
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+
+/// Which of the two streams of a
+/// [`SelectWithStrategy`](SelectWithStrategy) to poll first.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PollNext {
+    /// Poll the first stream.
+    Left,
+    /// Poll the second stream.
+    Right,
+}
+
+impl PollNext {
+    /// Switches to the other stream, returning the previous value.
+    ///
+    /// Using this as the strategy, with a `PollNext` as its state, polls the
+    /// two streams in a round-robin fashion.
+    pub fn toggle(&mut self) -> PollNext {
+        let old = *self;
+        *self = match old {
+            PollNext::Left => PollNext::Right,
+            PollNext::Right => PollNext::Left,
+        };
+        old
+    }
+}
+
+impl Default for PollNext {
+    fn default() -> PollNext {
+        PollNext::Left
+    }
+}
+
+/// Stream for the [`select_with_strategy`] function.
+#[must_use = "streams do nothing unless polled"]
+pub struct SelectWithStrategy<St1, St2, Clos, State> {
+    stream1: Fuse<St1>,
+    stream2: Fuse<St2>,
+    state: State,
+    clos: Clos,
+}
+
+impl<St1: Unpin, St2: Unpin, Clos, State> Unpin
+    for SelectWithStrategy<St1, St2, Clos, State> {}
+
+impl<St1, St2, Clos, State> fmt::Debug for SelectWithStrategy<St1, St2, Clos, State>
+    where St1: fmt::Debug,
+          St2: fmt::Debug,
+          State: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SelectWithStrategy")
+            .field("stream1", &self.stream1)
+            .field("stream2", &self.stream2)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+/// Merges two streams, using a custom strategy to decide which stream to
+/// poll first.
+///
+/// Each time the returned stream is polled, `which_first` is called with a
+/// mutable reference to `State` and chooses the stream to poll first. If that
+/// stream doesn't have an item ready, the other stream is polled as well, so
+/// neither stream can stall the other. The state starts out as
+/// `State::default()` and is kept by the returned stream, which makes it
+/// possible to implement strategies such as weighted or token-bucket based
+/// priorities.
+///
+/// After one of the two streams has ended, the remaining one is polled
+/// exclusively. The returned stream ends once both streams have ended.
+///
+/// # Examples
+///
+/// Always draining a control stream before a data stream:
+///
+/// ```
+/// #![feature(async_await, await_macro)]
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, PollNext, StreamExt};
+///
+/// let control = stream::iter(vec!["stop", "start"]);
+/// let data = stream::iter(vec!["a", "b"]);
+///
+/// let stream = stream::select_with_strategy(control, data, |_: &mut ()| PollNext::Left);
+///
+/// assert_eq!(await!(stream.collect::<Vec<_>>()), vec!["stop", "start", "a", "b"]);
+/// # })
+/// ```
+///
+/// Alternating between the two streams, like
+/// [`StreamExt::select`](crate::stream::StreamExt::select):
+///
+/// ```
+/// #![feature(async_await, await_macro)]
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, PollNext, StreamExt};
+///
+/// let left = stream::repeat(1).take(3);
+/// let right = stream::repeat(2).take(2);
+///
+/// let stream = stream::select_with_strategy(left, right, PollNext::toggle);
+///
+/// assert_eq!(await!(stream.collect::<Vec<_>>()), vec![1, 2, 1, 2, 1]);
+/// # })
+/// ```
+pub fn select_with_strategy<St1, St2, Clos, State>(
+    stream1: St1,
+    stream2: St2,
+    which_first: Clos,
+) -> SelectWithStrategy<St1, St2, Clos, State>
+    where St1: Stream,
+          St2: Stream<Item = St1::Item>,
+          Clos: FnMut(&mut State) -> PollNext,
+          State: Default,
+{
+    SelectWithStrategy {
+        stream1: stream1.fuse(),
+        stream2: stream2.fuse(),
+        state: State::default(),
+        clos: which_first,
+    }
+}
+
+impl<St1, St2, Clos, State> SelectWithStrategy<St1, St2, Clos, State> {
+    /// Acquires a reference to the state of the strategy.
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Acquires a mutable reference to the state of the strategy.
+    pub fn state_mut(&mut self) -> &mut State {
+        &mut self.state
+    }
+
+    /// Acquires a reference to the underlying streams that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> (&St1, &St2) {
+        (self.stream1.get_ref(), self.stream2.get_ref())
+    }
+
+    /// Acquires a mutable reference to the underlying streams that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> (&mut St1, &mut St2) {
+        (self.stream1.get_mut(), self.stream2.get_mut())
+    }
+
+    /// Consumes this combinator, returning the underlying streams.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> (St1, St2) {
+        (self.stream1.into_inner(), self.stream2.into_inner())
+    }
+}
+
+impl<St1, St2, Clos, State> Stream for SelectWithStrategy<St1, St2, Clos, State>
+    where St1: Stream,
+          St2: Stream<Item = St1::Item>,
+          Clos: FnMut(&mut State) -> PollNext,
+{
+    type Item = St1::Item;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        lw: &LocalWaker
+    ) -> Poll<Option<St1::Item>> {
+        let SelectWithStrategy { stream1, stream2, state, clos } =
+            unsafe { Pin::get_mut_unchecked(self) };
+        let stream1 = unsafe { Pin::new_unchecked(stream1) };
+        let stream2 = unsafe { Pin::new_unchecked(stream2) };
+
+        match clos(state) {
+            PollNext::Left => poll_inner(stream1, stream2, lw),
+            PollNext::Right => poll_inner(stream2, stream1, lw),
+        }
+    }
+}
+
+fn poll_inner<St1, St2>(
+    a: Pin<&mut St1>,
+    b: Pin<&mut St2>,
+    lw: &LocalWaker
+) -> Poll<Option<St1::Item>>
+    where St1: Stream, St2: Stream<Item = St1::Item>
+{
+    let a_done = match a.poll_next(lw) {
+        Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+        Poll::Ready(None) => true,
+        Poll::Pending => false,
+    };
+
+    match b.poll_next(lw) {
+        Poll::Ready(Some(item)) => Poll::Ready(Some(item)),
+        Poll::Ready(None) if a_done => Poll::Ready(None),
+        Poll::Ready(None) | Poll::Pending => Poll::Pending,
+    }
+}
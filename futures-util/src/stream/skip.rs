@@ -67,6 +67,18 @@ impl<St: Stream> Stream for Skip<St> {
 
         self.stream().poll_next(lw)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        let remaining = if self.remaining > usize::max_value() as u64 {
+            usize::max_value()
+        } else {
+            self.remaining as usize
+        };
+        let lower = lower.saturating_sub(remaining);
+        let upper = upper.map(|x| x.saturating_sub(remaining));
+        (lower, upper)
+    }
 }
 
 /* TODO
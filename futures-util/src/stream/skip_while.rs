@@ -102,6 +102,21 @@ impl<St, Fut, F> Stream for SkipWhile<St, Fut, F>
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending_len = if self.pending_item.is_some() { 1 } else { 0 };
+        let (lower, upper) = self.stream.size_hint();
+        let lower = if self.done_skipping {
+            lower.saturating_add(pending_len)
+        } else {
+            0 // can't know a lower bound while still skipping
+        };
+        let upper = match upper {
+            Some(x) => x.checked_add(pending_len),
+            None => None,
+        };
+        (lower, upper)
+    }
 }
 
 /* TODO
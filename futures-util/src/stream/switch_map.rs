@@ -0,0 +1,125 @@
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A stream combinator which runs a future for the most recent item of a
+/// stream, dropping the future of the previous item.
+///
+/// This structure is produced by the `Stream::switch_map` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct SwitchMap<St, Fut, F> {
+    stream: St,
+    future: Option<Fut>,
+    f: F,
+    done: bool,
+}
+
+impl<St: Unpin, Fut: Unpin, F> Unpin for SwitchMap<St, Fut, F> {}
+
+impl<St, Fut, F> SwitchMap<St, Fut, F>
+    where St: Stream,
+          F: FnMut(St::Item) -> Fut,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_pinned!(future: Option<Fut>);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, f: F) -> SwitchMap<St, Fut, F> {
+        SwitchMap {
+            stream,
+            future: None,
+            f,
+            done: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, Fut, F> Stream for SwitchMap<St, Fut, F>
+    where St: Stream,
+          F: FnMut(St::Item) -> Fut,
+          Fut: Future,
+{
+    type Item = Fut::Output;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<Fut::Output>> {
+        // Newer items take precedence, so the stream is polled before the
+        // future, even if the future might be ready already
+        while !self.done {
+            match self.stream().poll_next(lw) {
+                Poll::Ready(Some(item)) => {
+                    let fut = (self.f())(item);
+                    Pin::set(self.future(), Some(fut));
+                }
+                Poll::Ready(None) => *self.done() = true,
+                Poll::Pending => break,
+            }
+        }
+
+        let output = match self.future().as_pin_mut() {
+            Some(fut) => ready!(fut.poll(lw)),
+            None if self.done => return Poll::Ready(None),
+            None => return Poll::Pending,
+        };
+        Pin::set(self.future(), None);
+        Poll::Ready(Some(output))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let future_len = if self.future.is_some() { 1 } else { 0 };
+        let (lower, upper) = if self.done {
+            (0, Some(0))
+        } else {
+            self.stream.size_hint()
+        };
+        // Any item may replace the running future
+        let lower = if lower > 0 || future_len > 0 { 1 } else { 0 };
+        let upper = match upper {
+            Some(x) => x.checked_add(future_len),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+/* TODO
+// Forwarding impl of Sink from the underlying stream
+impl<S, U, F> Sink for SwitchMap<S, U, F>
+    where S: Sink + Stream,
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream);
+}
+*/
@@ -0,0 +1,167 @@
+use core::fmt;
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`take_until`](super::StreamExt::take_until) combinator.
+#[must_use = "streams do nothing unless polled"]
+pub struct TakeUntil<St, Fut: Future> {
+    stream: Option<St>,
+    fut: Option<Fut>,
+    fut_result: Option<Fut::Output>,
+    done: bool,
+}
+
+impl<St: Unpin, Fut: Future + Unpin> Unpin for TakeUntil<St, Fut> {}
+
+impl<St, Fut> fmt::Debug for TakeUntil<St, Fut>
+    where St: fmt::Debug,
+          Fut: Future + fmt::Debug,
+          Fut::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TakeUntil")
+            .field("stream", &self.stream)
+            .field("fut", &self.fut)
+            .field("fut_result", &self.fut_result)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<St, Fut> TakeUntil<St, Fut>
+    where St: Stream,
+          Fut: Future,
+{
+    unsafe_pinned!(stream: Option<St>);
+    unsafe_pinned!(fut: Option<Fut>);
+    unsafe_unpinned!(fut_result: Option<Fut::Output>);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, fut: Fut) -> TakeUntil<St, Fut> {
+        TakeUntil {
+            stream: Some(stream),
+            fut: Some(fut),
+            fut_result: None,
+            done: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    ///
+    /// This method returns an `Option` because the underlying stream is
+    /// dropped as soon as the stop future has resolved.
+    pub fn get_ref(&self) -> Option<&St> {
+        self.stream.as_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    ///
+    /// This method returns an `Option` because the underlying stream is
+    /// dropped as soon as the stop future has resolved.
+    pub fn get_mut(&mut self) -> Option<&mut St> {
+        self.stream.as_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    ///
+    /// This method returns an `Option` because the underlying stream is
+    /// dropped as soon as the stop future has resolved.
+    pub fn into_inner(self) -> Option<St> {
+        self.stream
+    }
+
+    /// Whether the stop future has resolved.
+    pub fn is_stopped(&self) -> bool {
+        self.fut.is_none()
+    }
+
+    /// Takes the output of the stop future, if it has resolved.
+    ///
+    /// This returns `None` if the stop future hasn't resolved yet or if its
+    /// output has already been taken.
+    pub fn take_result(&mut self) -> Option<Fut::Output> {
+        self.fut_result.take()
+    }
+
+    // Polls the stop future, dropping it and the stream as soon as it has
+    // resolved
+    fn poll_stop(mut self: Pin<&mut Self>, lw: &LocalWaker) {
+        let poll = match self.fut().as_pin_mut() {
+            Some(fut) => fut.poll(lw),
+            None => return,
+        };
+        if let Poll::Ready(output) = poll {
+            *self.fut_result() = Some(output);
+            Pin::set(self.as_mut().fut(), None);
+            Pin::set(self.stream(), None);
+        }
+    }
+}
+
+impl<St, Fut> Stream for TakeUntil<St, Fut>
+    where St: Stream,
+          Fut: Future,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<St::Item>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+
+        let poll = match self.as_mut().stream().as_pin_mut() {
+            Some(stream) => stream.poll_next(lw),
+            None => return Poll::Ready(None),
+        };
+        // The stop future is polled even if an item is ready, so that it
+        // can't be starved by a busy stream. An item produced by the same
+        // poll is still yielded, and the stream ends on the next call.
+        self.as_mut().poll_stop(lw);
+
+        match poll {
+            Poll::Ready(Some(item)) => Poll::Ready(Some(item)),
+            Poll::Ready(None) => {
+                *self.done() = true;
+                Poll::Ready(None)
+            }
+            Poll::Pending if self.fut.is_none() => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.stream {
+            // The stop future can end the stream at any time
+            Some(ref stream) if !self.done => (0, stream.size_hint().1),
+            _ => (0, Some(0)),
+        }
+    }
+}
+
+/* TODO
+// Forwarding impl of Sink from the underlying stream
+impl<S, Fut> Sink for TakeUntil<S, Fut>
+    where S: Sink + Stream,
+          Fut: Future,
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream);
+}
+*/
@@ -0,0 +1,135 @@
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A stream which waits for a cooldown timer after each item before yielding
+/// the next one.
+///
+/// This stream is returned by the `Stream::throttle` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Throttle<St, F, T> {
+    stream: St,
+    make_timer: F,
+    timer: Option<T>,
+    // Items that may currently be yielded without waiting, at most `burst`
+    tokens: usize,
+    burst: usize,
+}
+
+impl<St: Unpin, F, T: Unpin> Unpin for Throttle<St, F, T> {}
+
+impl<St, F, T> Throttle<St, F, T>
+    where St: Stream,
+          F: FnMut() -> T,
+          T: Future<Output = ()>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(make_timer: F);
+    unsafe_pinned!(timer: Option<T>);
+    unsafe_unpinned!(tokens: usize);
+
+    pub(super) fn new(stream: St, make_timer: F) -> Throttle<St, F, T> {
+        Throttle { stream, make_timer, timer: None, tokens: 1, burst: 1 }
+    }
+
+    /// Allow up to `burst` items to be yielded back to back.
+    ///
+    /// Every item uses up one of `burst` slots, and each slot that's used up
+    /// is freed again by a timer, one timer at a time. After an idle period,
+    /// at most `burst` items therefore pass through without waiting. The
+    /// default burst of 1 waits for a timer between any two items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `burst` is 0.
+    pub fn burst(mut self, burst: usize) -> Self {
+        assert!(burst > 0, "burst must be greater than 0");
+        self.tokens = burst;
+        self.burst = burst;
+        self
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, F, T> Stream for Throttle<St, F, T>
+    where St: Stream,
+          F: FnMut() -> T,
+          T: Future<Output = ()>,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<St::Item>> {
+        // Free up slots for the timers that have fired, restarting the timer
+        // until all slots are free again
+        while let Some(timer) = self.timer().as_pin_mut() {
+            if timer.poll(lw).is_pending() {
+                break;
+            }
+            *self.tokens() += 1;
+            if self.tokens < self.burst {
+                let timer = (self.make_timer())();
+                Pin::set(self.timer(), Some(timer));
+            } else {
+                Pin::set(self.timer(), None);
+            }
+        }
+
+        // The running timer wakes us up once a slot is free
+        if self.tokens == 0 {
+            return Poll::Pending;
+        }
+
+        let item = ready!(self.stream().poll_next(lw));
+        if item.is_some() {
+            *self.tokens() -= 1;
+            // Start the cooldown right away so that time spent by the
+            // consumer counts towards it.
+            if self.timer.is_none() {
+                let timer = (self.make_timer())();
+                Pin::set(self.timer(), Some(timer));
+            }
+        }
+        Poll::Ready(item)
+    }
+}
+
+/* TODO
+// Forwarding impl of Sink from the underlying stream
+impl<St, F, T> Sink for Throttle<St, F, T>
+    where St: Sink + Stream
+{
+    type SinkItem = St::SinkItem;
+    type SinkError = St::SinkError;
+
+    delegate_sink!(stream);
+}
+*/
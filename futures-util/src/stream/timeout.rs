@@ -0,0 +1,123 @@
+use core::fmt;
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A stream which yields an error if the underlying stream doesn't produce an
+/// item in time.
+///
+/// This stream is returned by the `Stream::timeout` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Timeout<St, F, T> {
+    stream: St,
+    make_timer: F,
+    timer: Option<T>,
+    exempt_first: bool,
+}
+
+impl<St: Unpin, F, T: Unpin> Unpin for Timeout<St, F, T> {}
+
+impl<St, F, T> Timeout<St, F, T>
+    where St: Stream,
+          F: FnMut() -> T,
+          T: Future<Output = ()>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(make_timer: F);
+    unsafe_pinned!(timer: Option<T>);
+    unsafe_unpinned!(exempt_first: bool);
+
+    pub(super) fn new(stream: St, make_timer: F) -> Timeout<St, F, T> {
+        Timeout { stream, make_timer, timer: None, exempt_first: false }
+    }
+
+    /// Don't apply the timeout to the first item of the stream.
+    ///
+    /// This is useful for streams which may take a while to produce their
+    /// first item, e.g. because a connection has to be set up first, but
+    /// are expected to produce items at a steady pace afterwards.
+    pub fn exempt_first_item(mut self) -> Self {
+        self.exempt_first = true;
+        self
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, F, T> Stream for Timeout<St, F, T>
+    where St: Stream,
+          F: FnMut() -> T,
+          T: Future<Output = ()>,
+{
+    type Item = Result<St::Item, Elapsed>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<Self::Item>> {
+        match self.stream().poll_next(lw) {
+            Poll::Ready(Some(item)) => {
+                // The next item gets a fresh timer
+                *self.exempt_first() = false;
+                Pin::set(self.timer(), None);
+                return Poll::Ready(Some(Ok(item)));
+            }
+            Poll::Ready(None) => {
+                Pin::set(self.timer(), None);
+                return Poll::Ready(None);
+            }
+            Poll::Pending => {}
+        }
+
+        if *self.exempt_first() {
+            return Poll::Pending;
+        }
+
+        if self.timer().as_pin_mut().is_none() {
+            let timer = (self.make_timer())();
+            Pin::set(self.timer(), Some(timer));
+        }
+
+        ready!(self.timer().as_pin_mut().unwrap().poll(lw));
+        Pin::set(self.timer(), None);
+        Poll::Ready(Some(Err(Elapsed)))
+    }
+}
+
+/// Error returned when a timeout elapsed before a value was produced.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Elapsed;
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "deadline has elapsed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Elapsed {}
@@ -0,0 +1,61 @@
+use core::marker::Unpin;
+use core::mem;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A future which collects all of the pairs of a stream into two collections.
+///
+/// This future is created by the `Stream::unzip` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Unzip<St, FromA, FromB> {
+    stream: St,
+    left: FromA,
+    right: FromB,
+}
+
+impl<St: Unpin, FromA, FromB> Unpin for Unzip<St, FromA, FromB> {}
+
+impl<St: Stream, FromA: Default, FromB: Default> Unzip<St, FromA, FromB> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(left: FromA);
+    unsafe_unpinned!(right: FromB);
+
+    fn finish(mut self: Pin<&mut Self>) -> (FromA, FromB) {
+        (
+            mem::replace(self.left(), Default::default()),
+            mem::replace(self.right(), Default::default()),
+        )
+    }
+
+    pub(super) fn new(stream: St) -> Unzip<St, FromA, FromB> {
+        Unzip {
+            stream,
+            left: Default::default(),
+            right: Default::default(),
+        }
+    }
+}
+
+impl<St, A, B, FromA, FromB> Future for Unzip<St, FromA, FromB>
+where St: Stream<Item = (A, B)>,
+      FromA: Default + Extend<A>,
+      FromB: Default + Extend<B>,
+{
+    type Output = (FromA, FromB);
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<(FromA, FromB)> {
+        loop {
+            match ready!(self.stream().poll_next(lw)) {
+                Some((a, b)) => {
+                    self.left().extend(Some(a));
+                    self.right().extend(Some(b));
+                }
+                None => return Poll::Ready(self.finish()),
+            }
+        }
+    }
+}
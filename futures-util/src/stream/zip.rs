@@ -1,4 +1,5 @@
 use crate::stream::{StreamExt, Fuse};
+use core::cmp;
 use core::marker::Unpin;
 use core::pin::Pin;
 use futures_core::stream::Stream;
@@ -46,16 +47,30 @@ impl<St1, St2> Stream for Zip<St1, St2>
         mut self: Pin<&mut Self>,
         lw: &LocalWaker
     ) -> Poll<Option<Self::Item>> {
+        // Once either stream has ended, the other one isn't polled again
+        if self.stream1.is_done() || self.stream2.is_done() {
+            return Poll::Ready(None);
+        }
+
         if self.queued1().is_none() {
             match self.stream1().poll_next(lw) {
                 Poll::Ready(Some(item1)) => *self.queued1() = Some(item1),
-                Poll::Ready(None) | Poll::Pending => {}
+                Poll::Ready(None) => {
+                    // The queued item would never be paired
+                    *self.queued2() = None;
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => {}
             }
         }
         if self.queued2().is_none() {
             match self.stream2().poll_next(lw) {
                 Poll::Ready(Some(item2)) => *self.queued2() = Some(item2),
-                Poll::Ready(None) | Poll::Pending => {}
+                Poll::Ready(None) => {
+                    *self.queued1() = None;
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => {}
             }
         }
 
@@ -63,10 +78,30 @@ impl<St1, St2> Stream for Zip<St1, St2>
             let pair = (self.queued1().take().unwrap(),
                         self.queued2().take().unwrap());
             Poll::Ready(Some(pair))
-        } else if self.stream1().is_done() || self.stream2().is_done() {
-            Poll::Ready(None)
         } else {
             Poll::Pending
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queued1_len = if self.queued1.is_some() { 1 } else { 0 };
+        let queued2_len = if self.queued2.is_some() { 1 } else { 0 };
+        let (stream1_lower, stream1_upper) = self.stream1.size_hint();
+        let (stream2_lower, stream2_upper) = self.stream2.size_hint();
+
+        let stream1_lower = stream1_lower.saturating_add(queued1_len);
+        let stream2_lower = stream2_lower.saturating_add(queued2_len);
+        let lower = cmp::min(stream1_lower, stream2_lower);
+
+        let stream1_upper = stream1_upper.and_then(|x| x.checked_add(queued1_len));
+        let stream2_upper = stream2_upper.and_then(|x| x.checked_add(queued2_len));
+        let upper = match (stream1_upper, stream2_upper) {
+            (Some(x), Some(y)) => Some(cmp::min(x, y)),
+            (Some(x), None) => Some(x),
+            (None, Some(y)) => Some(y),
+            (None, None) => None,
+        };
+
+        (lower, upper)
+    }
 }
@@ -0,0 +1,113 @@
+use crate::stream::{StreamExt, Fuse};
+use core::cmp;
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// An item of a [`ZipLongest`] stream, which holds items of both streams
+/// while both of them have items left, and of only one of them once the other
+/// stream has ended.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EitherOrBoth<A, B> {
+    /// An item of each stream.
+    Both(A, B),
+    /// An item of the first stream, after the second stream has ended.
+    Left(A),
+    /// An item of the second stream, after the first stream has ended.
+    Right(B),
+}
+
+/// An adapter for pairing up the items of two streams until both of them
+/// have ended.
+///
+/// This stream is returned by the `Stream::zip_longest` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ZipLongest<St1: Stream, St2: Stream> {
+    stream1: Fuse<St1>,
+    stream2: Fuse<St2>,
+    queued1: Option<St1::Item>,
+    queued2: Option<St2::Item>,
+}
+
+impl<St1: Stream + Unpin, St2: Stream + Unpin> Unpin for ZipLongest<St1, St2> {}
+
+impl<St1: Stream, St2: Stream> ZipLongest<St1, St2> {
+    unsafe_pinned!(stream1: Fuse<St1>);
+    unsafe_pinned!(stream2: Fuse<St2>);
+    unsafe_unpinned!(queued1: Option<St1::Item>);
+    unsafe_unpinned!(queued2: Option<St2::Item>);
+
+    pub(super) fn new(stream1: St1, stream2: St2) -> ZipLongest<St1, St2> {
+        ZipLongest {
+            stream1: stream1.fuse(),
+            stream2: stream2.fuse(),
+            queued1: None,
+            queued2: None,
+        }
+    }
+}
+
+impl<St1, St2> Stream for ZipLongest<St1, St2>
+    where St1: Stream, St2: Stream
+{
+    type Item = EitherOrBoth<St1::Item, St2::Item>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker
+    ) -> Poll<Option<Self::Item>> {
+        if self.queued1.is_none() {
+            if let Poll::Ready(Some(item1)) = self.stream1().poll_next(lw) {
+                *self.queued1() = Some(item1);
+            }
+        }
+        if self.queued2.is_none() {
+            if let Poll::Ready(Some(item2)) = self.stream2().poll_next(lw) {
+                *self.queued2() = Some(item2);
+            }
+        }
+
+        // An item is only yielded on its own once the other stream has
+        // ended, not while the other stream is pending
+        let done1 = self.stream1.is_done();
+        let done2 = self.stream2.is_done();
+        match (self.queued1.is_some(), self.queued2.is_some()) {
+            (true, true) => {
+                let item1 = self.queued1().take().unwrap();
+                let item2 = self.queued2().take().unwrap();
+                Poll::Ready(Some(EitherOrBoth::Both(item1, item2)))
+            }
+            (true, false) if done2 => {
+                Poll::Ready(self.queued1().take().map(EitherOrBoth::Left))
+            }
+            (false, true) if done1 => {
+                Poll::Ready(self.queued2().take().map(EitherOrBoth::Right))
+            }
+            (false, false) if done1 && done2 => Poll::Ready(None),
+            _ => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queued1_len = if self.queued1.is_some() { 1 } else { 0 };
+        let queued2_len = if self.queued2.is_some() { 1 } else { 0 };
+        let (stream1_lower, stream1_upper) = self.stream1.size_hint();
+        let (stream2_lower, stream2_upper) = self.stream2.size_hint();
+
+        let stream1_lower = stream1_lower.saturating_add(queued1_len);
+        let stream2_lower = stream2_lower.saturating_add(queued2_len);
+        let lower = cmp::max(stream1_lower, stream2_lower);
+
+        let stream1_upper = stream1_upper.and_then(|x| x.checked_add(queued1_len));
+        let stream2_upper = stream2_upper.and_then(|x| x.checked_add(queued2_len));
+        let upper = match (stream1_upper, stream2_upper) {
+            (Some(x), Some(y)) => Some(cmp::max(x, y)),
+            _ => None,
+        };
+
+        (lower, upper)
+    }
+}
@@ -1,5 +1,8 @@
 //! Task notification
 
+mod noop_waker;
+pub(crate) use self::noop_waker::noop_local_waker;
+
 mod spawn;
 pub use self::spawn::{SpawnExt, LocalSpawnExt};
 
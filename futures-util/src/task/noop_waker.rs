@@ -0,0 +1,29 @@
+use core::ptr::NonNull;
+use futures_core::task::{LocalWaker, UnsafeWake, Waker};
+
+// A waker which does nothing when woken. It has no state, so a single
+// instance is shared by all of its handles.
+struct NoopWake;
+
+unsafe impl UnsafeWake for NoopWake {
+    unsafe fn clone_raw(&self) -> Waker {
+        Waker::new(noop_unsafe_wake())
+    }
+
+    unsafe fn drop_raw(&self) {}
+
+    unsafe fn wake(&self) {}
+}
+
+fn noop_unsafe_wake() -> NonNull<dyn UnsafeWake> {
+    static INSTANCE: NoopWake = NoopWake;
+    NonNull::from(&INSTANCE as &dyn UnsafeWake)
+}
+
+/// Creates a `LocalWaker` which does nothing when woken.
+///
+/// Cloning it and dropping the clones doesn't allocate, so a future which
+/// registers it somewhere doesn't keep anything alive after being dropped.
+pub(crate) fn noop_local_waker() -> LocalWaker {
+    unsafe { LocalWaker::new(noop_unsafe_wake()) }
+}
@@ -1,9 +1,13 @@
 use futures_core::task::{LocalSpawn, Spawn};
 
 if_std! {
-    use crate::future::{FutureExt, RemoteHandle};
+    use crate::future::{self, FutureExt, RemoteHandle};
+    use crate::stream::StreamExt;
     use futures_core::future::{Future, FutureObj, LocalFutureObj};
+    use futures_core::stream::Stream;
     use futures_core::task::SpawnError;
+    use std::panic::AssertUnwindSafe;
+    use std::thread;
 }
 
 impl<Sp: ?Sized> SpawnExt for Sp where Sp: Spawn {}
@@ -21,6 +25,15 @@ pub trait SpawnExt: Spawn {
     /// you want to spawn a future with output other than `()` or if you want
     /// to be able to await its completion.
     ///
+    /// This method always boxes `future`. If you already have a
+    /// `Box<F>` or `Pin<Box<F>>` of a concrete future type `F`, convert it
+    /// with `FutureObj::from` and pass it to
+    /// [`spawn_obj`](futures_core::task::Spawn::spawn_obj) to reuse the
+    /// existing allocation. Boxed trait objects like
+    /// `Pin<Box<dyn Future<Output = ()> + Send>>` can't be reused that way,
+    /// because [`FutureObj`] stores a thin pointer, so they are boxed again
+    /// either way.
+    ///
     /// Note this method will eventually be replaced with the upcoming
     /// `Spawn::spawn` method which will take a `dyn Future` as input.
     /// Technical limitations prevent `Spawn::spawn` from being implemented
@@ -51,6 +64,15 @@ pub trait SpawnExt: Spawn {
     /// spawning fails, a [`SpawnError`]. [`RemoteHandle`] is a future that
     /// resolves to the output of the spawned future.
     ///
+    /// The spawned future is bound to the handle: dropping the handle
+    /// cancels it, which makes this suitable for tasks that must not outlive
+    /// their parent. Cancellation can't interrupt a call to `poll` that's
+    /// already in progress on another thread; that call runs to its end and
+    /// the future is dropped instead of being polled again. Any side effects
+    /// of the polls made so far remain observable, but the output is never
+    /// delivered. Use [`RemoteHandle::forget`] to let the future keep running
+    /// without the handle.
+    ///
     /// ```
     /// #![feature(async_await, await_macro, futures_api)]
     /// use futures::executor::ThreadPool;
@@ -76,6 +98,138 @@ pub trait SpawnExt: Spawn {
         self.spawn(future)?;
         Ok(handle)
     }
+
+    /// Spawns a task that polls the given future to completion and returns a
+    /// future that resolves to the spawned future's output, or to the panic
+    /// payload if it panicked.
+    ///
+    /// The [`RemoteHandle`] returned by
+    /// [`spawn_with_handle`](SpawnExt::spawn_with_handle) resumes panics of
+    /// the spawned future on the task awaiting it. The handle returned by
+    /// this method resolves to an `Err` containing the panic payload instead,
+    /// which allows supervising code to react to the panic, e.g. by spawning
+    /// the task again.
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::executor::ThreadPool;
+    /// use futures::future;
+    /// use futures::task::SpawnExt;
+    ///
+    /// let mut executor = ThreadPool::new().unwrap();
+    ///
+    /// let future = future::lazy(|_| -> i32 { panic!("oh no") });
+    /// let join_handle_fut = executor.spawn_with_handle_catching(future).unwrap();
+    /// assert!(executor.run(join_handle_fut).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    fn spawn_with_handle_catching<Fut>(
+        &mut self,
+        future: Fut
+    ) -> Result<RemoteHandle<thread::Result<Fut::Output>>, SpawnError>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send,
+    {
+        self.spawn_with_handle(AssertUnwindSafe(future).catch_unwind())
+    }
+
+    /// Runs the blocking closure `f` on a dedicated thread and returns a
+    /// future that resolves to its result.
+    ///
+    /// This is meant for synchronous work, such as file IO or heavy
+    /// computations, which would otherwise block a thread of the executor
+    /// and keep it from polling other tasks. A new thread is started for
+    /// every call, outside of any thread pool, so the executor's pool doesn't
+    /// need to be sized with blocking work in mind. There's no limit on the
+    /// number of these threads: either limit how many calls are in flight,
+    /// e.g. with [`buffer_unordered`](crate::stream::StreamExt::buffer_unordered),
+    /// or use an executor that runs blocking work on a bounded pool of its
+    /// own, such as `ThreadPool::spawn_blocking` from `futures-executor`,
+    /// which takes precedence over this method when called on a `ThreadPool`.
+    ///
+    /// An error is returned if the executor has shut down or if the thread
+    /// couldn't be started, in which case `f` isn't run. Otherwise `f` always
+    /// runs to completion: dropping the
+    /// returned [`RemoteHandle`] detaches it and discards its result. A
+    /// panic in `f` is propagated to the task awaiting the handle.
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::executor::ThreadPool;
+    /// use futures::task::SpawnExt;
+    ///
+    /// let mut executor = ThreadPool::new().unwrap();
+    ///
+    /// let handle = executor.spawn_blocking(|| (1..=10).sum::<u32>()).unwrap();
+    /// assert_eq!(executor.run(handle), 55);
+    /// ```
+    #[cfg(feature = "std")]
+    fn spawn_blocking<F, R>(&mut self, f: F) -> Result<RemoteHandle<R>, SpawnError>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.status()?;
+        let (job, handle) = RemoteHandle::blocking(f);
+        thread::Builder::new().spawn(job).map_err(|_| SpawnError::shutdown())?;
+        Ok(handle)
+    }
+
+    /// Spawns a task that drives the given stream with item type `()` to
+    /// completion.
+    ///
+    /// This is a shorthand for spawning a future which pulls every item out
+    /// of the stream and discards it.
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::executor::ThreadPool;
+    /// use futures::stream::{self, StreamExt};
+    /// use futures::task::SpawnExt;
+    ///
+    /// let mut executor = ThreadPool::new().unwrap();
+    ///
+    /// let stream = stream::iter(0..3).map(|x| println!("{}", x));
+    /// executor.spawn_stream(stream).unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    fn spawn_stream<St>(&mut self, stream: St) -> Result<(), SpawnError>
+    where
+        St: Stream<Item = ()> + Send + 'static,
+    {
+        self.spawn(stream.for_each(|()| future::ready(())))
+    }
+
+    /// Spawns a task that drives the given stream with item type `()` to
+    /// completion and returns a future that resolves once the stream has
+    /// ended.
+    ///
+    /// Dropping the returned [`RemoteHandle`] drops the stream without
+    /// driving it any further.
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::executor::ThreadPool;
+    /// use futures::stream::{self, StreamExt};
+    /// use futures::task::SpawnExt;
+    ///
+    /// let mut executor = ThreadPool::new().unwrap();
+    ///
+    /// let stream = stream::iter(0..3).map(|x| println!("{}", x));
+    /// let join_handle_fut = executor.spawn_stream_with_handle(stream).unwrap();
+    /// executor.run(join_handle_fut);
+    /// ```
+    #[cfg(feature = "std")]
+    fn spawn_stream_with_handle<St>(
+        &mut self,
+        stream: St
+    ) -> Result<RemoteHandle<()>, SpawnError>
+    where
+        St: Stream<Item = ()> + Send + 'static,
+    {
+        self.spawn_with_handle(stream.for_each(|()| future::ready(())))
+    }
 }
 
 /// Extension trait for `LocalSpawn`.
@@ -146,4 +300,100 @@ pub trait LocalSpawnExt: LocalSpawn {
         self.spawn_local(future)?;
         Ok(handle)
     }
+
+    /// Spawns a task that runs the blocking closure `f` and returns a future
+    /// that resolves to its result.
+    ///
+    /// This is the counterpart of
+    /// [`SpawnExt::spawn_blocking`](SpawnExt::spawn_blocking), so that generic
+    /// code can offload blocking work regardless of the kind of executor it's
+    /// given. However, a single-threaded executor has no other thread to run
+    /// `f` on, so `f` runs inline when the task is first polled and **blocks
+    /// the whole executor** until it returns. No other task makes progress in
+    /// the meantime, so this is only suitable for short blocking calls.
+    ///
+    /// As with [`spawn_local_with_handle`](LocalSpawnExt::spawn_local_with_handle),
+    /// dropping the returned [`RemoteHandle`] before the task has run
+    /// cancels it, in which case `f` isn't run at all.
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::executor::LocalPool;
+    /// use futures::task::LocalSpawnExt;
+    ///
+    /// let mut executor = LocalPool::new();
+    /// let mut spawner = executor.spawner();
+    ///
+    /// let handle = spawner.spawn_local_blocking(|| (1..=10).sum::<u32>()).unwrap();
+    /// assert_eq!(executor.run_until(handle), 55);
+    /// ```
+    #[cfg(feature = "std")]
+    fn spawn_local_blocking<F, R>(&mut self, f: F) -> Result<RemoteHandle<R>, SpawnError>
+    where
+        F: FnOnce() -> R + 'static,
+        R: 'static,
+    {
+        self.spawn_local_with_handle(future::lazy(move |_| f()))
+    }
+
+    /// Spawns a task that drives the given stream with item type `()` to
+    /// completion.
+    ///
+    /// This is the counterpart of
+    /// [`SpawnExt::spawn_stream`](SpawnExt::spawn_stream) for streams
+    /// that aren't `Send`.
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::executor::LocalPool;
+    /// use futures::stream::{self, StreamExt};
+    /// use futures::task::LocalSpawnExt;
+    /// use std::rc::Rc;
+    ///
+    /// let mut executor = LocalPool::new();
+    /// let mut spawner = executor.spawner();
+    ///
+    /// let prefix = Rc::new("item");
+    /// let stream = stream::iter(0..3).map(move |x| println!("{} {}", prefix, x));
+    /// spawner.spawn_local_stream(stream).unwrap();
+    /// executor.run();
+    /// ```
+    #[cfg(feature = "std")]
+    fn spawn_local_stream<St>(&mut self, stream: St) -> Result<(), SpawnError>
+    where
+        St: Stream<Item = ()> + 'static,
+    {
+        self.spawn_local(stream.for_each(|()| future::ready(())))
+    }
+
+    /// Spawns a task that drives the given stream with item type `()` to
+    /// completion and returns a future that resolves once the stream has
+    /// ended.
+    ///
+    /// Dropping the returned [`RemoteHandle`] drops the stream without
+    /// driving it any further.
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::executor::LocalPool;
+    /// use futures::stream::{self, StreamExt};
+    /// use futures::task::LocalSpawnExt;
+    ///
+    /// let mut executor = LocalPool::new();
+    /// let mut spawner = executor.spawner();
+    ///
+    /// let stream = stream::iter(0..3).map(|x| println!("{}", x));
+    /// let join_handle_fut = spawner.spawn_local_stream_with_handle(stream).unwrap();
+    /// executor.run_until(join_handle_fut);
+    /// ```
+    #[cfg(feature = "std")]
+    fn spawn_local_stream_with_handle<St>(
+        &mut self,
+        stream: St
+    ) -> Result<RemoteHandle<()>, SpawnError>
+    where
+        St: Stream<Item = ()> + 'static,
+    {
+        self.spawn_local_with_handle(stream.for_each(|()| future::ready(())))
+    }
 }
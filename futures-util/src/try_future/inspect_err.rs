@@ -0,0 +1,49 @@
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::{Future, TryFuture};
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`inspect_err`](super::TryFutureExt::inspect_err) combinator.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct InspectErr<Fut, F> {
+    future: Fut,
+    f: Option<F>,
+}
+
+impl<Fut, F> InspectErr<Fut, F> {
+    unsafe_pinned!(future: Fut);
+    unsafe_unpinned!(f: Option<F>);
+
+    /// Creates a new InspectErr.
+    pub(super) fn new(future: Fut, f: F) -> InspectErr<Fut, F> {
+        InspectErr { future, f: Some(f) }
+    }
+}
+
+impl<Fut: Unpin, F> Unpin for InspectErr<Fut, F> {}
+
+impl<Fut, F> Future for InspectErr<Fut, F>
+    where Fut: TryFuture,
+          F: FnOnce(&Fut::Error),
+{
+    type Output = Result<Fut::Ok, Fut::Error>;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Self::Output> {
+        match self.future().try_poll(lw) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(result) => {
+                let f = self.f().take()
+                    .expect("InspectErr must not be polled after it returned `Poll::Ready`");
+                if let Err(x) = &result {
+                    f(x);
+                }
+                Poll::Ready(result)
+            }
+        }
+    }
+}
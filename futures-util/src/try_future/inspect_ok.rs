@@ -0,0 +1,49 @@
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::{Future, TryFuture};
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`inspect_ok`](super::TryFutureExt::inspect_ok) combinator.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct InspectOk<Fut, F> {
+    future: Fut,
+    f: Option<F>,
+}
+
+impl<Fut, F> InspectOk<Fut, F> {
+    unsafe_pinned!(future: Fut);
+    unsafe_unpinned!(f: Option<F>);
+
+    /// Creates a new InspectOk.
+    pub(super) fn new(future: Fut, f: F) -> InspectOk<Fut, F> {
+        InspectOk { future, f: Some(f) }
+    }
+}
+
+impl<Fut: Unpin, F> Unpin for InspectOk<Fut, F> {}
+
+impl<Fut, F> Future for InspectOk<Fut, F>
+    where Fut: TryFuture,
+          F: FnOnce(&Fut::Ok),
+{
+    type Output = Result<Fut::Ok, Fut::Error>;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Self::Output> {
+        match self.future().try_poll(lw) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(result) => {
+                let f = self.f().take()
+                    .expect("InspectOk must not be polled after it returned `Poll::Ready`");
+                if let Ok(x) = &result {
+                    f(x);
+                }
+                Poll::Ready(result)
+            }
+        }
+    }
+}
@@ -16,10 +16,8 @@ pub use self::join::{Join, Join3, Join4, Join5};
 pub use self::select::Select;
 
 if_std! {
-mod join_all;
 mod select_all;
 mod select_ok;
-pub use self::join_all::{join_all, JoinAll};
 pub use self::select_all::{SelectAll, SelectAllNext, select_all};
 pub use self::select_ok::{SelectOk, select_ok};
 }
@@ -38,6 +36,12 @@ pub use self::flatten_sink::FlattenSink;
 mod try_join;
 pub use self::try_join::{TryJoin, TryJoin3, TryJoin4, TryJoin5};
 
+mod inspect_err;
+pub use self::inspect_err::InspectErr;
+
+mod inspect_ok;
+pub use self::inspect_ok::InspectOk;
+
 mod into_future;
 pub use self::into_future::IntoFuture;
 
@@ -53,6 +57,13 @@ pub use self::or_else::OrElse;
 mod unwrap_or_else;
 pub use self::unwrap_or_else::UnwrapOrElse;
 
+if_std! {
+    mod try_join_all;
+    pub use self::try_join_all::{
+        try_join_all, try_join_all_settled, TryJoinAll, TryJoinAllSettled,
+    };
+}
+
 // Implementation details
 mod try_chain;
 pub(crate) use self::try_chain::{TryChain, TryChainAction};
@@ -148,6 +159,32 @@ pub trait TryFutureExt: TryFuture {
         MapOk::new(self, f)
     }
 
+    /// Do something with the success value of this future before passing it
+    /// on.
+    ///
+    /// The closure is called with a reference to the value once the future
+    /// has completed successfully, and isn't called if it fails. The output
+    /// is passed on unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::future::{self, TryFutureExt};
+    ///
+    /// # futures::executor::block_on(async {
+    /// let future = future::ok::<i32, i32>(1);
+    /// let future = future.inspect_ok(|x| println!("resolved with {}", x));
+    /// assert_eq!(await!(future), Ok(1));
+    /// # });
+    /// ```
+    fn inspect_ok<F>(self, f: F) -> InspectOk<Self, F>
+        where F: FnOnce(&Self::Ok),
+              Self: Sized,
+    {
+        InspectOk::new(self, f)
+    }
+
     /// Maps this future's error value to a different value.
     ///
     /// This method can be used to change the [`Error`](TryFuture::Error) type
@@ -196,6 +233,32 @@ pub trait TryFutureExt: TryFuture {
         MapErr::new(self, f)
     }
 
+    /// Do something with the error value of this future before passing it
+    /// on.
+    ///
+    /// The closure is called with a reference to the error once the future
+    /// has failed, for example to log it, and isn't called if it succeeds.
+    /// The output is passed on unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::future::{self, TryFutureExt};
+    ///
+    /// # futures::executor::block_on(async {
+    /// let future = future::err::<i32, &str>("bad request");
+    /// let future = future.inspect_err(|e| eprintln!("request failed: {}", e));
+    /// assert_eq!(await!(future), Err("bad request"));
+    /// # });
+    /// ```
+    fn inspect_err<F>(self, f: F) -> InspectErr<Self, F>
+        where F: FnOnce(&Self::Error),
+              Self: Sized,
+    {
+        InspectErr::new(self, f)
+    }
+
     /// Maps this future's [`Error`](TryFuture::Error) to a new error type
     /// using the [`Into`](std::convert::Into) trait.
     ///
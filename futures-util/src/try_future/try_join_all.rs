@@ -0,0 +1,201 @@
+//! Definition of the `TryJoinAll` combinator, waiting for all of a list of
+//! futures to finish successfully, or for one of them to fail.
+
+use crate::stream::{FuturesUnordered, StreamExt};
+use futures_core::future::{Future, TryFuture};
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::unsafe_pinned;
+use std::fmt;
+use std::marker::Unpin;
+use std::iter::FromIterator;
+use std::mem;
+use std::pin::Pin;
+use std::prelude::v1::*;
+
+// A future that remembers its position in the list
+struct Indexed<Fut> {
+    index: usize,
+    future: Fut,
+}
+
+impl<Fut> Indexed<Fut> {
+    unsafe_pinned!(future: Fut);
+}
+
+impl<Fut: TryFuture> Future for Indexed<Fut> {
+    type Output = (usize, Result<Fut::Ok, Fut::Error>);
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Self::Output> {
+        let output = ready!(self.future().try_poll(lw));
+        Poll::Ready((self.index, output))
+    }
+}
+
+/// A future which takes a list of fallible futures and resolves with a vector
+/// of their outputs, or with the error of the first of them to fail, along
+/// with the outputs of the futures that completed before that.
+///
+/// This future is created with the `try_join_all_settled` function.
+#[must_use = "futures do nothing unless polled"]
+pub struct TryJoinAllSettled<Fut: TryFuture> {
+    in_progress: FuturesUnordered<Indexed<Fut>>,
+    outputs: Vec<Option<Fut::Ok>>,
+}
+
+// The futures are pinned by `FuturesUnordered`, and the outputs are never
+// pinned.
+impl<Fut: TryFuture> Unpin for TryJoinAllSettled<Fut> {}
+
+impl<Fut> fmt::Debug for TryJoinAllSettled<Fut>
+    where Fut: TryFuture,
+          Fut::Ok: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("TryJoinAllSettled")
+            .field("in_progress", &self.in_progress.len())
+            .field("outputs", &self.outputs)
+            .finish()
+    }
+}
+
+/// Creates a future which represents either a collection of the outputs of
+/// the futures given or the error of the first of them to fail, like
+/// [`try_join_all`], but reports which futures had already succeeded when
+/// one of them failed.
+///
+/// On failure, the future resolves to `Err((error, outputs))`, where
+/// `outputs` has an entry for every future given: `Some` with the output of
+/// a future that had completed successfully, and `None` for the others.
+/// This allows cleaning up resources created by the futures that succeeded.
+/// The futures that are still running are dropped at that point.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, try_join_all_settled};
+///
+/// let futures = vec![future::ok(1), future::err("failed"), future::ok(3)];
+///
+/// let (err, outputs) = await!(try_join_all_settled(futures)).unwrap_err();
+/// assert_eq!(err, "failed");
+/// assert_eq!(outputs.len(), 3);
+/// # })
+/// ```
+pub fn try_join_all_settled<I>(iter: I) -> TryJoinAllSettled<I::Item>
+    where I: IntoIterator,
+          I::Item: TryFuture,
+{
+    let in_progress: FuturesUnordered<_> = iter.into_iter()
+        .enumerate()
+        .map(|(index, future)| Indexed { index, future })
+        .collect();
+    let outputs = (0..in_progress.len()).map(|_| None).collect();
+
+    TryJoinAllSettled { in_progress, outputs }
+}
+
+impl<Fut: TryFuture> Future for TryJoinAllSettled<Fut> {
+    type Output = Result<Vec<Fut::Ok>, (Fut::Error, Vec<Option<Fut::Ok>>)>;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Self::Output> {
+        let this = &mut *self;
+
+        loop {
+            match ready!(this.in_progress.poll_next_unpin(lw)) {
+                Some((index, Ok(output))) => this.outputs[index] = Some(output),
+                Some((_, Err(e))) => {
+                    // Drop the futures that are still running right away
+                    this.in_progress = FuturesUnordered::new();
+                    let outputs = mem::replace(&mut this.outputs, Vec::new());
+                    return Poll::Ready(Err((e, outputs)));
+                }
+                None => {
+                    let outputs = mem::replace(&mut this.outputs, Vec::new());
+                    return Poll::Ready(Ok(
+                        outputs.into_iter().map(Option::unwrap).collect()
+                    ));
+                }
+            }
+        }
+    }
+}
+
+impl<Fut: TryFuture> FromIterator<Fut> for TryJoinAllSettled<Fut> {
+    fn from_iter<T: IntoIterator<Item = Fut>>(iter: T) -> Self {
+        try_join_all_settled(iter)
+    }
+}
+
+/// A future which takes a list of fallible futures and resolves with a vector
+/// of their outputs, or with the error of the first of them to fail.
+///
+/// This future is created with the `try_join_all` function.
+#[must_use = "futures do nothing unless polled"]
+pub struct TryJoinAll<Fut: TryFuture> {
+    inner: TryJoinAllSettled<Fut>,
+}
+
+impl<Fut> fmt::Debug for TryJoinAll<Fut>
+    where Fut: TryFuture,
+          Fut::Ok: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("TryJoinAll")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+/// Creates a future which represents either a collection of the outputs of
+/// the futures given or the error of the first of them to fail.
+///
+/// The returned future will drive execution for all of its underlying
+/// futures, collecting the outputs into a `Vec` in the same order as the
+/// futures were provided. As soon as one of the futures fails, the returned
+/// future resolves to its error and the futures that are still running are
+/// dropped, like with the `try_join!` macro.
+///
+/// Only the futures that have been woken are polled again, so this is
+/// suitable for large numbers of futures as well.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, try_join_all};
+///
+/// let futures = vec![future::ok::<u32, u32>(1), future::ok(2), future::ok(3)];
+/// assert_eq!(await!(try_join_all(futures)), Ok(vec![1, 2, 3]));
+///
+/// let futures = vec![future::ok::<u32, u32>(1), future::err(2), future::ok(3)];
+/// assert_eq!(await!(try_join_all(futures)), Err(2));
+/// # })
+/// ```
+pub fn try_join_all<I>(iter: I) -> TryJoinAll<I::Item>
+    where I: IntoIterator,
+          I::Item: TryFuture,
+{
+    TryJoinAll {
+        inner: try_join_all_settled(iter),
+    }
+}
+
+impl<Fut: TryFuture> Future for TryJoinAll<Fut> {
+    type Output = Result<Vec<Fut::Ok>, Fut::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Self::Output> {
+        match ready!(Pin::new(&mut self.inner).poll(lw)) {
+            Ok(outputs) => Poll::Ready(Ok(outputs)),
+            Err((e, _)) => Poll::Ready(Err(e)),
+        }
+    }
+}
+
+impl<Fut: TryFuture> FromIterator<Fut> for TryJoinAll<Fut> {
+    fn from_iter<T: IntoIterator<Item = Fut>>(iter: T) -> Self {
+        try_join_all(iter)
+    }
+}
@@ -0,0 +1,68 @@
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::stream::{Stream, TryStream};
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`inspect_err`](super::TryStreamExt::inspect_err)
+/// combinator.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct InspectErr<St, F> {
+    stream: St,
+    f: F,
+}
+
+impl<St, F> InspectErr<St, F> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+
+    /// Creates a new InspectErr.
+    pub(super) fn new(stream: St, f: F) -> Self {
+        InspectErr { stream, f }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St: Unpin, F> Unpin for InspectErr<St, F> {}
+
+impl<St, F> Stream for InspectErr<St, F>
+where
+    St: TryStream,
+    F: FnMut(&St::Error),
+{
+    type Item = Result<St::Ok, St::Error>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<Self::Item>> {
+        let item = ready!(self.stream().try_poll_next(lw));
+        if let Some(Err(e)) = &item {
+            (self.f())(e);
+        }
+        Poll::Ready(item)
+    }
+}
@@ -4,7 +4,7 @@
 //! that return `Result`s, allowing for short-circuiting computations.
 
 use core::marker::Unpin;
-use futures_core::future::TryFuture;
+use futures_core::future::{Future, TryFuture};
 use futures_core::stream::TryStream;
 
 #[cfg(feature = "compat")]
@@ -13,6 +13,9 @@ use crate::compat::Compat;
 mod err_into;
 pub use self::err_into::ErrInto;
 
+mod inspect_err;
+pub use self::inspect_err::InspectErr;
+
 mod into_stream;
 pub use self::into_stream::IntoStream;
 
@@ -31,22 +34,33 @@ pub use self::try_for_each::TryForEach;
 mod try_filter_map;
 pub use self::try_filter_map::TryFilterMap;
 
+mod try_count;
+pub use self::try_count::TryCount;
+
 mod try_fold;
 pub use self::try_fold::TryFold;
 
 mod try_skip_while;
 pub use self::try_skip_while::TrySkipWhile;
 
+mod try_merge;
+pub use self::try_merge::TryMerge;
+
+mod try_timeout;
+pub use self::try_timeout::TryTimeout;
+
+mod try_unfold;
+pub use self::try_unfold::{try_unfold, TryUnfold};
+
 if_std! {
     mod try_buffer_unordered;
-    pub use self::try_buffer_unordered::TryBufferUnordered;
+    pub use self::try_buffer_unordered::{TryBufferUnordered, ErrorStrategy};
 
     mod try_collect;
     pub use self::try_collect::TryCollect;
 
     mod try_for_each_concurrent;
     pub use self::try_for_each_concurrent::TryForEachConcurrent;
-    use futures_core::future::Future;
 }
 
 impl<S: TryStream> TryStreamExt for S {}
@@ -131,6 +145,41 @@ pub trait TryStreamExt: TryStream {
         MapErr::new(self, f)
     }
 
+    /// Do something with the error value of this stream, afterwards passing
+    /// it on.
+    ///
+    /// This is similar to the `StreamExt::inspect` method where it allows
+    /// easily inspecting the error value as it passes through the stream, for
+    /// example to log it. The closure is called once for every error, and
+    /// both successful values and errors are passed on unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let mut errors = Vec::new();
+    /// {
+    ///     let mut stream = stream::iter(vec![Ok(1), Err("bad"), Ok(2)])
+    ///         .inspect_err(|e| errors.push(*e));
+    ///
+    ///     assert_eq!(await!(stream.try_next()), Ok(Some(1)));
+    ///     assert_eq!(await!(stream.try_next()), Err("bad"));
+    ///     assert_eq!(await!(stream.try_next()), Ok(Some(2)));
+    /// }
+    /// assert_eq!(errors, vec!["bad"]);
+    /// # })
+    /// ```
+    fn inspect_err<F>(self, f: F) -> InspectErr<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Error),
+    {
+        InspectErr::new(self, f)
+    }
+
     /// Wraps a [`TryStream`] into a type that implements
     /// [`Stream`](futures_core::Stream)
     ///
@@ -256,6 +305,89 @@ pub trait TryStreamExt: TryStream {
         TrySkipWhile::new(self, f)
     }
 
+    /// Merges this stream with another one, alternating fairly between the
+    /// two and ending after the first error.
+    ///
+    /// Items are pulled in the same round-robin order as
+    /// [`StreamExt::merge`](crate::stream::StreamExt::merge). As soon as
+    /// either stream yields an error, that error is yielded and the merged
+    /// stream ends, without polling either stream again. Items that the
+    /// other stream would have produced afterwards are never pulled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt, TryStreamExt};
+    ///
+    /// let stream1 = stream::iter(vec![Ok(1), Err(2), Ok(3)]);
+    /// let stream2 = stream::iter(vec![Ok(4), Ok(5), Ok(6)]);
+    ///
+    /// let merged = stream1.try_merge(stream2);
+    /// assert_eq!(
+    ///     await!(merged.collect::<Vec<_>>()),
+    ///     vec![Ok(1), Ok(4), Err(2)],
+    /// );
+    /// # })
+    /// ```
+    fn try_merge<St>(self, other: St) -> TryMerge<Self, St>
+        where St: TryStream<Ok = Self::Ok, Error = Self::Error>,
+              Self: Sized
+    {
+        TryMerge::new(self, other)
+    }
+
+    /// Wraps the stream so that it yields an error if the next item doesn't
+    /// arrive in time.
+    ///
+    /// This behaves like [`StreamExt::timeout`](crate::stream::StreamExt::timeout),
+    /// including how the timer is created and reset after every item, but
+    /// reports elapsed timeouts through the stream's own error type instead
+    /// of wrapping every item in another `Result`. A timeout is yielded as
+    /// `Err(Elapsed.into())`, and the stream may still produce items
+    /// afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::future;
+    /// use futures::stream::{Elapsed, StreamExt, TryStreamExt};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     TimedOut,
+    /// }
+    ///
+    /// impl From<Elapsed> for Error {
+    ///     fn from(_: Elapsed) -> Error {
+    ///         Error::TimedOut
+    ///     }
+    /// }
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    /// tx.unbounded_send(Ok::<i32, Error>(1)).unwrap();
+    ///
+    /// // A timer that fires right away, for the sake of the example
+    /// let mut stream = rx.try_timeout(|| future::ready(()));
+    ///
+    /// assert_eq!(await!(stream.next()), Some(Ok(1)));
+    /// assert_eq!(await!(stream.next()), Some(Err(Error::TimedOut)));
+    /// # drop(tx);
+    /// # });
+    /// ```
+    fn try_timeout<F, T>(self, make_timer: F) -> TryTimeout<Self, F, T>
+        where Self::Error: From<crate::stream::Elapsed>,
+              F: FnMut() -> T,
+              T: Future<Output = ()>,
+              Self: Sized
+    {
+        TryTimeout::new(self, make_timer)
+    }
+
     /// Attempts to run this stream to completion, executing the provided asynchronous
     /// closure for each element on the stream concurrently as elements become
     /// available, exiting as soon as an error occurs.
@@ -408,6 +540,12 @@ pub trait TryStreamExt: TryStream {
     /// exit early if an error is encountered in either the stream or the
     /// provided closure.
     ///
+    /// The stream isn't lost when the fold fails: the returned future's
+    /// [`into_inner`](TryFold::into_inner) method gives it back, so that the
+    /// rest of the stream can still be consumed after handling the error. If
+    /// the error came from the stream itself, the future can also be polled
+    /// again to continue the fold with the accumulator it had so far.
+    ///
     /// # Examples
     ///
     /// ```
@@ -433,6 +571,33 @@ pub trait TryStreamExt: TryStream {
         TryFold::new(self, f, init)
     }
 
+    /// Attempt to drain the stream, returning a future which resolves to the
+    /// number of successful items it yielded.
+    ///
+    /// The items are dropped as soon as they have been counted. The first
+    /// error ends the count, and the returned future resolves to that error
+    /// without polling the stream any further.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2)]);
+    /// assert_eq!(await!(stream.try_count()), Ok(2));
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Err(2), Ok(3)]);
+    /// assert_eq!(await!(stream.try_count()), Err(2));
+    /// # })
+    /// ```
+    fn try_count(self) -> TryCount<Self>
+        where Self: Sized
+    {
+        TryCount::new(self)
+    }
+
     /// Attempt to execute several futures from a stream concurrently.
     ///
     /// This stream's `Ok` type must be a [`TryFuture`] with an `Error` type
@@ -493,6 +658,60 @@ pub trait TryStreamExt: TryStream {
     /// assert_eq!(await!(buffered.next()), Some(Err("error in the stream")));
     /// # })
     /// ```
+    ///
+    /// By default, the returned stream keeps pulling new futures after an
+    /// error. Use
+    /// [`TryBufferUnordered::error_strategy`](TryBufferUnordered::error_strategy)
+    /// to stop taking on new work after the first error instead:
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt, TryStreamExt, ErrorStrategy};
+    ///
+    /// let stream_of_futures = stream::iter(vec![
+    ///     Ok(future::ready(Ok(1i32))),
+    ///     Err("error in the stream"),
+    ///     Ok(future::ready(Ok(2i32))),
+    /// ]);
+    /// let mut buffered = stream_of_futures
+    ///     .try_buffer_unordered(10)
+    ///     .error_strategy(ErrorStrategy::DrainPending);
+    ///
+    /// assert_eq!(await!(buffered.next()), Some(Err("error in the stream")));
+    /// assert_eq!(await!(buffered.next()), Some(Ok(1i32)));
+    /// assert_eq!(await!(buffered.next()), None);
+    /// # })
+    /// ```
+    ///
+    /// A plain stream of fallible futures can be turned into a `TryStream`
+    /// with `map(Ok)`. Combined with
+    /// [`ErrorStrategy::DropPending`](ErrorStrategy::DropPending), the
+    /// returned stream ends with the first error and drops the futures that
+    /// are still running. Outputs are yielded as soon as their futures
+    /// complete, so every success that came before the error has already
+    /// been yielded by then:
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt, TryStreamExt, ErrorStrategy};
+    ///
+    /// let futures = vec![
+    ///     future::ready(Ok(1i32)),
+    ///     future::ready(Err("request failed")),
+    ///     future::ready(Ok(2i32)),
+    /// ];
+    /// let mut buffered = stream::iter(futures)
+    ///     .map(Ok)
+    ///     .try_buffer_unordered(1)
+    ///     .error_strategy(ErrorStrategy::DropPending);
+    ///
+    /// assert_eq!(await!(buffered.next()), Some(Ok(1i32)));
+    /// assert_eq!(await!(buffered.next()), Some(Err("request failed")));
+    /// assert_eq!(await!(buffered.next()), None);
+    /// # })
+    /// ```
     #[cfg(feature = "std")]
     fn try_buffer_unordered(self, n: usize) -> TryBufferUnordered<Self>
         where Self::Ok: TryFuture<Error = Self::Error>,
@@ -18,6 +18,35 @@ pub struct TryBufferUnordered<St>
     stream: Fuse<IntoStream<St>>,
     in_progress_queue: FuturesUnordered<IntoFuture<St::Ok>>,
     max: usize,
+    strategy: ErrorStrategy,
+    errored: bool,
+}
+
+/// What [`TryBufferUnordered`](TryBufferUnordered) does after it has yielded
+/// an error, set with
+/// [`TryBufferUnordered::error_strategy`](TryBufferUnordered::error_strategy).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ErrorStrategy {
+    /// Keep pulling new futures from the underlying stream as if nothing had
+    /// happened. This is the default.
+    Continue,
+    /// Stop pulling new futures and drop the futures that are still running.
+    /// The stream ends right after the error.
+    ///
+    /// Outputs aren't held back, so any success that completed before the
+    /// error has already been yielded. Futures that would have completed
+    /// later are dropped along with their outputs.
+    DropPending,
+    /// Stop pulling new futures but keep yielding the outputs of the futures
+    /// that are still running, including any further errors. The stream ends
+    /// once all of them have completed.
+    DrainPending,
+}
+
+impl Default for ErrorStrategy {
+    fn default() -> Self {
+        ErrorStrategy::Continue
+    }
 }
 
 impl<St> Unpin for TryBufferUnordered<St>
@@ -30,15 +59,27 @@ impl<St> TryBufferUnordered<St>
 {
     unsafe_pinned!(stream: Fuse<IntoStream<St>>);
     unsafe_unpinned!(in_progress_queue: FuturesUnordered<IntoFuture<St::Ok>>);
+    unsafe_unpinned!(errored: bool);
 
     pub(super) fn new(stream: St, n: usize) -> Self {
         TryBufferUnordered {
             stream: IntoStream::new(stream).fuse(),
             in_progress_queue: FuturesUnordered::new(),
             max: n,
+            strategy: ErrorStrategy::default(),
+            errored: false,
         }
     }
 
+    /// Sets what happens after the first error, whether it comes from the
+    /// underlying stream or from one of the buffered futures.
+    ///
+    /// See [`ErrorStrategy`](ErrorStrategy) for the available options.
+    pub fn error_strategy(mut self, strategy: ErrorStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
     /// Acquires a reference to the underlying stream that this combinator is
     /// pulling from.
     pub fn get_ref(&self) -> &St {
@@ -61,6 +102,17 @@ impl<St> TryBufferUnordered<St>
     pub fn into_inner(self) -> St {
         self.stream.into_inner().into_inner()
     }
+
+    fn on_error(mut self: Pin<&mut Self>) {
+        match self.strategy {
+            ErrorStrategy::Continue => {}
+            ErrorStrategy::DropPending => {
+                *self.errored() = true;
+                self.in_progress_queue().clear();
+            }
+            ErrorStrategy::DrainPending => *self.errored() = true,
+        }
+    }
 }
 
 impl<St> Stream for TryBufferUnordered<St>
@@ -75,22 +127,31 @@ impl<St> Stream for TryBufferUnordered<St>
     ) -> Poll<Option<Self::Item>> {
         // First up, try to spawn off as many futures as possible by filling up
         // our slab of futures. Propagate errors from the stream immediately.
-        while self.in_progress_queue.len() < self.max {
+        // After an error, the error strategy may prevent us from taking on
+        // any new work.
+        while !self.errored && self.in_progress_queue.len() < self.max {
             match self.stream().poll_next(lw) {
                 Poll::Ready(Some(Ok(fut))) => self.in_progress_queue().push(fut.into_future()),
-                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(Some(Err(e))) => {
+                    self.as_mut().on_error();
+                    return Poll::Ready(Some(Err(e)));
+                }
                 Poll::Ready(None) | Poll::Pending => break,
             }
         }
 
         // Attempt to pull the next value from the in_progress_queue
         match Pin::new(self.in_progress_queue()).poll_next(lw) {
+            Poll::Ready(Some(Err(e))) => {
+                self.as_mut().on_error();
+                return Poll::Ready(Some(Err(e)));
+            }
             x @ Poll::Pending | x @ Poll::Ready(Some(_)) => return x,
             Poll::Ready(None) => {}
         }
 
         // If more values are still coming from the stream, we're not done yet
-        if self.stream.is_done() {
+        if self.errored || self.stream.is_done() {
             Poll::Ready(None)
         } else {
             Poll::Pending
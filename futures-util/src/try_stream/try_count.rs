@@ -0,0 +1,44 @@
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::TryStream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A future which attempts to count the items of a stream.
+///
+/// This future is returned by the `TryStream::try_count` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct TryCount<St> {
+    stream: St,
+    count: usize,
+}
+
+impl<St: Unpin> Unpin for TryCount<St> {}
+
+impl<St: TryStream> TryCount<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(count: usize);
+
+    pub(super) fn new(stream: St) -> TryCount<St> {
+        TryCount { stream, count: 0 }
+    }
+}
+
+impl<St: TryStream> Future for TryCount<St> {
+    type Output = Result<usize, St::Error>;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Self::Output> {
+        loop {
+            match ready!(self.stream().try_poll_next(lw)) {
+                Some(Ok(_)) => *self.count() += 1,
+                Some(Err(e)) => return Poll::Ready(Err(e)),
+                None => return Poll::Ready(Ok(self.count)),
+            }
+        }
+    }
+}
@@ -5,7 +5,7 @@ use futures_core::stream::TryStream;
 use futures_core::task::{LocalWaker, Poll};
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
 
-/// The future for the `TryStream::fold` method.
+/// Future for the [`try_fold`](super::TryStreamExt::try_fold) combinator.
 #[derive(Debug)]
 #[must_use = "streams do nothing unless polled"]
 pub struct TryFold<St, Fut, T, F> {
@@ -35,6 +35,29 @@ where St: TryStream,
             future: None,
         }
     }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// This can be used to keep consuming the stream after the fold resolved
+    /// to an error. The accumulated value is dropped.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
 }
 
 impl<St, Fut, T, F> Future for TryFold<St, Fut, T, F>
@@ -48,9 +71,13 @@ impl<St, Fut, T, F> Future for TryFold<St, Fut, T, F>
         loop {
             // we're currently processing a future to produce a new accum value
             if self.accum().is_none() {
-                let accum = ready!(self.future().as_pin_mut().unwrap().try_poll(lw)?);
-                *self.accum() = Some(accum);
+                let accum = ready!(
+                    self.future().as_pin_mut()
+                        .expect("TryFold polled after completion")
+                        .try_poll(lw)
+                );
                 Pin::set(self.future(), None);
+                *self.accum() = Some(accum?);
             }
 
             let item = ready!(self.stream().try_poll_next(lw)?);
@@ -0,0 +1,80 @@
+use crate::stream::{Merge, StreamExt};
+use crate::try_stream::{IntoStream, TryStreamExt};
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::stream::{Stream, TryStream};
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`try_merge`](super::TryStreamExt::try_merge) combinator.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct TryMerge<St1, St2> {
+    inner: Merge<IntoStream<St1>, IntoStream<St2>>,
+    errored: bool,
+}
+
+impl<St1: Unpin, St2: Unpin> Unpin for TryMerge<St1, St2> {}
+
+impl<St1, St2> TryMerge<St1, St2>
+    where St1: TryStream,
+          St2: TryStream<Ok = St1::Ok, Error = St1::Error>
+{
+    unsafe_pinned!(inner: Merge<IntoStream<St1>, IntoStream<St2>>);
+    unsafe_unpinned!(errored: bool);
+
+    pub(super) fn new(stream1: St1, stream2: St2) -> TryMerge<St1, St2> {
+        TryMerge {
+            inner: stream1.into_stream().merge(stream2.into_stream()),
+            errored: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying streams that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> (&St1, &St2) {
+        let (stream1, stream2) = self.inner.get_ref();
+        (stream1.get_ref(), stream2.get_ref())
+    }
+
+    /// Acquires a mutable reference to the underlying streams that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> (&mut St1, &mut St2) {
+        let (stream1, stream2) = self.inner.get_mut();
+        (stream1.get_mut(), stream2.get_mut())
+    }
+
+    /// Consumes this combinator, returning the underlying streams.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> (St1, St2) {
+        let (stream1, stream2) = self.inner.into_inner();
+        (stream1.into_inner(), stream2.into_inner())
+    }
+}
+
+impl<St1, St2> Stream for TryMerge<St1, St2>
+    where St1: TryStream,
+          St2: TryStream<Ok = St1::Ok, Error = St1::Error>
+{
+    type Item = Result<St1::Ok, St1::Error>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker
+    ) -> Poll<Option<Self::Item>> {
+        if self.errored {
+            return Poll::Ready(None);
+        }
+
+        let item = ready!(self.as_mut().inner().poll_next(lw));
+        if let Some(Err(_)) = item {
+            *self.errored() = true;
+        }
+        Poll::Ready(item)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::stream::{Elapsed, StreamExt, Timeout};
+use crate::try_stream::{IntoStream, TryStreamExt};
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::{Stream, TryStream};
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::unsafe_pinned;
+
+/// Stream for the [`try_timeout`](super::TryStreamExt::try_timeout)
+/// combinator.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct TryTimeout<St, F, T> {
+    inner: Timeout<IntoStream<St>, F, T>,
+}
+
+impl<St: Unpin, F, T: Unpin> Unpin for TryTimeout<St, F, T> {}
+
+impl<St, F, T> TryTimeout<St, F, T>
+    where St: TryStream,
+          F: FnMut() -> T,
+          T: Future<Output = ()>,
+{
+    unsafe_pinned!(inner: Timeout<IntoStream<St>, F, T>);
+
+    pub(super) fn new(stream: St, make_timer: F) -> TryTimeout<St, F, T> {
+        TryTimeout { inner: stream.into_stream().timeout(make_timer) }
+    }
+
+    /// Don't apply the timeout to the first item of the stream.
+    ///
+    /// See [`Timeout::exempt_first_item`] for details.
+    pub fn exempt_first_item(self) -> Self {
+        TryTimeout { inner: self.inner.exempt_first_item() }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.inner.get_ref().get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.inner.get_mut().get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.inner.into_inner().into_inner()
+    }
+}
+
+impl<St, F, T> Stream for TryTimeout<St, F, T>
+    where St: TryStream,
+          St::Error: From<Elapsed>,
+          F: FnMut() -> T,
+          T: Future<Output = ()>,
+{
+    type Item = Result<St::Ok, St::Error>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<Self::Item>> {
+        let item = match ready!(self.inner().poll_next(lw)) {
+            Some(Ok(item)) => Some(item),
+            Some(Err(elapsed)) => Some(Err(elapsed.into())),
+            None => None,
+        };
+        Poll::Ready(item)
+    }
+}
@@ -0,0 +1,109 @@
+use core::marker::Unpin;
+use core::pin::Pin;
+use futures_core::future::TryFuture;
+use futures_core::stream::Stream;
+use futures_core::task::{LocalWaker, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Creates a `TryStream` from a seed and a closure returning a `TryFuture`.
+///
+/// This function is the fallible version of [`unfold`](crate::stream::unfold).
+/// It calls the provided closure with the provided seed, then waits for the
+/// returned future to complete. If it completes with `Ok(Some((item, state)))`,
+/// `item` is yielded and `state` is used as the next internal state. If it
+/// completes with `Ok(None)`, the stream ends.
+///
+/// If the future fails, the error is yielded and the stream ends right after.
+/// The stream keeps returning `None` when it's polled after it has ended.
+///
+/// # Example
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::future;
+/// use futures::stream::{self, TryStreamExt};
+///
+/// // Stands in for an HTTP request fetching a page of results, along with
+/// // the number of the next page, or `None` when there are no pages left
+/// fn fetch_page(page: u32) -> future::Ready<Result<Option<(Vec<u32>, u32)>, String>> {
+///     future::ready(match page {
+///         0 => Ok(Some((vec![1, 2], 1))),
+///         1 => Ok(Some((vec![3], 2))),
+///         2 => Ok(None),
+///         _ => Err(format!("page {} doesn't exist", page)),
+///     })
+/// }
+///
+/// let pages = stream::try_unfold(0, fetch_page);
+/// assert_eq!(await!(pages.try_collect::<Vec<_>>()), Ok(vec![vec![1, 2], vec![3]]));
+///
+/// // A failed request ends the stream
+/// let mut pages = stream::try_unfold(3, fetch_page);
+/// assert_eq!(await!(pages.try_next()), Err("page 3 doesn't exist".to_string()));
+/// assert_eq!(await!(pages.try_next()), Ok(None));
+/// # });
+/// ```
+pub fn try_unfold<T, F, Fut, Item>(init: T, f: F) -> TryUnfold<T, F, Fut>
+    where F: FnMut(T) -> Fut,
+          Fut: TryFuture<Ok = Option<(Item, T)>>,
+{
+    TryUnfold {
+        f,
+        state: Some(init),
+        fut: None,
+    }
+}
+
+/// A stream which creates fallible futures, polls them and returns their
+/// result
+///
+/// This stream is returned by the `futures::stream::try_unfold` method
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct TryUnfold<T, F, Fut> {
+    f: F,
+    state: Option<T>,
+    fut: Option<Fut>,
+}
+
+impl<T, F, Fut: Unpin> Unpin for TryUnfold<T, F, Fut> {}
+
+impl<T, F, Fut> TryUnfold<T, F, Fut> {
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(state: Option<T>);
+    unsafe_pinned!(fut: Option<Fut>);
+}
+
+impl<T, F, Fut, Item> Stream for TryUnfold<T, F, Fut>
+    where F: FnMut(T) -> Fut,
+          Fut: TryFuture<Ok = Option<(Item, T)>>,
+{
+    type Item = Result<Item, Fut::Error>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker
+    ) -> Poll<Option<Self::Item>> {
+        if let Some(state) = self.state().take() {
+            let fut = (self.f())(state);
+            Pin::set(self.fut(), Some(fut));
+        }
+
+        // Without a future, the stream has already ended
+        let step = match self.fut().as_pin_mut() {
+            Some(fut) => ready!(fut.try_poll(lw)),
+            None => return Poll::Ready(None),
+        };
+        Pin::set(self.fut(), None);
+
+        match step {
+            Ok(Some((item, next_state))) => {
+                *self.state() = Some(next_state);
+                Poll::Ready(Some(Ok(item)))
+            }
+            Ok(None) => Poll::Ready(None),
+            Err(e) => Poll::Ready(Some(Err(e))),
+        }
+    }
+}
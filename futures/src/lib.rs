@@ -143,7 +143,9 @@ pub mod executor {
     //! spawn non-`Send` tasks, via
     //! [`spawn_local_obj`](crate::executor::LocalSpawn::spawn_local_obj).
     //! The `LocalPool` is best suited for running I/O-bound tasks that do
-    //! relatively little work between I/O operations.
+    //! relatively little work between I/O operations. Tasks that borrow from
+    //! the current stack frame can be grouped into a
+    //! [`LocalScope`](crate::executor::LocalScope) and run on it.
     //!
     //! There is also a convenience function,
     //! [`block_on`](crate::executor::block_on), for simply running a future to
@@ -154,7 +156,10 @@ pub mod executor {
         BlockingStream,
         Enter, EnterError,
         LocalSpawner, LocalPool,
-        ThreadPool, ThreadPoolBuilder,
+        LocalScope, LocalScopeSpawner,
+        RunOne, Stalled,
+        Metrics, Priority, Shutdown, ShutdownMode, ThreadPool,
+        ThreadPoolBuilder,
         block_on, block_on_stream, enter,
     };
 }
@@ -182,31 +187,43 @@ pub mod future {
         lazy, Lazy,
         maybe_done, MaybeDone,
         poll_fn, PollFn,
+        poll_immediate, PollImmediate,
         ready, ok, err, Ready,
+        yield_now, YieldNow,
 
         OptionFuture,
 
         FutureExt,
-        FlattenStream, Flatten, Fuse, Inspect, IntoStream, Join, Join3, Join4,
-        Join5, Map, Then,
+        FlattenStream, Flatten, Fuse, Inspect, InspectPoll, IntoStream, Join,
+        Join3, Join4, Join5, Map, MapInto, Then, Timeout, TimeoutRecoverable,
+        Elapsed,
     };
 
     #[cfg(feature = "std")]
     pub use futures_util::future::{
-        abortable, Abortable, AbortHandle, AbortRegistration, Aborted,
-        Remote, RemoteHandle,
+        abortable, Abortable, AbortHandle, AbortRegistration, Aborted, DropGuard,
+        Remote, RemoteHandle, SharedRemoteHandle,
+        join_all, JoinAll,
+        join_all_buffered, JoinAllBuffered,
+        select_ok, SelectOk,
+        select_ok_all, SelectOkAll,
         // For FutureExt:
-        CatchUnwind, Shared,
+        CatchUnwind, Shared, WeakShared,
 
-        // ToDo: JoinAll, SelectAll, SelectOk, join_all, select_all, select_ok
+        // ToDo: SelectAll, select_all
     };
 
     pub use futures_util::try_future::{
         TryFutureExt,
-        AndThen, ErrInto, FlattenSink, IntoFuture, MapErr, MapOk, OrElse,
-        UnwrapOrElse,
+        AndThen, ErrInto, FlattenSink, InspectErr, InspectOk, IntoFuture,
+        MapErr, MapOk, OrElse, UnwrapOrElse,
         TryJoin, TryJoin3, TryJoin4, TryJoin5,
     };
+
+    #[cfg(feature = "std")]
+    pub use futures_util::try_future::{
+        try_join_all, try_join_all_settled, TryJoinAll, TryJoinAllSettled,
+    };
 }
 
 #[cfg(feature = "std")]
@@ -272,11 +289,11 @@ pub mod sink {
     pub use futures_util::sink::{
         Close, Flush, Send, SendAll, SinkErrInto, SinkMapErr, With,
         SinkExt, Fanout, Drain, DrainError, drain,
-        // WithFlatMap,
+        WithFlatMap,
     };
 
     #[cfg(feature = "std")]
-    pub use futures_util::sink::Buffer;
+    pub use futures_util::sink::{Buffer, BufferPolicy, BufferWithPolicy, TimedFlush};
 }
 
 pub mod stream {
@@ -302,41 +319,49 @@ pub mod stream {
         iter, Iter,
         repeat, Repeat,
         empty, Empty,
+        cycle_with, CycleWith,
         once, Once,
         poll_fn, PollFn,
+        select_with_strategy, PollNext, SelectWithStrategy,
         unfold, Unfold,
 
         StreamExt,
-        Chain, Concat, Filter, FilterMap, Flatten, Fold, Forward, ForEach, Fuse,
-        StreamFuture, Inspect, Map, Next, Peekable, Select, Skip, SkipWhile,
-        Take, TakeWhile, Then, Zip
+        Chain, Concat, Count, Cycle, Debounce, Dedup, DedupBy, DedupByKey, Enumerate,
+        Filter, FilterMap, Flatten, Fold, FoldWhile, ControlFlow, Forward,
+        ForEach, Fuse, StreamFuture, Inspect, Map, Merge, Next, NextIf,
+        NextIfEq, PeekMut, Peekable, ScanAsync, Select, Skip, SkipWhile, SwitchMap,
+        Take, TakeUntil, TakeWhile, Then, Throttle, Timeout, Elapsed, Unzip,
+        Zip, ZipLongest, EitherOrBoth,
     };
 
     #[cfg(feature = "std")]
     pub use futures_util::stream::{
         futures_ordered, FuturesOrdered,
         futures_unordered, FuturesUnordered,
+        QueueKey, CancelHandle, QueueHandle,
+        select_all, SelectAll, SelectAllHandle,
 
         // For StreamExt:
-        BufferUnordered, Buffered, CatchUnwind, Chunks, Collect, SplitStream,
-        SplitSink, ReuniteError,
-
-        // ToDo: select_all, SelectAll,
+        BufferUnordered, BufferUnorderedLazy, Buffered, CatchUnwind, Chunks,
+        ChunksTimeout, Collect, FlatMapUnordered, FlattenUnordered, Group, GroupBy,
+        Partition, ReadyChunks,
+        SplitStream, SplitSink, ReuniteError,
     };
 
     pub use futures_util::try_stream::{
         TryStreamExt,
-        TryNext, TryForEach, ErrInto,
-        TryFold, TrySkipWhile,
+        TryNext, TryForEach, ErrInto, InspectErr,
+        TryCount, TryFold, TrySkipWhile, TryMerge, TryTimeout,
         IntoStream,
-        // ToDo: AndThen, ErrInto, InspectErr, MapErr, OrElse
+        try_unfold, TryUnfold,
+        // ToDo: AndThen, ErrInto, MapErr, OrElse
     };
 
     #[cfg(feature = "std")]
     pub use futures_util::try_stream::{
         // For TryStreamExt:
-        TryCollect, TryBufferUnordered,
-        // ToDo: AndThen, InspectErr, MapErr, OrElse
+        TryCollect, TryBufferUnordered, ErrorStrategy,
+        // ToDo: AndThen, MapErr, OrElse
     };
 }
 
@@ -2,7 +2,7 @@
 
 use futures::channel::oneshot;
 use futures::executor::block_on;
-use futures::future::{abortable, Aborted, FutureExt};
+use futures::future::{abortable, ready, Abortable, AbortHandle, Aborted, FutureExt};
 use futures::task::Poll;
 use futures_test::task::WakeCounter;
 
@@ -39,3 +39,55 @@ fn abortable_resolves() {
 
     assert_eq!(Ok(Ok(())), block_on(abortable_rx));
 }
+
+#[test]
+fn shared_registration_aborts_all() {
+    let (_tx1, rx1) = oneshot::channel::<()>();
+    let (_tx2, rx2) = oneshot::channel::<()>();
+    let (abort_handle, abort_registration) = AbortHandle::new_pair();
+    let mut first = Abortable::new(rx1, abort_registration.clone());
+    let mut second = Abortable::new(rx2, abort_registration);
+
+    let first_counter = WakeCounter::new();
+    let second_counter = WakeCounter::new();
+    assert_eq!(Poll::Pending, first.poll_unpin(&first_counter.local_waker()));
+    assert_eq!(Poll::Pending, second.poll_unpin(&second_counter.local_waker()));
+
+    abort_handle.abort();
+    assert_eq!(1, first_counter.count());
+    assert_eq!(1, second_counter.count());
+    assert_eq!(Err(Aborted), block_on(first));
+    assert_eq!(Err(Aborted), block_on(second));
+}
+
+#[test]
+fn aborted_before_first_poll() {
+    let (abort_handle, abort_registration) = AbortHandle::new_pair();
+    abort_handle.abort();
+
+    let (_tx, rx) = oneshot::channel::<()>();
+    let first = Abortable::new(rx, abort_registration.clone());
+    let second = Abortable::new(ready(1), abort_registration);
+    assert_eq!(Err(Aborted), block_on(first));
+    assert_eq!(Err(Aborted), block_on(second));
+}
+
+#[test]
+fn drop_guard_aborts() {
+    let (_tx, rx) = oneshot::channel::<()>();
+    let (abortable_rx, abort_handle) = abortable(rx);
+
+    drop(abort_handle.abort_on_drop_guard());
+    assert_eq!(Err(Aborted), block_on(abortable_rx));
+}
+
+#[test]
+fn disarmed_drop_guard_does_not_abort() {
+    let (tx, rx) = oneshot::channel::<()>();
+    let (abortable_rx, abort_handle) = abortable(rx);
+
+    let guard = abort_handle.abort_on_drop_guard();
+    let _abort_handle = guard.disarm();
+    tx.send(()).unwrap();
+    assert_eq!(Ok(Ok(())), block_on(abortable_rx));
+}
@@ -0,0 +1,57 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot;
+use futures::future;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+use std::cell::Cell;
+
+#[test]
+fn pulls_only_on_demand() {
+    let pulled = Cell::new(0);
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+    let (tx3, rx3) = oneshot::channel::<i32>();
+    let (tx4, rx4) = oneshot::channel::<i32>();
+    let mut stream = stream::iter(vec![rx1, rx2, rx3, rx4])
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .buffer_unordered_lazy(3, 1);
+    let lw = &noop_local_waker_ref();
+
+    // Only the low-watermark is pulled up front
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(pulled.get(), 1);
+
+    tx1.send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Ok(1))));
+    assert_eq!(pulled.get(), 1);
+
+    // Yielding an output allows one more future to be pulled
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(pulled.get(), 2);
+
+    tx2.send(2).unwrap();
+    tx3.send(3).unwrap();
+    tx4.send(4).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Ok(2))));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Ok(3))));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Ok(4))));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert_eq!(pulled.get(), 4);
+}
+
+#[test]
+fn low_watermark_fills_queue() {
+    let pulled = Cell::new(0);
+    let mut stream = stream::iter(0..10)
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .map(|_| future::empty::<()>())
+        .buffer_unordered_lazy(8, 5);
+    let lw = &noop_local_waker_ref();
+
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(pulled.get(), 5);
+}
@@ -0,0 +1,111 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::sink::{BufferPolicy, Sink, SinkExt};
+use futures_test::task::{noop_local_waker_ref, WakeCounter};
+use std::cell::RefCell;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Duration;
+
+#[test]
+fn flushes_on_count() {
+    let lw = noop_local_waker_ref();
+    let mut sink = Vec::new().buffer_with_policy(
+        BufferPolicy::Count(2),
+        |_| -> futures::future::Ready<()> { panic!("no timer expected") },
+    );
+
+    assert!(Pin::new(&mut sink).poll_ready(lw).is_ready());
+    Pin::new(&mut sink).start_send(1).unwrap();
+    assert!(Pin::new(&mut sink).poll_ready(lw).is_ready());
+    assert!(sink.get_ref().is_empty());
+
+    Pin::new(&mut sink).start_send(2).unwrap();
+    assert!(Pin::new(&mut sink).poll_ready(lw).is_ready());
+    assert_eq!(sink.get_ref(), &vec![1, 2]);
+
+    Pin::new(&mut sink).start_send(3).unwrap();
+    assert!(Pin::new(&mut sink).poll_flush(lw).is_ready());
+    assert_eq!(sink.get_ref(), &vec![1, 2, 3]);
+}
+
+#[test]
+fn flushes_on_count_or_duration() {
+    let lw = noop_local_waker_ref();
+
+    // Each timer fires once its sender is used
+    let timers = Rc::new(RefCell::new(Vec::new()));
+    let timers2 = timers.clone();
+    let mut sink = Vec::new().buffer_with_policy(
+        BufferPolicy::CountOrDuration(3, Duration::from_millis(10)),
+        move |duration| {
+            assert_eq!(duration, Duration::from_millis(10));
+            let (timer_tx, timer_rx) = oneshot::channel::<()>();
+            timers2.borrow_mut().push(timer_tx);
+            timer_rx.map(|_| ())
+        },
+    );
+
+    // No timer runs while nothing is buffered
+    assert!(Pin::new(&mut sink).poll_ready(lw).is_ready());
+    assert!(timers.borrow().is_empty());
+
+    Pin::new(&mut sink).start_send(1).unwrap();
+    assert!(Pin::new(&mut sink).poll_ready(lw).is_ready());
+    Pin::new(&mut sink).start_send(2).unwrap();
+    assert!(Pin::new(&mut sink).poll_ready(lw).is_ready());
+    assert_eq!(timers.borrow().len(), 1);
+    assert!(sink.get_ref().is_empty());
+
+    timers.borrow_mut().pop().unwrap().send(()).unwrap();
+    assert!(Pin::new(&mut sink).poll_ready(lw).is_ready());
+    assert_eq!(sink.get_ref(), &vec![1, 2]);
+
+    // Reaching the count flushes without waiting for the new timer
+    for i in 3..=5 {
+        Pin::new(&mut sink).start_send(i).unwrap();
+        assert!(Pin::new(&mut sink).poll_ready(lw).is_ready());
+    }
+    assert_eq!(sink.get_ref(), &vec![1, 2, 3, 4, 5]);
+    assert_eq!(timers.borrow().len(), 1);
+}
+
+#[test]
+fn timed_flush_sends_partial_batch() {
+    let wake_counter = WakeCounter::new();
+    let lw = wake_counter.local_waker();
+    let (timer_tx, timer_rx) = oneshot::channel::<()>();
+    let mut timer_rx = Some(timer_rx);
+    let mut sink = Vec::new().buffer_with_policy(
+        BufferPolicy::CountOrDuration(10, Duration::from_secs(1)),
+        move |_| timer_rx.take().unwrap().map(|_| ()),
+    );
+
+    // The producer sends a single item and then stops
+    assert!(Pin::new(&mut sink).poll_ready(lw).is_ready());
+    Pin::new(&mut sink).start_send(1).unwrap();
+
+    {
+        let mut flush = sink.timed_flush();
+        assert!(flush.poll_unpin(lw).is_pending());
+        timer_tx.send(()).unwrap();
+        assert_eq!(wake_counter.count(), 1);
+        assert!(flush.poll_unpin(lw).is_ready());
+    }
+    assert_eq!(sink.get_ref(), &vec![1]);
+
+    // Nothing is left to flush
+    assert!(block_on(sink.timed_flush()).is_ok());
+}
+
+#[test]
+#[should_panic]
+fn zero_count_panics() {
+    let _ = Vec::<i32>::new().buffer_with_policy(
+        BufferPolicy::Count(0),
+        |_| futures::future::ready(()),
+    );
+}
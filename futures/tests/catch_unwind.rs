@@ -0,0 +1,36 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn catches_panic() {
+    assert_eq!(block_on(future::ready(2).catch_unwind()).ok(), Some(2));
+
+    let fut = future::lazy(|_| -> i32 { panic!("boom") });
+    let payload = block_on(fut.catch_unwind()).unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+}
+
+#[test]
+fn repoll_after_panic_panics() {
+    let lw = noop_local_waker_ref();
+    let polls = AtomicUsize::new(0);
+    let mut fut = future::poll_fn(|_| -> Poll<()> {
+        polls.fetch_add(1, Ordering::SeqCst);
+        panic!("boom")
+    }).catch_unwind();
+
+    assert!(match fut.poll_unpin(lw) {
+        Poll::Ready(Err(_)) => true,
+        _ => false,
+    });
+    let repoll = panic::catch_unwind(AssertUnwindSafe(|| fut.poll_unpin(lw)));
+    assert!(repoll.is_err());
+    drop(fut);
+    assert_eq!(polls.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,77 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::{mpsc, oneshot};
+use futures::future::FutureExt;
+use futures::stream::{self, Stream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn yields_on_capacity_or_timer() {
+    let (tx, rx) = mpsc::unbounded();
+
+    // Each timer fires once its sender is used
+    let timers = Rc::new(RefCell::new(Vec::new()));
+    let timers2 = timers.clone();
+    let mut stream = rx.chunks_timeout(2, move || {
+        let (timer_tx, timer_rx) = oneshot::channel::<()>();
+        timers2.borrow_mut().push(timer_tx);
+        timer_rx.map(|_| ())
+    });
+    let lw = &noop_local_waker_ref();
+
+    // No timer runs while nothing is buffered
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert!(timers.borrow().is_empty());
+
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(vec![1, 2])));
+    assert_eq!(timers.borrow().len(), 1);
+    timers.borrow_mut().clear();
+
+    tx.unbounded_send(3).unwrap();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    timers.borrow_mut().pop().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(vec![3])));
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    tx.unbounded_send(4).unwrap();
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(vec![4])));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn flushes_partial_chunk_at_end_and_drops_timer() {
+    let (tx, rx) = mpsc::unbounded();
+    let timers = Rc::new(RefCell::new(Vec::new()));
+    let timers2 = timers.clone();
+    let mut stream = rx.chunks_timeout(3, move || {
+        let (timer_tx, timer_rx) = oneshot::channel::<()>();
+        timers2.borrow_mut().push(timer_tx);
+        timer_rx.map(|_| ())
+    });
+    let lw = &noop_local_waker_ref();
+
+    tx.unbounded_send(1).unwrap();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(timers.borrow().len(), 1);
+
+    // The partial chunk doesn't wait for its timer once the stream ends
+    tx.unbounded_send(2).unwrap();
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(vec![1, 2])));
+    assert!(timers.borrow()[0].is_canceled());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert_eq!(timers.borrow().len(), 1);
+}
+
+#[test]
+fn size_hint() {
+    let stream = stream::iter(1..=7)
+        .chunks_timeout(3, || -> futures::future::Empty<()> { futures::future::empty() });
+    assert_eq!(stream.size_hint(), (3, Some(7)));
+}
@@ -0,0 +1,40 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use std::rc::Rc;
+
+#[test]
+fn counts_items() {
+    assert_eq!(block_on(stream::iter(1..=5).count()), 5);
+    assert_eq!(block_on(stream::empty::<()>().count()), 0);
+
+    let (tx, rx) = mpsc::unbounded();
+    for i in 0..3 {
+        tx.unbounded_send(i).unwrap();
+    }
+    drop(tx);
+    assert_eq!(block_on(rx.count()), 3);
+}
+
+#[test]
+fn drops_counted_items() {
+    let item = Rc::new(());
+    let items = vec![item.clone(), item.clone()];
+    assert_eq!(block_on(stream::iter(items).count()), 2);
+    assert_eq!(Rc::strong_count(&item), 1);
+}
+
+#[test]
+fn try_count_stops_at_error() {
+    let (tx, mut rx) = mpsc::unbounded();
+    tx.unbounded_send(Ok::<i32, i32>(1)).unwrap();
+    tx.unbounded_send(Err(2)).unwrap();
+    tx.unbounded_send(Ok(3)).unwrap();
+    drop(tx);
+
+    assert_eq!(block_on((&mut rx).try_count()), Err(2));
+    // The items after the error are left in the stream
+    assert_eq!(block_on(rx.try_count()), Ok(1));
+}
@@ -0,0 +1,52 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::stream::{self, Stream, StreamExt};
+use std::cell::Cell;
+
+#[test]
+fn cycle_repeats_stream() {
+    let stream = stream::iter(vec!['a', 'b']).cycle();
+    assert_eq!(stream.size_hint(), (usize::max_value(), None));
+
+    let items: Vec<_> = block_on(stream.take(5).collect());
+    assert_eq!(items, vec!['a', 'b', 'a', 'b', 'a']);
+}
+
+#[test]
+fn cycle_of_empty_stream_ends() {
+    let stream = stream::empty::<i32>().cycle();
+    assert_eq!(stream.size_hint(), (0, Some(0)));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![]);
+
+    let stream = stream::iter(Vec::<i32>::new()).cycle();
+    assert_eq!(stream.size_hint(), (0, Some(0)));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![]);
+}
+
+#[test]
+fn cycle_with_recreates_stream() {
+    let calls = Cell::new(0);
+    let stream = stream::cycle_with(|| {
+        calls.set(calls.get() + 1);
+        stream::iter(vec![calls.get(); 2])
+    });
+
+    let items: Vec<_> = block_on(stream.take(5).collect());
+    assert_eq!(items, vec![1, 1, 2, 2, 3]);
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn cycle_with_ends_on_empty_stream() {
+    let calls = Cell::new(0);
+    let stream = stream::cycle_with(|| {
+        calls.set(calls.get() + 1);
+        let items = if calls.get() < 3 { vec![calls.get()] } else { vec![] };
+        stream::iter(items)
+    });
+
+    let items: Vec<_> = block_on(stream.collect());
+    assert_eq!(items, vec![1, 2]);
+    assert_eq!(calls.get(), 3);
+}
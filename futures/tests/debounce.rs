@@ -0,0 +1,54 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::{mpsc, oneshot};
+use futures::future::FutureExt;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn yields_last_item_of_burst() {
+    // Each timer fires once its sender is used
+    let timers = Rc::new(RefCell::new(Vec::new()));
+    let timers2 = timers.clone();
+    let (tx, rx) = mpsc::unbounded();
+    let mut stream = rx.debounce(move || {
+        let (timer_tx, timer_rx) = oneshot::channel::<()>();
+        timers2.borrow_mut().push(timer_tx);
+        timer_rx.map(|_| ())
+    });
+    let lw = &noop_local_waker_ref();
+
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert!(timers.borrow().is_empty());
+
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    // A new item restarts the timer
+    tx.unbounded_send(3).unwrap();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(timers.borrow().len(), 3);
+
+    timers.borrow_mut().pop().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(3)));
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    tx.unbounded_send(4).unwrap();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    timers.borrow_mut().pop().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(4)));
+}
+
+#[test]
+fn flushes_held_item_when_stream_ends() {
+    let mut stream = stream::iter(vec![1, 2, 3])
+        .debounce(|| -> futures::future::Empty<()> { futures::future::empty() });
+    let lw = &noop_local_waker_ref();
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(3)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
@@ -0,0 +1,43 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+
+#[test]
+fn skips_consecutive_duplicates() {
+    let stream = stream::iter(vec![1, 1, 2, 3, 3, 3, 2, 2]).dedup();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 2, 3, 2]);
+
+    let stream = stream::empty::<i32>().dedup();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![]);
+}
+
+#[test]
+fn skips_ready_run_in_one_poll() {
+    let lw = noop_local_waker_ref();
+    let mut stream = stream::iter(vec![7; 1000].into_iter().chain(Some(8))).dedup();
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(7)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(8)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn dedup_by_key_compares_keys() {
+    let stream = stream::iter(vec!["apple", "avocado", "banana", "apricot"])
+        .dedup_by_key(|s| s.chars().next());
+    assert_eq!(
+        block_on(stream.collect::<Vec<_>>()),
+        vec!["apple", "banana", "apricot"],
+    );
+}
+
+#[test]
+fn dedup_by_compares_with_last_yielded() {
+    // Items within 2 of the last yielded item are considered duplicates
+    let stream = stream::iter(vec![1, 2, 3, 4, 10, 11])
+        .dedup_by(|last: &i32, item: &i32| (item - last).abs() <= 2);
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 4, 10]);
+}
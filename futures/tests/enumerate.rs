@@ -0,0 +1,86 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::sink::{Sink, SinkExt};
+use futures::stream::{self, Stream, StreamExt};
+use futures::task::{LocalWaker, Poll};
+use pin_utils::unsafe_pinned;
+use std::pin::Pin;
+
+#[test]
+fn counts_items() {
+    let stream = stream::iter(vec!["a", "b", "c"]).enumerate();
+    assert_eq!(
+        block_on(stream.collect::<Vec<_>>()),
+        vec![(0, "a"), (1, "b"), (2, "c")],
+    );
+
+    let stream = stream::empty::<()>().enumerate();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![]);
+}
+
+// A stream that is a sink as well
+struct Duplex<St> {
+    stream: St,
+    sent: Vec<i32>,
+}
+
+impl<St> Duplex<St> {
+    unsafe_pinned!(stream: St);
+}
+
+impl<St: Stream> Stream for Duplex<St> {
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        lw: &LocalWaker,
+    ) -> Poll<Option<St::Item>> {
+        self.stream().poll_next(lw)
+    }
+}
+
+impl<St> Sink for Duplex<St> {
+    type SinkItem = i32;
+    type SinkError = ();
+
+    fn poll_ready(
+        self: Pin<&mut Self>,
+        _: &LocalWaker,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(
+        self: Pin<&mut Self>,
+        item: Self::SinkItem,
+    ) -> Result<(), Self::SinkError> {
+        unsafe { Pin::get_mut_unchecked(self) }.sent.push(item);
+        Ok(())
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        _: &LocalWaker,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        self: Pin<&mut Self>,
+        _: &LocalWaker,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn forwards_sink() {
+    let duplex = Duplex { stream: stream::iter(vec![10, 20]), sent: Vec::new() };
+    let mut enumerate = duplex.enumerate();
+
+    block_on(enumerate.send(1)).unwrap();
+    block_on(enumerate.send(2)).unwrap();
+    assert_eq!(block_on(enumerate.next()), Some((0, 10)));
+    assert_eq!(enumerate.get_ref().sent, vec![1, 2]);
+}
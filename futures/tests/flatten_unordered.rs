@@ -0,0 +1,78 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::block_on_stream;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+
+#[test]
+fn interleaves_inner_streams() {
+    let (tx1, rx1) = mpsc::unbounded();
+    let (tx2, rx2) = mpsc::unbounded();
+    let mut stream = stream::iter(vec![rx1, rx2]).flatten_unordered(None);
+    let lw = &noop_local_waker_ref();
+
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    tx2.unbounded_send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    tx1.unbounded_send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+    tx2.unbounded_send(3).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(3)));
+
+    drop(tx1);
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    drop(tx2);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn limit_bounds_inner_streams_in_progress() {
+    let (tx1, rx1) = mpsc::unbounded();
+    let (tx2, rx2) = mpsc::unbounded();
+    let mut stream = stream::iter(vec![rx1, rx2]).flatten_unordered(1);
+    let lw = &noop_local_waker_ref();
+
+    // The second stream isn't taken on until the first one has ended
+    tx2.unbounded_send(2).unwrap();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    tx1.unbounded_send(1).unwrap();
+    drop(tx1);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+
+    drop(tx2);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn errors_do_not_end_other_streams() {
+    let inner1 = stream::iter(vec![Ok(1), Err(2)]);
+    let inner2 = stream::iter(vec![Ok::<i32, i32>(3)]);
+    let mut items: Vec<_> = block_on_stream(stream::iter(vec![inner1, inner2]).flatten_unordered(0))
+        .collect();
+    items.sort();
+    assert_eq!(items, vec![Ok(1), Ok(3), Err(2)]);
+}
+
+#[test]
+fn flat_map_unordered_limits_mapped_streams() {
+    let (tx1, rx1) = mpsc::unbounded();
+    let (tx2, rx2) = mpsc::unbounded();
+    let mut receivers = vec![Some(rx1), Some(rx2)];
+    let mut stream = stream::iter(0..2)
+        .flat_map_unordered(1, move |i: usize| receivers[i].take().unwrap());
+    let lw = &noop_local_waker_ref();
+
+    tx2.unbounded_send(2).unwrap();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    drop(tx1);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+
+    drop(tx2);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
@@ -0,0 +1,41 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::future;
+use futures::stream::{self, ControlFlow, StreamExt};
+
+#[test]
+fn breaks_without_polling_further() {
+    let (tx, mut rx) = mpsc::unbounded();
+    for i in 1..=5 {
+        tx.unbounded_send(i).unwrap();
+    }
+
+    let first_two = (&mut rx).fold_while(Vec::new(), |mut acc, x| {
+        acc.push(x);
+        future::ready(if acc.len() == 2 {
+            ControlFlow::Break(acc)
+        } else {
+            ControlFlow::Continue(acc)
+        })
+    });
+    assert_eq!(block_on(first_two), vec![1, 2]);
+
+    // The remaining items are still in the channel
+    drop(tx);
+    assert_eq!(block_on(rx.collect::<Vec<_>>()), vec![3, 4, 5]);
+}
+
+#[test]
+fn resolves_at_end_of_stream() {
+    let sum = stream::iter(1..=4).fold_while(0, |acc, x| {
+        future::ready(ControlFlow::Continue(acc + x))
+    });
+    assert_eq!(block_on(sum), 10);
+
+    let empty = stream::iter(Vec::<i32>::new()).fold_while(7, |_, _| {
+        future::ready(ControlFlow::Break(0))
+    });
+    assert_eq!(block_on(empty), 7);
+}
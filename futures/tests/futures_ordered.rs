@@ -82,3 +82,57 @@ fn queue_never_unblocked() {
         assert!(stream.poll_next(lw).unwrap().is_pending());
     })
 }*/
+
+#[test]
+fn len_counts_buffered_outputs() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+
+    let mut stream = futures_ordered(vec![a_rx, b_rx]);
+    assert_eq!(stream.len(), 2);
+
+    b_tx.send(2).unwrap();
+    assert!(stream.poll_next_unpin(&noop_local_waker_ref()).is_pending());
+    assert_eq!(stream.len(), 2);
+
+    a_tx.send(1).unwrap();
+    let mut iter = block_on_stream(stream);
+    assert_eq!(Some(Ok(1)), iter.next());
+    assert_eq!(Some(Ok(2)), iter.next());
+    assert_eq!(None, iter.next());
+}
+
+#[test]
+fn push_front_yields_first() {
+    let lw = noop_local_waker_ref();
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+
+    let mut stream = FuturesOrdered::new();
+    stream.push_back(a_rx);
+    stream.push_front(b_rx);
+    stream.push_back(c_rx);
+
+    // The outputs of `a` and `c` wait for `b`, which was pushed to the front
+    a_tx.send(1).unwrap();
+    c_tx.send(3).unwrap();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(stream.len(), 3);
+
+    b_tx.send(2).unwrap();
+    let outputs = block_on(stream.collect::<Vec<_>>());
+    assert_eq!(outputs, vec![Ok(2), Ok(1), Ok(3)]);
+}
+
+#[test]
+fn push_front_after_yielding() {
+    let mut stream = FuturesOrdered::new();
+    stream.push_back(future::ready(1));
+    stream.push_back(future::ready(2));
+    assert_eq!(block_on(stream.next()), Some(1));
+
+    stream.push_front(future::ready(0));
+    stream.push_back(future::ready(3));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![0, 2, 3]);
+}
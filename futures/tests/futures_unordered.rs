@@ -7,8 +7,13 @@ use futures::stream::{StreamExt, futures_unordered, FuturesUnordered};
 use futures::task::Poll;
 use futures_test::{assert_stream_done, assert_stream_next};
 use futures_test::future::FutureTestExt;
-use futures_test::task::noop_local_waker_ref;
+use futures_test::task::{noop_local_waker_ref, WakeCounter};
 use std::boxed::Box;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
 
 #[test]
 fn works_1() {
@@ -60,6 +65,14 @@ fn from_iterator() {
     assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1,2,3]);
 }
 
+#[test]
+fn extend() {
+    let mut stream = futures_unordered(vec![future::ready::<i32>(1)]);
+    stream.extend(vec![future::ready(2), future::ready(3)]);
+    assert_eq!(stream.len(), 3);
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1,2,3]);
+}
+
 /* ToDo: This requires FutureExt::select to be implemented
 #[test]
 fn finished_future() {
@@ -139,3 +152,387 @@ fn futures_not_moved_after_poll() {
     assert_stream_next!(stream, ());
     assert_stream_done!(stream);
 }
+
+#[test]
+fn push_with_key_yields_keys() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+
+    let mut stream = FuturesUnordered::new();
+    let a_key = stream.push_with_key(a_rx);
+    let b_key = stream.push_with_key(b_rx);
+    assert_ne!(a_key, b_key);
+
+    let lw = &noop_local_waker_ref();
+    assert!(Pin::new(&mut stream).poll_next_with_key(lw).is_pending());
+
+    b_tx.send(2).unwrap();
+    assert_eq!(Pin::new(&mut stream).poll_next_with_key(lw),
+               Poll::Ready(Some((b_key, Ok(2)))));
+    a_tx.send(1).unwrap();
+    assert_eq!(Pin::new(&mut stream).poll_next_with_key(lw),
+               Poll::Ready(Some((a_key, Ok(1)))));
+    assert_eq!(Pin::new(&mut stream).poll_next_with_key(lw),
+               Poll::Ready(None));
+}
+
+#[test]
+fn push_front_is_polled_first() {
+    let mut stream = FuturesUnordered::new();
+    stream.push(future::ready(1));
+    stream.push(future::ready(2));
+    stream.push_front(future::ready(3));
+    stream.push_front(future::ready(4));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![4, 3, 1, 2]);
+}
+
+#[test]
+fn cancel_handle() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+
+    let mut stream = FuturesUnordered::new();
+    let a_handle = stream.push_cancelable(a_rx);
+    let b_handle = stream.push_cancelable(b_rx);
+
+    let lw = &noop_local_waker_ref();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(stream.len(), 2);
+
+    // The future stops counting right away, and only once
+    a_handle.cancel();
+    assert_eq!(stream.len(), 1);
+    a_handle.cancel();
+    assert_eq!(stream.len(), 1);
+    assert!(!a_tx.is_canceled());
+
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(stream.len(), 1);
+    assert!(a_tx.is_canceled());
+
+    b_tx.send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Ok(2))));
+    assert_eq!(stream.len(), 0);
+
+    // Canceling completed futures is a no-op
+    a_handle.cancel();
+    b_handle.cancel();
+    assert_eq!(stream.len(), 0);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn clear() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+
+    let mut stream = futures_unordered(vec![a_rx, b_rx]);
+
+    let lw = &noop_local_waker_ref();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    b_tx.send(2).unwrap();
+    stream.clear();
+    assert!(stream.is_empty());
+    assert!(a_tx.is_canceled());
+
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+    stream.push(c_rx);
+    c_tx.send(3).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Ok(3))));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn iter_len() {
+    let stream = futures_unordered(vec![
+        future::ready(1),
+        future::ready(2),
+        future::ready(3)
+    ]);
+
+    let mut iter = stream.iter();
+    assert_eq!(iter.len(), 3);
+    assert!(iter.next().is_some());
+    assert_eq!(iter.len(), 2);
+    assert!(iter.next().is_some());
+    assert_eq!(iter.len(), 1);
+    assert!(iter.next().is_some());
+    assert_eq!(iter.len(), 0);
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn bounded_try_push() {
+    let mut stream = FuturesUnordered::bounded(2);
+    assert_eq!(stream.available_capacity(), 2);
+    assert!(stream.try_push(future::ready(1)).is_ok());
+    assert!(stream.try_push(future::ready(2)).is_ok());
+    assert_eq!(stream.available_capacity(), 0);
+    assert!(stream.try_push(future::ready(3)).is_err());
+
+    assert_stream_next!(stream, 1);
+    assert_eq!(stream.available_capacity(), 1);
+    assert!(stream.try_push(future::ready(3)).is_ok());
+    assert_stream_next!(stream, 2);
+    assert_stream_next!(stream, 3);
+    assert_stream_done!(stream);
+}
+
+#[test]
+fn panicking_future_is_released() {
+    let mut stream = (0..4).map(|i| future::lazy(move |_| {
+        if i == 2 {
+            panic!("future {} panicked", i);
+        }
+        i
+    })).collect::<FuturesUnordered<_>>();
+
+    let lw = &noop_local_waker_ref();
+    let mut outputs = Vec::new();
+    let mut panics = 0;
+    loop {
+        match panic::catch_unwind(AssertUnwindSafe(|| stream.poll_next_unpin(lw))) {
+            Ok(Poll::Ready(Some(i))) => outputs.push(i),
+            Ok(Poll::Ready(None)) => break,
+            Ok(Poll::Pending) => panic!("all futures are ready"),
+            Err(_) => panics += 1,
+        }
+    }
+
+    outputs.sort();
+    assert_eq!(outputs, vec![0, 1, 3]);
+    assert_eq!(panics, 1);
+    assert!(stream.is_empty());
+}
+
+#[test]
+fn drain() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+
+    let mut stream = futures_unordered(vec![a_rx, b_rx]);
+
+    let lw = &noop_local_waker_ref();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    // `a_rx` gets queued for polling, but is drained before that happens
+    a_tx.send(1).unwrap();
+    let drained = stream.drain().collect::<Vec<_>>();
+    assert_eq!(drained.len(), 2);
+    assert!(stream.is_empty());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+
+    b_tx.send(2).unwrap();
+    stream.extend(drained);
+    let mut outputs = block_on(stream.collect::<Vec<_>>())
+        .into_iter()
+        .map(Result::unwrap)
+        .collect::<Vec<_>>();
+    outputs.sort();
+    assert_eq!(outputs, vec![1, 2]);
+}
+
+#[test]
+fn poll_budget_yields() {
+    let mut stream = FuturesUnordered::with_poll_budget(32);
+    for _ in 0..1000 {
+        stream.push(future::ready(()).pending_once());
+    }
+
+    let wake_counter = WakeCounter::new();
+    let lw = wake_counter.local_waker();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(stream.len(), 1000);
+    assert!(wake_counter.count() > 0);
+
+    assert_eq!(block_on(stream.collect::<Vec<_>>()).len(), 1000);
+}
+
+#[test]
+fn poll_ready_once() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+
+    let mut stream = futures_unordered(vec![a_rx, b_rx]);
+
+    let mut outputs = Vec::new();
+    assert_eq!(stream.poll_ready_once(|output| outputs.push(output)), 0);
+    assert!(outputs.is_empty());
+    assert_eq!(stream.len(), 2);
+
+    b_tx.send(2).unwrap();
+    assert_eq!(stream.poll_ready_once(|output| outputs.push(output)), 1);
+    assert_eq!(outputs, vec![Ok(2)]);
+    assert_eq!(stream.len(), 1);
+
+    // Nothing was woken up
+    assert_eq!(stream.poll_ready_once(|output| outputs.push(output)), 0);
+
+    a_tx.send(1).unwrap();
+    assert_eq!(stream.poll_ready_once(|output| outputs.push(output)), 1);
+    assert_eq!(outputs, vec![Ok(2), Ok(1)]);
+    assert!(stream.is_empty());
+}
+
+#[test]
+fn debug_reports_len_and_ready() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (_b_tx, b_rx) = oneshot::channel::<i32>();
+
+    let mut stream = futures_unordered(vec![a_rx, b_rx]);
+    assert_eq!(format!("{:?}", stream), "FuturesUnordered { len: 2, ready: 2 }");
+
+    let lw = &noop_local_waker_ref();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(format!("{:?}", stream), "FuturesUnordered { len: 2, ready: 0 }");
+
+    a_tx.send(1).unwrap();
+    assert_eq!(format!("{:?}", stream), "FuturesUnordered { len: 2, ready: 1 }");
+}
+
+#[test]
+fn remove_by_key() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+
+    let mut stream = FuturesUnordered::new();
+    let a_key = stream.push_with_key(a_rx);
+    let b_key = stream.push_with_key(b_rx);
+
+    let lw = &noop_local_waker_ref();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    b_tx.send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Ok(2))));
+    assert!(stream.remove(b_key).is_none());
+
+    let a_rx = stream.remove(a_key).unwrap();
+    assert!(stream.is_empty());
+    assert!(stream.remove(a_key).is_none());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+
+    a_tx.send(1).unwrap();
+    assert_eq!(block_on(a_rx), Ok(1));
+}
+
+#[test]
+fn push_through_handle() {
+    let mut stream = FuturesUnordered::new();
+    let handle = stream.handle();
+
+    let lw = &noop_local_waker_ref();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+
+    let pusher = handle.clone();
+    thread::spawn(move || {
+        for i in 0..3 {
+            pusher.push(future::ready(i)).unwrap();
+        }
+    }).join().unwrap();
+
+    assert_eq!(stream.len(), 0);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(0)));
+    assert_eq!(stream.len(), 2);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+
+    drop(stream);
+    assert!(handle.push(future::ready(3)).is_err());
+}
+
+#[test]
+fn is_terminated() {
+    let mut stream = FuturesUnordered::new();
+    assert!(!stream.is_terminated());
+
+    let lw = &noop_local_waker_ref();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(stream.is_terminated());
+
+    // Pushing makes a regular set usable again
+    stream.push(future::ready(1));
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn fused_rejects_pushes_after_termination() {
+    let mut stream = FuturesUnordered::fused();
+    assert!(stream.try_push(future::ready(1)).is_ok());
+
+    let lw = &noop_local_waker_ref();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(stream.is_terminated());
+
+    assert!(stream.try_push(future::ready(2)).is_err());
+    stream.handle().push(future::ready(3)).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(stream.is_empty());
+}
+
+#[test]
+fn set_poll_budget() {
+    let mut stream = futures_unordered(vec![
+        future::ready(1).pending_once(),
+        future::ready(2).pending_once(),
+    ]);
+    stream.set_poll_budget(1);
+
+    let lw = &noop_local_waker_ref();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn wakers_outlive_the_set() {
+    struct SetOnDrop(Arc<AtomicBool>);
+
+    impl Drop for SetOnDrop {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    let wakers = Arc::new(Mutex::new(Vec::new()));
+    let guard = SetOnDrop(dropped.clone());
+    let wakers2 = wakers.clone();
+
+    let mut stream = FuturesUnordered::new();
+    stream.push(future::poll_fn(move |lw| {
+        let _ = &guard;
+        wakers2.lock().unwrap().push(lw.clone().into_waker());
+        Poll::<()>::Pending
+    }));
+    assert!(stream.poll_next_unpin(noop_local_waker_ref()).is_pending());
+
+    // The future is dropped along with the set, even though it holds a waker
+    // referring to its own task
+    drop(stream);
+    assert!(dropped.load(Ordering::SeqCst));
+
+    // The task itself lives on in the wakers, which can still be cloned,
+    // woken and dropped from any thread
+    let waker = wakers.lock().unwrap().pop().unwrap();
+    let threads: Vec<_> = (0..4).map(|_| {
+        let waker = waker.clone();
+        thread::spawn(move || {
+            let clones: Vec<_> = (0..1000).map(|_| waker.clone()).collect();
+            for clone in &clones {
+                clone.wake();
+            }
+        })
+    }).collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    waker.wake();
+}
@@ -0,0 +1,102 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+
+#[test]
+fn groups_interleaved_items() {
+    let words = vec!["apple", "bean", "avocado", "banana", "cherry", "asparagus"];
+    let groups = stream::iter(words).group_by(8, |word| word.chars().next().unwrap());
+    let groups: Vec<_> = block_on(groups.collect());
+
+    let mut result = Vec::new();
+    for (key, group) in groups {
+        assert_eq!(*group.key(), key);
+        result.push((key, block_on(group.collect::<Vec<_>>())));
+    }
+    assert_eq!(result, vec![
+        ('a', vec!["apple", "avocado", "asparagus"]),
+        ('b', vec!["bean", "banana"]),
+        ('c', vec!["cherry"]),
+    ]);
+}
+
+#[test]
+fn group_pulls_items_for_others() {
+    let lw = &noop_local_waker_ref();
+    let mut groups = stream::iter(vec![1, 3, 2, 5, 4]).group_by(4, |x| x % 2);
+
+    let (_, mut odds) = match groups.poll_next_unpin(lw) {
+        Poll::Ready(Some(group)) => group,
+        _ => panic!("expected a group"),
+    };
+    assert_eq!(odds.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(odds.poll_next_unpin(lw), Poll::Ready(Some(3)));
+    // `2` starts a new group, which is yielded by the parent later on
+    assert_eq!(odds.poll_next_unpin(lw), Poll::Ready(Some(5)));
+
+    let (key, mut evens) = match groups.poll_next_unpin(lw) {
+        Poll::Ready(Some(group)) => group,
+        _ => panic!("expected a group"),
+    };
+    assert_eq!(key, 0);
+    assert_eq!(evens.poll_next_unpin(lw), Poll::Ready(Some(2)));
+    assert_eq!(evens.poll_next_unpin(lw), Poll::Ready(Some(4)));
+    assert_eq!(evens.poll_next_unpin(lw), Poll::Ready(None));
+    assert_eq!(odds.poll_next_unpin(lw), Poll::Ready(None));
+    assert_eq!(groups.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn full_buffer_applies_backpressure() {
+    let lw = &noop_local_waker_ref();
+    let (tx, rx) = mpsc::unbounded();
+    let mut groups = rx.group_by(1, |x: &i32| *x < 10);
+
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    tx.unbounded_send(10).unwrap();
+    let (_, mut small) = match groups.poll_next_unpin(lw) {
+        Poll::Ready(Some(group)) => group,
+        _ => panic!("expected a group"),
+    };
+
+    // `1` is still buffered, so `2` isn't pulled from the channel
+    assert!(groups.poll_next_unpin(lw).is_pending());
+    assert_eq!(small.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(small.poll_next_unpin(lw), Poll::Ready(Some(2)));
+
+    // `10` starts a new group and `small` has to wait for it to be taken
+    assert!(small.poll_next_unpin(lw).is_pending());
+    assert!(groups.poll_next_unpin(lw).is_ready());
+}
+
+#[test]
+fn dropped_group_discards_its_items() {
+    let lw = &noop_local_waker_ref();
+    let mut groups = stream::iter(vec![1, 2, 3, 4]).group_by(4, |x| x % 2);
+
+    let (_, odds) = match groups.poll_next_unpin(lw) {
+        Poll::Ready(Some(group)) => group,
+        _ => panic!("expected a group"),
+    };
+    drop(odds);
+
+    let (key, evens) = match groups.poll_next_unpin(lw) {
+        Poll::Ready(Some(group)) => group,
+        _ => panic!("expected a group"),
+    };
+    assert_eq!(key, 0);
+    // No new group is created for the odd numbers
+    assert_eq!(groups.poll_next_unpin(lw), Poll::Ready(None));
+    assert_eq!(block_on(evens.collect::<Vec<_>>()), vec![2, 4]);
+}
+
+#[test]
+#[should_panic]
+fn zero_buffer_panics() {
+    let _ = stream::iter(vec![1]).group_by(0, |x| *x);
+}
@@ -1,7 +1,11 @@
 #![feature(pin, arbitrary_self_types, futures_api)]
 
 use futures::executor::block_on;
-use futures::future::{self, FutureExt};
+use futures::channel::oneshot;
+use futures::future::{self, FutureExt, TryFutureExt};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
 
 #[test]
 fn smoke() {
@@ -14,3 +18,54 @@ fn smoke() {
 
     assert_eq!(counter, 40);
 }
+
+#[test]
+fn inspect_err_sees_each_error_once() {
+    let mut seen = Vec::new();
+
+    {
+        let stream = stream::iter(vec![Ok(1), Err("a"), Ok(2), Err("b")])
+            .inspect_err(|e| seen.push(*e));
+        let items: Vec<Result<i32, &str>> = block_on(stream.collect());
+        assert_eq!(items, vec![Ok(1), Err("a"), Ok(2), Err("b")]);
+    }
+
+    assert_eq!(seen, vec!["a", "b"]);
+}
+
+#[test]
+fn inspect_ok_and_err() {
+    let mut seen = Vec::new();
+
+    {
+        let ok = future::ok::<i32, i32>(1)
+            .inspect_ok(|x| seen.push(*x))
+            .inspect_err(|_| panic!());
+        assert_eq!(block_on(ok), Ok(1));
+    }
+    {
+        let err = future::err::<i32, i32>(2)
+            .inspect_ok(|_| panic!())
+            .inspect_err(|e| seen.push(*e));
+        assert_eq!(block_on(err), Err(2));
+    }
+
+    assert_eq!(seen, vec![1, 2]);
+}
+
+#[test]
+fn inspect_poll_sees_every_poll() {
+    let lw = noop_local_waker_ref();
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut polls = Vec::new();
+
+    {
+        let mut fut = rx.inspect_poll(|poll| polls.push(poll.is_ready()));
+        assert!(fut.poll_unpin(lw).is_pending());
+        assert!(fut.poll_unpin(lw).is_pending());
+        tx.send(3).unwrap();
+        assert_eq!(fut.poll_unpin(lw), Poll::Ready(Ok(3)));
+    }
+
+    assert_eq!(polls, vec![false, false, true]);
+}
@@ -0,0 +1,45 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, join_all, FutureExt, JoinAll};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+
+fn keeps_order_of(n: usize) {
+    let lw = noop_local_waker_ref();
+    let (senders, receivers): (Vec<_>, Vec<_>) =
+        (0..n).map(|_| oneshot::channel::<usize>()).unzip();
+
+    let mut fut = join_all(receivers);
+    assert!(fut.poll_unpin(lw).is_pending());
+
+    // Complete the futures back to front
+    for (i, tx) in senders.into_iter().enumerate().rev() {
+        tx.send(i).unwrap();
+        if i > 0 {
+            assert!(fut.poll_unpin(lw).is_pending());
+        }
+    }
+    let expected = (0..n).map(Ok).collect::<Vec<_>>();
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(expected));
+}
+
+#[test]
+fn keeps_order_of_few_futures() {
+    keeps_order_of(3);
+}
+
+#[test]
+fn keeps_order_of_many_futures() {
+    keeps_order_of(100);
+}
+
+#[test]
+fn empty_and_from_iterator() {
+    let empty: Vec<future::Ready<i32>> = Vec::new();
+    assert_eq!(block_on(join_all(empty)), vec![]);
+
+    let fut: JoinAll<_> = (1..=3).map(future::ready).collect();
+    assert_eq!(block_on(fut), vec![1, 2, 3]);
+}
@@ -0,0 +1,61 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot;
+use futures::future::{join_all_buffered, FutureExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+use std::cell::Cell;
+
+#[test]
+fn keeps_input_order() {
+    let lw = noop_local_waker_ref();
+    let (senders, receivers): (Vec<_>, Vec<_>) =
+        (0..3).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut senders = senders.into_iter().map(Some).collect::<Vec<_>>();
+
+    let mut fut = join_all_buffered(receivers, 3);
+    assert!(fut.poll_unpin(lw).is_pending());
+
+    for &i in &[2, 0, 1] {
+        senders[i].take().unwrap().send(i as i32).unwrap();
+    }
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(vec![Ok(0), Ok(1), Ok(2)]));
+}
+
+#[test]
+fn limits_futures_in_flight() {
+    let lw = noop_local_waker_ref();
+    let created = Cell::new(0);
+    let mut senders = Vec::new();
+    let mut receivers = Vec::new();
+    for _ in 0..4 {
+        let (tx, rx) = oneshot::channel::<()>();
+        senders.push(tx);
+        receivers.push(rx);
+    }
+
+    // The futures are only created when there's room for them
+    let futures = receivers.into_iter().map(|rx| {
+        created.set(created.get() + 1);
+        rx
+    });
+    let mut fut = join_all_buffered(futures, 2);
+    assert!(fut.poll_unpin(lw).is_pending());
+    assert_eq!(created.get(), 2);
+
+    senders.remove(0).send(()).unwrap();
+    assert!(fut.poll_unpin(lw).is_pending());
+    assert_eq!(created.get(), 3);
+
+    for tx in senders {
+        tx.send(()).unwrap();
+    }
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(vec![Ok(()); 4]));
+    assert_eq!(created.get(), 4);
+}
+
+#[test]
+#[should_panic]
+fn zero_max_panics() {
+    let _ = join_all_buffered(Vec::<oneshot::Receiver<()>>::new(), 0);
+}
@@ -0,0 +1,30 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::future::{self, FutureExt, TryFutureExt};
+
+#[derive(Debug, PartialEq)]
+struct Meters(u32);
+
+impl From<u32> for Meters {
+    fn from(x: u32) -> Meters {
+        Meters(x)
+    }
+}
+
+#[test]
+fn map_into() {
+    assert_eq!(block_on(future::ready(5u32).map_into::<Meters>()), Meters(5));
+
+    let fut: future::MapInto<_, Option<&str>> = future::ready("a").map_into();
+    assert_eq!(block_on(fut), Some("a"));
+}
+
+#[test]
+fn err_into() {
+    let fut = future::err::<(), u32>(3).err_into::<Meters>();
+    assert_eq!(block_on(fut), Err(Meters(3)));
+
+    let fut = future::ok::<u8, u32>(1).err_into::<Meters>();
+    assert_eq!(block_on(fut), Ok(1));
+}
@@ -0,0 +1,54 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::block_on_stream;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+
+#[test]
+fn alternates_between_ready_streams() {
+    let merged = stream::repeat(1).take(3).merge(stream::repeat(2).take(3));
+    let items: Vec<_> = block_on_stream(merged).collect();
+    assert_eq!(items, vec![1, 2, 1, 2, 1, 2]);
+}
+
+#[test]
+fn ends_when_both_streams_end() {
+    let (tx1, rx1) = mpsc::unbounded();
+    let (tx2, rx2) = mpsc::unbounded();
+    let mut merged = rx1.merge(rx2);
+    let lw = &noop_local_waker_ref();
+
+    tx1.unbounded_send(1).unwrap();
+    tx1.unbounded_send(2).unwrap();
+    assert_eq!(merged.poll_next_unpin(lw), Poll::Ready(Some(1)));
+
+    tx2.unbounded_send(3).unwrap();
+    assert_eq!(merged.poll_next_unpin(lw), Poll::Ready(Some(3)));
+    assert_eq!(merged.poll_next_unpin(lw), Poll::Ready(Some(2)));
+
+    drop(tx1);
+    assert!(merged.poll_next_unpin(lw).is_pending());
+
+    tx2.unbounded_send(4).unwrap();
+    drop(tx2);
+    assert_eq!(merged.poll_next_unpin(lw), Poll::Ready(Some(4)));
+    assert_eq!(merged.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn merge_passes_errors_through() {
+    let stream1 = stream::iter(vec![Ok(1), Err(2), Ok(3)]);
+    let stream2 = stream::iter(vec![Ok::<i32, i32>(4)]);
+    let items: Vec<_> = block_on_stream(stream1.merge(stream2)).collect();
+    assert_eq!(items, vec![Ok(1), Ok(4), Err(2), Ok(3)]);
+}
+
+#[test]
+fn try_merge_ends_after_first_error() {
+    let stream1 = stream::iter(vec![Ok(1), Ok(2), Ok(3)]);
+    let stream2 = stream::iter(vec![Err::<i32, i32>(4), Ok(5)]);
+    let items: Vec<_> = block_on_stream(stream1.try_merge(stream2)).collect();
+    assert_eq!(items, vec![Ok(1), Err(4)]);
+}
@@ -0,0 +1,49 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot;
+use futures::future::{self, FutureExt};
+use futures::task::{Poll, Waker};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn ready_future() {
+    assert_eq!(future::ready(1).now_or_never(), Some(1));
+    assert_eq!(future::lazy(|_| 2).now_or_never(), Some(2));
+}
+
+#[test]
+fn pending_future_is_dropped() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    assert_eq!(rx.now_or_never(), None);
+    assert!(tx.is_canceled());
+}
+
+#[test]
+fn registered_waker_is_noop() {
+    let slot: Arc<Mutex<Option<Waker>>> = Arc::new(Mutex::new(None));
+    let slot2 = slot.clone();
+    let fut = future::poll_fn(move |lw| {
+        *slot2.lock().unwrap() = Some(lw.clone().into_waker());
+        Poll::Pending::<()>
+    });
+
+    assert_eq!(fut.now_or_never(), None);
+    // The future, along with its handle to the slot, is gone
+    assert_eq!(Arc::strong_count(&slot), 1);
+
+    // The waker it registered outlives it, and waking it does nothing
+    let waker = slot.lock().unwrap().take().unwrap();
+    waker.clone().wake();
+    waker.wake();
+}
+
+#[test]
+fn maybe_done_output_mut() {
+    let mut fut = future::maybe_done(future::ready(vec![1]));
+    assert_eq!((&mut fut).now_or_never(), Some(()));
+
+    let mut fut = std::pin::Pin::new(&mut fut);
+    fut.as_mut().output_mut().unwrap().push(2);
+    assert_eq!(fut.as_mut().take_output(), Some(vec![1, 2]));
+    assert!(fut.as_mut().output_mut().is_none());
+}
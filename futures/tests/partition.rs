@@ -0,0 +1,51 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::{noop_local_waker_ref, WakeCounter};
+
+#[test]
+fn buffers_one_item_per_half() {
+    let (mut left, mut right) = stream::iter(vec![1, 3, 2, 4]).partition(|x| x % 2 == 0);
+    let lw = &noop_local_waker_ref();
+
+    // `1` and `3` are both for `right`, so `left` can only buffer one of them
+    assert!(left.poll_next_unpin(lw).is_pending());
+    assert_eq!(right.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert!(left.poll_next_unpin(lw).is_pending());
+    assert_eq!(right.poll_next_unpin(lw), Poll::Ready(Some(3)));
+    assert_eq!(left.poll_next_unpin(lw), Poll::Ready(Some(2)));
+    assert_eq!(left.poll_next_unpin(lw), Poll::Ready(Some(4)));
+    assert_eq!(left.poll_next_unpin(lw), Poll::Ready(None));
+    assert_eq!(right.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn wakes_half_when_item_arrives() {
+    let (tx, rx) = mpsc::unbounded();
+    let (mut left, mut right) = rx.partition(|x: &i32| *x < 10);
+    let left_counter = WakeCounter::new();
+    let right_counter = WakeCounter::new();
+
+    assert!(left.poll_next_unpin(left_counter.local_waker()).is_pending());
+    assert!(right.poll_next_unpin(right_counter.local_waker()).is_pending());
+
+    // `right` polled the channel last, so it pulls the item for `left`
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(right_counter.count(), 1);
+    assert!(right.poll_next_unpin(right_counter.local_waker()).is_pending());
+    assert_eq!(left_counter.count(), 1);
+    assert_eq!(left.poll_next_unpin(left_counter.local_waker()), Poll::Ready(Some(1)));
+}
+
+#[test]
+fn dropping_half_discards_its_items() {
+    let (left, mut right) = stream::iter(vec![1, 10, 2, 20]).partition(|x| *x < 10);
+    let lw = &noop_local_waker_ref();
+
+    drop(left);
+    assert_eq!(right.poll_next_unpin(lw), Poll::Ready(Some(10)));
+    assert_eq!(right.poll_next_unpin(lw), Poll::Ready(Some(20)));
+    assert_eq!(right.poll_next_unpin(lw), Poll::Ready(None));
+}
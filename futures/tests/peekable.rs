@@ -0,0 +1,97 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+use std::pin::Pin;
+
+#[test]
+fn peek_mut_modifies_next_item() {
+    let mut stream = stream::iter(vec![1, 2]).peekable();
+
+    if let Some(item) = block_on(stream.peek_mut()) {
+        *item *= 10;
+    }
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![10, 2]);
+}
+
+#[test]
+fn peek_mut_waits_for_item() {
+    let (tx, rx) = mpsc::unbounded();
+    let mut stream = rx.peekable();
+    let lw = &noop_local_waker_ref();
+
+    {
+        let mut peek = stream.peek_mut();
+        assert!(peek.poll_unpin(lw).is_pending());
+        tx.unbounded_send(1).unwrap();
+        match peek.poll_unpin(lw) {
+            Poll::Ready(Some(item)) => *item += 1,
+            _ => panic!("expected an item"),
+        }
+    }
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+
+    drop(tx);
+    assert_eq!(block_on(stream.peek_mut()), None);
+}
+
+#[test]
+fn next_if_keeps_rejected_item() {
+    let mut stream = stream::iter(vec![1, 2, 3]).peekable();
+
+    assert_eq!(block_on(stream.next_if(|x| *x == 1)), Some(1));
+    assert_eq!(block_on(stream.next_if(|x| *x == 1)), None);
+    assert_eq!(block_on(stream.next_if_eq(&2)), Some(2));
+    assert_eq!(block_on(stream.next_if_eq(&2)), None);
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![3]);
+}
+
+#[test]
+fn next_if_waits_for_item() {
+    let (tx, rx) = mpsc::unbounded();
+    let mut stream = rx.peekable();
+    let lw = &noop_local_waker_ref();
+
+    {
+        let mut next = stream.next_if(|x| *x > 0);
+        assert!(next.poll_unpin(lw).is_pending());
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(next.poll_unpin(lw), Poll::Ready(Some(1)));
+    }
+
+    drop(tx);
+    assert_eq!(block_on(stream.next_if(|x| *x > 0)), None);
+}
+
+#[test]
+fn peek_keeps_item_until_taken() {
+    let (tx, rx) = mpsc::unbounded();
+    let mut stream = rx.peekable();
+    let lw = &noop_local_waker_ref();
+
+    {
+        let mut pinned = Pin::new(&mut stream);
+        assert_eq!(pinned.peek(lw), Poll::Pending);
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(pinned.peek(lw), Poll::Ready(Some(&1)));
+        assert_eq!(pinned.peek(lw), Poll::Ready(Some(&1)));
+    }
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+
+    drop(tx);
+    {
+        let mut pinned = Pin::new(&mut stream);
+        assert_eq!(pinned.peek(lw), Poll::Ready(Some(&2)));
+    }
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+
+    // Peeking at the end of the stream
+    let mut pinned = Pin::new(&mut stream);
+    assert_eq!(pinned.peek(lw), Poll::Ready(None));
+    assert_eq!(pinned.peek(lw), Poll::Ready(None));
+}
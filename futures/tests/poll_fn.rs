@@ -0,0 +1,29 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::future::{poll_fn, FutureExt, PollFn};
+use futures::task::{LocalWaker, Poll};
+use futures_test::task::noop_local_waker_ref;
+
+// Ready on the second poll
+fn ready_after_one_poll(lw: &LocalWaker) -> Poll<i32> {
+    thread_local!(static POLLED: std::cell::Cell<bool> = std::cell::Cell::new(false));
+    if POLLED.with(|p| p.replace(true)) {
+        Poll::Ready(7)
+    } else {
+        lw.wake();
+        Poll::Pending
+    }
+}
+
+struct Holder {
+    fut: PollFn<fn(&LocalWaker) -> Poll<i32>>,
+}
+
+#[test]
+fn stored_in_named_field() {
+    let mut holder = Holder { fut: poll_fn(ready_after_one_poll) };
+    let lw = noop_local_waker_ref();
+
+    assert_eq!(holder.fut.poll_unpin(lw), Poll::Pending);
+    assert_eq!(holder.fut.poll_unpin(lw), Poll::Ready(7));
+}
@@ -0,0 +1,65 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::{mpsc, oneshot};
+use futures::executor::block_on;
+use futures::future::{self, poll_immediate, FutureExt};
+use futures::stream::StreamExt;
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+
+#[test]
+fn returns_pending_future() {
+    let (tx, rx) = oneshot::channel::<i32>();
+
+    let mut fut = poll_immediate(rx);
+    assert_eq!(fut.poll_unpin(noop_local_waker_ref()), Poll::Ready(None));
+    let rx = fut.into_inner().unwrap();
+
+    tx.send(1).unwrap();
+    let mut fut = poll_immediate(rx);
+    assert_eq!(block_on(&mut fut), Some(Ok(1)));
+    assert!(fut.into_inner().is_none());
+}
+
+#[test]
+fn drains_completed_handles() {
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (_tx2, rx2) = oneshot::channel::<i32>();
+    let mut handles = vec![rx1, rx2];
+    tx1.send(1).unwrap();
+
+    let mut done = Vec::new();
+    let mut i = 0;
+    while i < handles.len() {
+        match block_on(poll_immediate(&mut handles[i])) {
+            Some(output) => {
+                handles.remove(i);
+                done.push(output);
+            }
+            None => i += 1,
+        }
+    }
+    assert_eq!(done, vec![Ok(1)]);
+    assert_eq!(handles.len(), 1);
+}
+
+#[test]
+#[should_panic]
+fn poll_after_completion_panics() {
+    let mut fut = poll_immediate(future::ready(1));
+    assert_eq!(fut.poll_unpin(noop_local_waker_ref()), Poll::Ready(Some(1)));
+    let _ = fut.poll_unpin(noop_local_waker_ref());
+}
+
+#[test]
+fn stream() {
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let mut stream = poll_immediate(rx);
+
+    assert_eq!(block_on(stream.next()), Some(Poll::Pending));
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(block_on(stream.next()), Some(Poll::Ready(1)));
+    drop(tx);
+    assert_eq!(block_on(stream.next()), None);
+    assert_eq!(block_on(stream.next()), None);
+}
@@ -0,0 +1,31 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::stream::StreamExt;
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+
+#[test]
+fn yields_available_items_without_waiting() {
+    let (tx, rx) = mpsc::unbounded();
+    let mut stream = rx.ready_chunks(3);
+    let lw = &noop_local_waker_ref();
+
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(vec![1, 2])));
+
+    for i in 3..8 {
+        tx.unbounded_send(i).unwrap();
+    }
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(vec![3, 4, 5])));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(vec![6, 7])));
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    tx.unbounded_send(8).unwrap();
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(vec![8])));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
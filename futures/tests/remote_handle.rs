@@ -0,0 +1,112 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot;
+use futures::executor::ThreadPool;
+use futures::future::{self, Future, FutureExt};
+use futures::task::SpawnExt;
+use std::sync::mpsc;
+use std::time::Duration;
+
+struct SendOnDrop(mpsc::Sender<()>);
+
+impl Drop for SendOnDrop {
+    fn drop(&mut self) {
+        let _ = self.0.send(());
+    }
+}
+
+// Reports every poll of `fut` on `polled`, so that a test can wait until the
+// pool is waiting on it
+fn report_polls<Fut>(mut fut: Fut, polled: mpsc::Sender<()>) -> impl Future<Output = Fut::Output>
+    where Fut: Future + Unpin,
+{
+    future::poll_fn(move |lw| {
+        let _ = polled.send(());
+        fut.poll_unpin(lw)
+    })
+}
+
+#[test]
+fn drop_cancels_pending_future() {
+    let mut pool = ThreadPool::new().unwrap();
+    let (_tx, rx) = oneshot::channel::<()>();
+    let (dropped_tx, dropped_rx) = mpsc::channel();
+    let (polled_tx, polled_rx) = mpsc::channel();
+
+    let guard = SendOnDrop(dropped_tx);
+    let future = report_polls(rx, polled_tx).map(move |_| drop(guard));
+    let handle = pool.spawn_with_handle(future).unwrap();
+
+    // Wait for the pool to start waiting on the channel, which never completes
+    polled_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    drop(handle);
+
+    assert!(dropped_rx.recv_timeout(Duration::from_secs(5)).is_ok());
+}
+
+#[test]
+fn forget_keeps_future_running() {
+    let mut pool = ThreadPool::new().unwrap();
+    let (tx, rx) = oneshot::channel::<i32>();
+    let (done_tx, done_rx) = mpsc::channel();
+
+    let handle = pool.spawn_with_handle(rx.map(move |res| {
+        done_tx.send(res.unwrap()).unwrap();
+    })).unwrap();
+    handle.forget();
+
+    tx.send(5).unwrap();
+    assert_eq!(done_rx.recv_timeout(Duration::from_secs(5)), Ok(5));
+}
+
+#[test]
+fn shared_handle_cancels_after_last_clone_is_dropped() {
+    let mut pool = ThreadPool::new().unwrap();
+    let (_tx, rx) = oneshot::channel::<()>();
+    let (dropped_tx, dropped_rx) = mpsc::channel();
+    let (polled_tx, polled_rx) = mpsc::channel();
+
+    let guard = SendOnDrop(dropped_tx);
+    let future = report_polls(rx, polled_tx).map(move |_| drop(guard));
+    let handle = pool.spawn_with_handle(future).unwrap();
+    let handle1 = handle.shared_handle();
+    let handle2 = handle1.clone();
+
+    polled_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    drop(handle1);
+    assert!(dropped_rx.recv_timeout(Duration::from_millis(50)).is_err());
+
+    drop(handle2);
+    assert!(dropped_rx.recv_timeout(Duration::from_secs(5)).is_ok());
+}
+
+#[test]
+fn shared_handle_clone_after_completion() {
+    let mut pool = ThreadPool::new().unwrap();
+    let (tx, rx) = oneshot::channel::<i32>();
+
+    let handle = pool.spawn_with_handle(rx.map(|res| res.unwrap())).unwrap();
+    let handle1 = handle.shared_handle();
+    tx.send(7).unwrap();
+    assert_eq!(pool.run(handle1.clone()), 7);
+
+    let handle2 = handle1.clone();
+    drop(handle1);
+    assert_eq!(pool.run(handle2), 7);
+}
+
+#[test]
+fn spawn_blocking_runs_after_handle_is_dropped() {
+    let mut pool = ThreadPool::new().unwrap();
+    let (start_tx, start_rx) = mpsc::channel::<()>();
+    let (done_tx, done_rx) = mpsc::channel();
+
+    let handle = pool.spawn_blocking(move || {
+        start_rx.recv().unwrap();
+        done_tx.send(3).unwrap();
+    }).unwrap();
+    drop(handle);
+
+    start_tx.send(()).unwrap();
+    assert_eq!(done_rx.recv_timeout(Duration::from_secs(5)), Ok(3));
+}
@@ -0,0 +1,32 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on_stream;
+use futures::future;
+use futures::stream::{self, StreamExt};
+use futures_test::future::FutureTestExt;
+use std::cell::Cell;
+
+#[test]
+fn holds_state_across_pending() {
+    let stream = stream::iter(vec![1, 2, 3, 4]).scan_async(10, |acc, x| {
+        *acc += x;
+        future::ready(Some(*acc)).pending_once()
+    });
+    let items: Vec<_> = block_on_stream(Box::pinned(stream)).collect();
+    assert_eq!(items, vec![11, 13, 16, 20]);
+}
+
+#[test]
+fn none_ends_stream_without_pulling_more() {
+    let pulled = Cell::new(0);
+    let stream = stream::iter(1..=10)
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .scan_async((), |(), x| future::ready(if x < 3 { Some(x) } else { None }));
+    let mut stream = block_on_stream(Box::pinned(stream));
+
+    assert_eq!(stream.next(), Some(1));
+    assert_eq!(stream.next(), Some(2));
+    assert_eq!(stream.next(), None);
+    assert_eq!(stream.next(), None);
+    assert_eq!(pulled.get(), 3);
+}
@@ -0,0 +1,55 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::future;
+use futures::stream::{self, SelectAll, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+
+#[test]
+fn keeps_polling_after_a_stream_ends() {
+    let (tx1, rx1) = mpsc::unbounded();
+    let (tx2, rx2) = mpsc::unbounded();
+    let mut set = stream::select_all(vec![rx1, rx2]);
+    let lw = &noop_local_waker_ref();
+
+    drop(tx1);
+    assert!(set.poll_next_unpin(lw).is_pending());
+    assert_eq!(set.len(), 1);
+
+    tx2.unbounded_send(2).unwrap();
+    assert_eq!(set.poll_next_unpin(lw), Poll::Ready(Some(2)));
+    drop(tx2);
+    assert_eq!(set.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(set.is_empty());
+}
+
+#[test]
+fn push_after_end_rearms() {
+    let mut set = SelectAll::new();
+    let lw = &noop_local_waker_ref();
+    assert_eq!(set.poll_next_unpin(lw), Poll::Ready(None));
+
+    set.push(stream::iter(vec![1]));
+    assert_eq!(set.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(set.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn push_through_handle_while_consumed() {
+    let set = stream::select_all(vec![stream::iter(vec![1, 2])]);
+    let handle = set.handle();
+    let mut items = Vec::new();
+
+    block_on(set.for_each(|x| {
+        if x == 1 {
+            handle.push(stream::iter(vec![10, 20])).unwrap();
+        }
+        items.push(x);
+        future::ready(())
+    }));
+
+    items.sort();
+    assert_eq!(items, vec![1, 2, 10, 20]);
+}
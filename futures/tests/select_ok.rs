@@ -0,0 +1,50 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{select_ok, FutureExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+
+#[test]
+fn ignores_errors_until_success() {
+    let lw = noop_local_waker_ref();
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+    let (tx3, rx3) = oneshot::channel::<i32>();
+
+    let mut fut = select_ok(vec![rx1, rx2, rx3]);
+    assert!(fut.poll_unpin(lw).is_pending());
+
+    // A canceled receiver fails, which is ignored
+    drop(tx1);
+    assert!(fut.poll_unpin(lw).is_pending());
+
+    tx3.send(3).unwrap();
+    let (value, mut rest) = match fut.poll_unpin(lw) {
+        Poll::Ready(Ok(output)) => output,
+        other => panic!("unexpected poll result: {:?}", other),
+    };
+    assert_eq!(value, 3);
+
+    // The remaining future can still be driven
+    assert_eq!(rest.len(), 1);
+    tx2.send(2).unwrap();
+    assert_eq!(block_on(rest.pop().unwrap()), Ok(2));
+}
+
+#[test]
+fn returns_last_error() {
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+
+    drop(tx2);
+    drop(tx1);
+    assert!(block_on(select_ok(vec![rx1, rx2])).is_err());
+}
+
+#[test]
+#[should_panic]
+fn empty_panics() {
+    let _ = select_ok(Vec::<oneshot::Receiver<()>>::new());
+}
@@ -0,0 +1,48 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, select_ok_all, FutureExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+
+#[test]
+fn collects_errors_in_completion_order() {
+    let lw = noop_local_waker_ref();
+    let (senders, receivers): (Vec<_>, Vec<_>) = (0..3)
+        .map(|_| oneshot::channel::<Result<(), &str>>())
+        .unzip();
+
+    let mut fut = select_ok_all(receivers.into_iter().map(|rx| rx.map(Result::unwrap)));
+    assert!(fut.poll_unpin(lw).is_pending());
+
+    let mut senders = senders.into_iter().map(Some).collect::<Vec<_>>();
+    for &(i, e) in &[(2, "c"), (0, "a")] {
+        senders[i].take().unwrap().send(Err(e)).unwrap();
+        assert!(fut.poll_unpin(lw).is_pending());
+    }
+    senders[1].take().unwrap().send(Err("b")).unwrap();
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(Err(vec!["c", "a", "b"])));
+}
+
+#[test]
+fn success_cancels_other_futures() {
+    let lw = noop_local_waker_ref();
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+    let (tx3, rx3) = oneshot::channel::<i32>();
+
+    let mut fut = select_ok_all(vec![rx1, rx2, rx3]);
+    assert!(fut.poll_unpin(lw).is_pending());
+
+    drop(tx1);
+    tx2.send(2).unwrap();
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(Ok(2)));
+    assert!(tx3.is_canceled());
+}
+
+#[test]
+fn empty_resolves_to_no_errors() {
+    let empty: Vec<future::Ready<Result<(), ()>>> = Vec::new();
+    assert_eq!(block_on(select_ok_all(empty)), Err(vec![]));
+}
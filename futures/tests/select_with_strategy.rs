@@ -0,0 +1,45 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::block_on_stream;
+use futures::stream::{self, PollNext, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+
+#[test]
+fn polls_other_stream_when_preferred_is_pending() {
+    let (tx1, rx1) = mpsc::unbounded();
+    let (tx2, rx2) = mpsc::unbounded();
+    let mut stream = stream::select_with_strategy(rx1, rx2, |_: &mut ()| PollNext::Left);
+    let lw = &noop_local_waker_ref();
+
+    tx2.unbounded_send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+
+    tx2.unbounded_send(4).unwrap();
+    tx1.unbounded_send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(4)));
+
+    drop(tx1);
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    drop(tx2);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn strategy_state_is_kept() {
+    // Polls the left stream first twice as often as the right one
+    let stream = stream::select_with_strategy(
+        stream::repeat(1).take(4),
+        stream::repeat(2).take(4),
+        |count: &mut u32| {
+            *count += 1;
+            if *count % 3 == 0 { PollNext::Right } else { PollNext::Left }
+        },
+    );
+    assert_eq!(stream.state(), &0);
+
+    let items: Vec<_> = block_on_stream(stream).collect();
+    assert_eq!(items, vec![1, 1, 2, 1, 1, 2, 2, 2]);
+}
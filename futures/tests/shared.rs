@@ -115,3 +115,71 @@ fn peek() {
         assert_eq!(f2.peek().unwrap(), Ok(42));
     }
 }
+
+#[test]
+fn clone_drives_future_after_original_is_dropped() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let f1 = rx.shared();
+    let f2 = f1.clone();
+
+    drop(f1);
+    tx.send(6).unwrap();
+    assert_eq!(block_on(f2), Ok(6));
+}
+
+#[test]
+fn error_is_shared_by_all_clones() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let f1 = rx.shared();
+    let f2 = f1.clone();
+
+    drop(tx);
+    assert_eq!(block_on(f1), Err(oneshot::Canceled));
+    assert_eq!(block_on(f2), Err(oneshot::Canceled));
+}
+
+#[test]
+fn peek_races_with_completion() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let f = rx.shared();
+
+    let peekers = (0..4).map(|_| {
+        let f = f.clone();
+        thread::spawn(move || {
+            // Each peek sees either nothing or the complete output
+            loop {
+                match f.peek() {
+                    None => thread::yield_now(),
+                    Some(output) => {
+                        assert_eq!(output, Ok(42));
+                        break;
+                    }
+                }
+            }
+        })
+    }).collect::<Vec<_>>();
+
+    tx.send(42).unwrap();
+    assert_eq!(block_on(f), Ok(42));
+    for peeker in peekers {
+        peeker.join().unwrap();
+    }
+}
+
+#[test]
+fn downgrade_and_upgrade() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let f1 = rx.shared();
+    let weak = f1.downgrade();
+
+    let f2 = weak.upgrade().unwrap();
+    tx.send(6).unwrap();
+    assert_eq!(block_on(f1), Ok(6));
+
+    // The output stays available while a strong reference is left
+    assert_eq!(weak.upgrade().unwrap().peek(), Some(Ok(6)));
+
+    drop(f2);
+    assert!(weak.upgrade().is_none());
+    assert!(weak.clone().upgrade().is_none());
+}
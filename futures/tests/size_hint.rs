@@ -0,0 +1,123 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::stream::{self, Stream, StreamExt};
+use futures::task::{LocalWaker, Poll};
+use futures_test::task::noop_local_waker_ref;
+use std::pin::Pin;
+
+#[test]
+fn sources() {
+    assert_eq!(stream::iter(vec![1, 2, 3]).size_hint(), (3, Some(3)));
+    assert_eq!(stream::iter((0..).filter(|_| true)).size_hint(), (0, None));
+    assert_eq!(stream::empty::<()>().size_hint(), (0, Some(0)));
+    assert_eq!(stream::once(future::ready(1)).size_hint(), (1, Some(1)));
+    assert_eq!(stream::repeat(1).size_hint(), (usize::max_value(), None));
+}
+
+#[test]
+fn pass_through() {
+    let iter = || stream::iter(vec![1, 2, 3]);
+    assert_eq!(iter().map(|x| x + 1).size_hint(), (3, Some(3)));
+    assert_eq!(iter().then(future::ready).size_hint(), (3, Some(3)));
+    assert_eq!(iter().inspect(|_| ()).size_hint(), (3, Some(3)));
+    assert_eq!(iter().enumerate().size_hint(), (3, Some(3)));
+    assert_eq!(iter().fuse().size_hint(), (3, Some(3)));
+    assert_eq!(iter().peekable().size_hint(), (3, Some(3)));
+}
+
+#[test]
+fn filters_keep_upper_bound() {
+    let iter = || stream::iter(vec![1, 2, 3]);
+    assert_eq!(
+        iter().filter(|x| future::ready(*x > 1)).size_hint(),
+        (0, Some(3)),
+    );
+    assert_eq!(
+        iter().filter_map(|x| future::ready(Some(x))).size_hint(),
+        (0, Some(3)),
+    );
+    assert_eq!(
+        iter().take_while(|_| future::ready(true)).size_hint(),
+        (0, Some(3)),
+    );
+    assert_eq!(
+        iter().skip_while(|_| future::ready(true)).size_hint(),
+        (0, Some(3)),
+    );
+    assert_eq!(iter().take_until(future::empty::<()>()).size_hint(), (0, Some(3)));
+}
+
+#[test]
+fn take_and_skip() {
+    let iter = || stream::iter(vec![1, 2, 3]);
+    assert_eq!(iter().take(2).size_hint(), (2, Some(2)));
+    assert_eq!(iter().take(5).size_hint(), (3, Some(3)));
+    assert_eq!(iter().take(0).size_hint(), (0, Some(0)));
+    assert_eq!(stream::repeat(1).take(5).size_hint(), (5, Some(5)));
+    assert_eq!(iter().skip(2).size_hint(), (1, Some(1)));
+    assert_eq!(iter().skip(5).size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn chain_and_zip() {
+    let iter = |n| stream::iter(0..n);
+    assert_eq!(iter(2).chain(iter(3)).size_hint(), (5, Some(5)));
+    assert_eq!(
+        stream::repeat(1).chain(stream::repeat(2)).size_hint(),
+        (usize::max_value(), None),
+    );
+    assert_eq!(iter(2).zip(iter(3)).size_hint(), (2, Some(2)));
+    assert_eq!(iter(2).zip(stream::repeat(1)).size_hint(), (2, Some(2)));
+}
+
+#[test]
+fn chunks() {
+    let iter = |n| stream::iter(0..n);
+    assert_eq!(iter(6).chunks(2).size_hint(), (3, Some(3)));
+    assert_eq!(iter(7).chunks(2).size_hint(), (4, Some(4)));
+    assert_eq!(iter(0).chunks(2).size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn updates_while_consumed() {
+    let mut stream = stream::iter(vec![1, 2, 3]).map(|x| x * 2).take(2);
+    assert_eq!(stream.size_hint(), (2, Some(2)));
+    assert_eq!(block_on(stream.next()), Some(2));
+    assert_eq!(stream.size_hint(), (1, Some(1)));
+    assert_eq!(block_on(stream.next()), Some(4));
+    assert_eq!(stream.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn collect_with_size_hints() {
+    let items: Vec<i32> = block_on(stream::iter(0..100).collect());
+    assert_eq!(items, (0..100).collect::<Vec<_>>());
+    assert!(items.capacity() >= items.len());
+
+    let items: String = block_on(stream::iter(vec!['a', 'b', 'c']).collect());
+    assert_eq!(items, "abc");
+    assert!(items.capacity() >= items.len());
+
+    // A huge lower bound doesn't make the collection allocate up front
+    struct Endless(Option<i32>);
+
+    impl Stream for Endless {
+        type Item = i32;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &LocalWaker) -> Poll<Option<i32>> {
+            match self.0.take() {
+                Some(item) => Poll::Ready(Some(item)),
+                None => Poll::Pending,
+            }
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (usize::max_value(), None)
+        }
+    }
+
+    let mut collect = Endless(Some(1)).collect::<Vec<_>>();
+    assert!(collect.poll_unpin(noop_local_waker_ref()).is_pending());
+}
@@ -0,0 +1,68 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::{mpsc, oneshot};
+use futures::future::FutureExt;
+use futures::stream::StreamExt;
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn discards_stale_results() {
+    // Each request completes once its sender is used
+    let requests = Rc::new(RefCell::new(Vec::new()));
+    let requests2 = requests.clone();
+    let (tx, rx) = mpsc::unbounded();
+    let mut stream = rx.switch_map(move |query: &'static str| {
+        let (resp_tx, resp_rx) = oneshot::channel::<String>();
+        requests2.borrow_mut().push((query, resp_tx));
+        resp_rx.map(|res| res.unwrap_or_else(|_| "cancelled".to_string()))
+    });
+    let lw = &noop_local_waker_ref();
+
+    tx.unbounded_send("a").unwrap();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    let (query_a, resp_a) = requests.borrow_mut().remove(0);
+    assert_eq!(query_a, "a");
+
+    // The request for "a" is dropped once "ab" arrives
+    tx.unbounded_send("ab").unwrap();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert!(resp_a.send("stale".to_string()).is_err());
+
+    // A new query and a response that are ready at the same time: the new
+    // query wins
+    let (_, resp_ab) = requests.borrow_mut().remove(0);
+    resp_ab.send("ab results".to_string()).unwrap();
+    tx.unbounded_send("abc").unwrap();
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    let (query_abc, resp_abc) = requests.borrow_mut().remove(0);
+    assert_eq!(query_abc, "abc");
+    resp_abc.send("abc results".to_string()).unwrap();
+    assert_eq!(
+        stream.poll_next_unpin(lw),
+        Poll::Ready(Some("abc results".to_string())),
+    );
+
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn finishes_last_future_after_stream_ends() {
+    let (tx, rx) = mpsc::unbounded();
+    let (resp_tx, resp_rx) = oneshot::channel::<i32>();
+    let mut resp_rx = Some(resp_rx);
+    let mut stream = rx.switch_map(move |()| resp_rx.take().unwrap());
+    let lw = &noop_local_waker_ref();
+
+    tx.unbounded_send(()).unwrap();
+    drop(tx);
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    resp_tx.send(5).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Ok(5))));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
@@ -0,0 +1,97 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::{mpsc, oneshot};
+use futures::executor::block_on_stream;
+use futures::future::{self, Future};
+use futures::stream::{self, StreamExt};
+use futures::task::{LocalWaker, Poll};
+use futures_test::task::noop_local_waker_ref;
+use std::pin::Pin;
+use std::rc::Rc;
+
+#[test]
+fn ends_when_future_resolves() {
+    let (tx, rx) = mpsc::unbounded();
+    let (stop_tx, stop_rx) = oneshot::channel::<()>();
+    let mut stream = rx.take_until(stop_rx);
+    let lw = &noop_local_waker_ref();
+
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+
+    assert!(!stream.is_stopped());
+    drop(stop_tx);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(stream.is_stopped());
+    assert_eq!(stream.take_result(), Some(Err(oneshot::Canceled)));
+    assert_eq!(stream.take_result(), None);
+
+    assert!(tx.unbounded_send(2).is_err());
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn prefers_item_ready_in_same_poll() {
+    let stream = stream::iter(vec![1, 2, 3]).take_until(future::ready(()));
+    let items: Vec<_> = block_on_stream(stream).collect();
+    assert_eq!(items, vec![1]);
+}
+
+#[test]
+fn ends_with_stream() {
+    let stream = stream::iter(vec![1, 2]).take_until(future::empty::<()>());
+    let items: Vec<_> = block_on_stream(stream).collect();
+    assert_eq!(items, vec![1, 2]);
+}
+
+struct Holder(Rc<()>);
+
+impl Future for Holder {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _lw: &LocalWaker) -> Poll<()> {
+        Poll::Ready(())
+    }
+}
+
+#[test]
+fn drops_future_once_resolved() {
+    let held = Rc::new(());
+    let (_tx, rx) = mpsc::unbounded::<i32>();
+    let mut stream = rx.take_until(Holder(held.clone()));
+    let lw = &noop_local_waker_ref();
+
+    assert_eq!(Rc::strong_count(&held), 2);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert_eq!(Rc::strong_count(&held), 1);
+}
+
+#[test]
+fn drops_stream_once_stopped() {
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let (stop_tx, stop_rx) = oneshot::channel::<()>();
+    let mut stream = rx.take_until(stop_rx);
+    let lw = &noop_local_waker_ref();
+
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert!(stream.get_ref().is_some());
+    stop_tx.send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+
+    // The receiver is gone, so the channel is closed
+    assert!(stream.get_ref().is_none());
+    assert!(tx.unbounded_send(1).is_err());
+    assert!(stream.into_inner().is_none());
+}
+
+#[test]
+fn keeps_stream_that_ended_by_itself() {
+    let mut stream = stream::iter(vec![1]).take_until(future::empty::<()>());
+    let lw = &noop_local_waker_ref();
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(!stream.is_stopped());
+    assert!(stream.into_inner().is_some());
+}
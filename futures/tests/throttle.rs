@@ -0,0 +1,76 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot;
+use futures::future::FutureExt;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+#[test]
+fn waits_for_cooldown_between_items() {
+    let pulled = Cell::new(0);
+
+    // Each timer fires once its sender is used
+    let timers = Rc::new(RefCell::new(Vec::new()));
+    let timers2 = timers.clone();
+    let mut stream = stream::iter(1..=3)
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .throttle(move || {
+            let (timer_tx, timer_rx) = oneshot::channel::<()>();
+            timers2.borrow_mut().push(timer_tx);
+            timer_rx.map(|_| ())
+        });
+    let lw = &noop_local_waker_ref();
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(timers.borrow().len(), 1);
+
+    // The source isn't polled during the cooldown
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(pulled.get(), 1);
+
+    timers.borrow_mut().pop().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+
+    timers.borrow_mut().pop().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(3)));
+
+    timers.borrow_mut().pop().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+    assert!(timers.borrow().is_empty());
+}
+
+#[test]
+fn burst_allows_items_back_to_back() {
+    let timers = Rc::new(RefCell::new(Vec::new()));
+    let timers2 = timers.clone();
+    let mut stream = stream::iter(1..=5)
+        .throttle(move || {
+            let (timer_tx, timer_rx) = oneshot::channel::<()>();
+            timers2.borrow_mut().push(timer_tx);
+            timer_rx.map(|_| ())
+        })
+        .burst(2);
+    let lw = &noop_local_waker_ref();
+
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(2)));
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    // Only one timer runs at a time, freeing up one slot when it fires
+    assert_eq!(timers.borrow().len(), 1);
+    timers.borrow_mut().pop().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(3)));
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    timers.borrow_mut().pop().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(4)));
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    timers.borrow_mut().pop().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(5)));
+    timers.borrow_mut().pop().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
@@ -0,0 +1,117 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::{mpsc, oneshot};
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::stream::{Elapsed, StreamExt, TryStreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn stream_timeout_resets_after_each_item() {
+    let (tx, rx) = mpsc::unbounded::<i32>();
+
+    // Each timer fires once its sender is used
+    let timers = Rc::new(RefCell::new(Vec::new()));
+    let timers2 = timers.clone();
+    let mut stream = rx.timeout(move || {
+        let (timer_tx, timer_rx) = oneshot::channel::<()>();
+        timers2.borrow_mut().push(timer_tx);
+        timer_rx.map(|_| ())
+    });
+    let lw = &noop_local_waker_ref();
+
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(timers.borrow().len(), 1);
+    timers.borrow_mut().pop().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Err(Elapsed))));
+
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Ok(1))));
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    assert_eq!(timers.borrow().len(), 1);
+
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn stream_timeout_exempt_first_item() {
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let mut stream = rx.timeout(|| future::ready(())).exempt_first_item();
+    let lw = &noop_local_waker_ref();
+
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Ok(1))));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Err(Elapsed))));
+}
+
+#[test]
+fn future_timeout_drops_future_on_timeout() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let (timer_tx, timer_rx) = oneshot::channel::<()>();
+    let mut future = rx.timeout(timer_rx.map(|_| ()));
+    let lw = &noop_local_waker_ref();
+
+    assert!(future.poll_unpin(lw).is_pending());
+    timer_tx.send(()).unwrap();
+    assert_eq!(future.poll_unpin(lw), Poll::Ready(Err(Elapsed)));
+    assert!(tx.is_canceled());
+}
+
+#[test]
+fn future_timeout_prefers_ready_future() {
+    let future = future::ready(1).timeout(future::ready(()));
+    assert_eq!(block_on(future), Ok(1));
+}
+
+#[test]
+fn future_timeout_recoverable_returns_future() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let (timer_tx, timer_rx) = oneshot::channel::<()>();
+    let mut future = rx.timeout_recoverable(timer_rx.map(|_| ()));
+    let lw = &noop_local_waker_ref();
+
+    assert!(future.poll_unpin(lw).is_pending());
+    timer_tx.send(()).unwrap();
+    let rx = match future.poll_unpin(lw) {
+        Poll::Ready(Err(rx)) => rx,
+        _ => panic!("expected a timeout"),
+    };
+    assert!(!tx.is_canceled());
+
+    tx.send(1).unwrap();
+    let future = rx.timeout_recoverable(future::ready(()));
+    assert_eq!(block_on(future), Ok(Ok(1)));
+}
+
+#[test]
+fn try_stream_timeout_converts_elapsed() {
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        Inner(i32),
+        TimedOut,
+    }
+
+    impl From<Elapsed> for Error {
+        fn from(_: Elapsed) -> Error {
+            Error::TimedOut
+        }
+    }
+
+    let (tx, rx) = mpsc::unbounded();
+    let mut stream = rx.try_timeout(|| future::ready(()));
+    let lw = &noop_local_waker_ref();
+
+    tx.unbounded_send(Ok(1)).unwrap();
+    tx.unbounded_send(Err(Error::Inner(2))).unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Ok(1))));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Err(Error::Inner(2)))));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(Err(Error::TimedOut))));
+
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
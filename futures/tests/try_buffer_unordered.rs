@@ -0,0 +1,65 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot;
+use futures::future::{self, Future, FutureExt};
+use futures::stream::{self, ErrorStrategy, StreamExt, TryStreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+use std::cell::Cell;
+use std::pin::Pin;
+
+fn errors_after_first(strategy: ErrorStrategy) -> (Vec<Poll<Option<Result<i32, i32>>>>, usize) {
+    let pulled = Cell::new(0);
+    let (tx, rx) = oneshot::channel::<i32>();
+    let futures: Vec<Pin<Box<dyn Future<Output = Result<i32, i32>>>>> = vec![
+        rx.map(|res| Ok(res.unwrap())).boxed(),
+        future::ready(Err(1)).boxed(),
+        future::ready(Ok(2)).boxed(),
+    ];
+    let mut stream = stream::iter(futures)
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .map(Ok)
+        .try_buffer_unordered(2)
+        .error_strategy(strategy);
+    let lw = &noop_local_waker_ref();
+
+    let mut polls = vec![stream.poll_next_unpin(lw)];
+    // The receiver is gone if the pending futures were dropped
+    let _ = tx.send(3);
+    polls.push(stream.poll_next_unpin(lw));
+    polls.push(stream.poll_next_unpin(lw));
+    (polls, pulled.get())
+}
+
+#[test]
+fn continue_after_error() {
+    let (polls, pulled) = errors_after_first(ErrorStrategy::Continue);
+    assert_eq!(polls, vec![
+        Poll::Ready(Some(Err(1))),
+        Poll::Ready(Some(Ok(3))),
+        Poll::Ready(Some(Ok(2))),
+    ]);
+    assert_eq!(pulled, 3);
+}
+
+#[test]
+fn drop_pending_after_error() {
+    let (polls, pulled) = errors_after_first(ErrorStrategy::DropPending);
+    assert_eq!(polls, vec![
+        Poll::Ready(Some(Err(1))),
+        Poll::Ready(None),
+        Poll::Ready(None),
+    ]);
+    assert_eq!(pulled, 2);
+}
+
+#[test]
+fn drain_pending_after_error() {
+    let (polls, pulled) = errors_after_first(ErrorStrategy::DrainPending);
+    assert_eq!(polls, vec![
+        Poll::Ready(Some(Err(1))),
+        Poll::Ready(Some(Ok(3))),
+        Poll::Ready(None),
+    ]);
+    assert_eq!(pulled, 2);
+}
@@ -0,0 +1,31 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::future;
+use futures::stream::{self, TryStreamExt};
+
+#[test]
+fn error_from_stream() {
+    let stream = stream::iter(vec![Ok(1), Err("bad item"), Ok(3), Ok(4)]);
+    let mut fold = stream.try_fold(0, |acc, x| future::ready(Ok(acc + x)));
+
+    assert_eq!(block_on(&mut fold), Err("bad item"));
+
+    // The fold keeps its accumulator and can carry on
+    assert_eq!(block_on(&mut fold), Ok(8));
+}
+
+#[test]
+fn error_from_closure() {
+    let stream = stream::iter(vec![Ok(1), Ok(2), Ok(3)]);
+    let mut fold = stream.try_fold(0, |acc, x| {
+        future::ready(if x == 2 { Err("bad accumulator") } else { Ok(acc + x) })
+    });
+
+    assert_eq!(block_on(&mut fold), Err("bad accumulator"));
+
+    // The rest of the stream can still be consumed
+    let mut stream = fold.into_inner();
+    assert_eq!(block_on(stream.try_next()), Ok(Some(3)));
+    assert_eq!(block_on(stream.try_next()), Ok(None));
+}
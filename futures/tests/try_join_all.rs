@@ -0,0 +1,57 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot::{self, Canceled};
+use futures::executor::block_on;
+use futures::future::{self, try_join_all, try_join_all_settled, FutureExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+
+#[test]
+fn keeps_input_order() {
+    let lw = noop_local_waker_ref();
+    let (mut senders, receivers): (Vec<_>, Vec<_>) =
+        (0..3).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut fut = try_join_all(receivers);
+    assert!(fut.poll_unpin(lw).is_pending());
+
+    for i in (0..3).rev() {
+        senders.pop().unwrap().send(i).unwrap();
+    }
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(Ok(vec![0, 1, 2])));
+
+    let futures: Vec<future::Ready<Result<i32, ()>>> = Vec::new();
+    assert_eq!(block_on(try_join_all(futures)), Ok(vec![]));
+}
+
+#[test]
+fn first_error_drops_remaining_futures() {
+    let lw = noop_local_waker_ref();
+    let (mut senders, receivers): (Vec<_>, Vec<_>) =
+        (0..3).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut fut = try_join_all(receivers);
+    assert!(fut.poll_unpin(lw).is_pending());
+
+    // Dropping a sender fails its receiver
+    drop(senders.remove(1));
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(Err(Canceled)));
+    assert!(senders.iter().all(|tx| tx.is_canceled()));
+}
+
+#[test]
+fn settled_reports_completed_outputs() {
+    let lw = noop_local_waker_ref();
+    let (mut senders, receivers): (Vec<_>, Vec<_>) =
+        (0..3).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut fut = try_join_all_settled(receivers);
+    assert!(fut.poll_unpin(lw).is_pending());
+
+    senders.remove(0).send(10).unwrap();
+    assert!(fut.poll_unpin(lw).is_pending());
+
+    drop(senders.remove(1));
+    assert_eq!(
+        fut.poll_unpin(lw),
+        Poll::Ready(Err((Canceled, vec![Some(10), None, None]))),
+    );
+    assert!(senders[0].is_canceled());
+}
@@ -35,3 +35,40 @@ fn unfold1() {
     assert_stream_pending!(stream);
     assert_stream_done!(stream);
 }
+
+#[test]
+fn try_unfold_ends_after_error() {
+    let mut stream = stream::try_unfold(0, |state| {
+        if state <= 1 {
+            future::ready(Ok(Some((state * 2, state + 1)))).pending_once()
+        } else {
+            future::ready(Err("out of items")).pending_once()
+        }
+    });
+
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, Ok(0));
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, Ok(2));
+
+    // The error is yielded once, after which the stream stays done
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, Err("out of items"));
+    assert_stream_done!(stream);
+    assert_stream_done!(stream);
+}
+
+#[test]
+fn try_unfold_ends_on_none() {
+    let mut stream = stream::try_unfold(0, |state| {
+        if state == 0 {
+            future::ready(Ok::<_, ()>(Some((state, state + 1))))
+        } else {
+            future::ready(Ok(None))
+        }
+    });
+
+    assert_stream_next!(stream, Ok(0));
+    assert_stream_done!(stream);
+    assert_stream_done!(stream);
+}
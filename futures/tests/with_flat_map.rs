@@ -0,0 +1,48 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::sink::{Sink, SinkExt};
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+use std::pin::Pin;
+
+#[test]
+fn expands_items() {
+    let (tx, rx) = mpsc::channel(5);
+    let mut tx = tx.with_flat_map(|chunk: Vec<u8>| {
+        stream::iter(chunk.into_iter().map(Ok))
+    });
+
+    block_on(tx.send(vec![1, 2, 3])).unwrap();
+    block_on(tx.send(vec![])).unwrap();
+    block_on(tx.send(vec![4])).unwrap();
+    drop(tx);
+
+    assert_eq!(block_on(rx.collect::<Vec<_>>()), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn waits_for_previous_expansion() {
+    let lw = noop_local_waker_ref();
+    let (tx, mut rx) = mpsc::channel(0);
+    let mut tx = tx.with_flat_map(|n: u32| stream::iter((0..n).map(Ok)));
+
+    assert_eq!(Pin::new(&mut tx).poll_ready(lw), Poll::Ready(Ok(())));
+    Pin::new(&mut tx).start_send(3).unwrap();
+
+    // The channel only has room for a single item, so the rest of the
+    // expansion has to wait for the receiver
+    assert_eq!(Pin::new(&mut tx).poll_ready(lw), Poll::Pending);
+    assert_eq!(rx.poll_next_unpin(lw), Poll::Ready(Some(0)));
+    assert_eq!(Pin::new(&mut tx).poll_ready(lw), Poll::Pending);
+    assert_eq!(rx.poll_next_unpin(lw), Poll::Ready(Some(1)));
+    assert_eq!(Pin::new(&mut tx).poll_ready(lw), Poll::Ready(Ok(())));
+    assert_eq!(rx.poll_next_unpin(lw), Poll::Ready(Some(2)));
+
+    // An empty expansion doesn't send anything
+    Pin::new(&mut tx).start_send(0).unwrap();
+    assert_eq!(Pin::new(&mut tx).poll_ready(lw), Poll::Ready(Ok(())));
+    assert_eq!(rx.poll_next_unpin(lw), Poll::Pending);
+}
@@ -0,0 +1,40 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::{LocalPool, RunOne};
+use futures::future::{self, FutureExt};
+use futures::task::{LocalSpawnExt, Poll};
+use futures_test::task::WakeCounter;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn wakes_and_completes_on_second_poll() {
+    let counter = WakeCounter::new();
+    let lw = &counter.local_waker();
+    let mut fut = future::yield_now();
+
+    assert_eq!(fut.poll_unpin(lw), Poll::Pending);
+    assert_eq!(counter.count(), 1);
+    assert_eq!(fut.poll_unpin(lw), Poll::Ready(()));
+    assert_eq!(counter.count(), 1);
+}
+
+#[test]
+fn lets_other_tasks_run() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut pool = LocalPool::new();
+    let mut spawner = pool.spawner();
+
+    let (log_a1, log_a2, log_b) = (log.clone(), log.clone(), log.clone());
+    spawner.spawn_local(future::lazy(move |_| log_a1.borrow_mut().push("a1"))
+        .then(|()| future::yield_now())
+        .map(move |()| log_a2.borrow_mut().push("a2"))).unwrap();
+    spawner.spawn_local(future::lazy(move |_| log_b.borrow_mut().push("b"))).unwrap();
+
+    // The first task yields, so the second one runs before it completes
+    assert_eq!(pool.try_run_one_step(), RunOne::Progressed);
+    assert_eq!(pool.try_run_one_step(), RunOne::Completed);
+    assert_eq!(pool.try_run_one_step(), RunOne::Completed);
+    assert_eq!(pool.try_run_one_step(), RunOne::Stalled);
+    assert_eq!(*log.borrow(), vec!["a1", "b", "a2"]);
+}
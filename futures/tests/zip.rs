@@ -0,0 +1,116 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::stream::{self, EitherOrBoth, Stream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_local_waker_ref;
+use std::cell::Cell;
+use std::collections::BTreeSet;
+
+#[test]
+fn ends_without_polling_other_stream() {
+    let polls = Cell::new(0);
+    let other = stream::poll_fn(|_| {
+        polls.set(polls.get() + 1);
+        Poll::Ready(Some('x'))
+    });
+    let mut zipped = stream::iter(vec![1]).zip(other);
+    let lw = noop_local_waker_ref();
+
+    assert_eq!(zipped.poll_next_unpin(lw), Poll::Ready(Some((1, 'x'))));
+    assert_eq!(zipped.poll_next_unpin(lw), Poll::Ready(None));
+    assert_eq!(zipped.poll_next_unpin(lw), Poll::Ready(None));
+    assert_eq!(polls.get(), 1);
+}
+
+#[test]
+fn drops_unpaired_item() {
+    let polls = Cell::new(0);
+    let shorter = stream::poll_fn(|_| {
+        polls.set(polls.get() + 1);
+        match polls.get() {
+            1 => Poll::Pending,
+            _ => Poll::Ready(None),
+        }
+    });
+    let mut zipped = stream::iter(vec![1, 2]).zip(shorter);
+    let lw = noop_local_waker_ref();
+
+    // The first item of the longer stream is queued while waiting for a
+    // partner, and dropped once the other stream has ended
+    assert_eq!(zipped.poll_next_unpin(lw), Poll::Pending);
+    assert_eq!(zipped.poll_next_unpin(lw), Poll::Ready(None));
+    assert_eq!(zipped.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn unzip() {
+    let stream = stream::iter(vec![(1, "one"), (2, "two"), (3, "three")]);
+    let (numbers, names): (Vec<_>, Vec<_>) = block_on(stream.unzip());
+    assert_eq!(numbers, vec![1, 2, 3]);
+    assert_eq!(names, vec!["one", "two", "three"]);
+
+    let (left, right): (Vec<i32>, Vec<i32>) = block_on(stream::empty().unzip());
+    assert!(left.is_empty());
+    assert!(right.is_empty());
+}
+
+#[test]
+fn unzip_with_size_hint() {
+    let stream = stream::iter((0..100).map(|x| (x, x * 2)));
+    let (left, right): (Vec<i32>, Vec<i32>) = block_on(stream.unzip());
+    assert_eq!(left, (0..100).collect::<Vec<_>>());
+    assert_eq!(right, (0..100).map(|x| x * 2).collect::<Vec<_>>());
+    assert!(left.capacity() >= left.len());
+    assert!(right.capacity() >= right.len());
+}
+
+#[test]
+fn unzip_into_any_collection_across_polls() {
+    let lw = noop_local_waker_ref();
+    let (tx, rx) = mpsc::unbounded();
+    let mut unzip = rx.unzip::<_, _, BTreeSet<i32>, String>();
+
+    tx.unbounded_send((3, 'a')).unwrap();
+    tx.unbounded_send((1, 'b')).unwrap();
+    assert!(unzip.poll_unpin(lw).is_pending());
+    tx.unbounded_send((3, 'c')).unwrap();
+    drop(tx);
+
+    let (numbers, letters) = match unzip.poll_unpin(lw) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("expected the channel to be drained"),
+    };
+    assert_eq!(numbers.into_iter().collect::<Vec<_>>(), vec![1, 3]);
+    assert_eq!(letters, "abc");
+}
+
+#[test]
+fn zip_longest_waits_for_pending_stream() {
+    let lw = noop_local_waker_ref();
+    let (tx, rx) = mpsc::unbounded::<char>();
+    let mut stream = stream::iter(1..=3).zip_longest(rx);
+
+    // The first stream has an item, but the second one isn't done yet
+    assert!(stream.poll_next_unpin(lw).is_pending());
+    tx.unbounded_send('a').unwrap();
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(EitherOrBoth::Both(1, 'a'))));
+    assert!(stream.poll_next_unpin(lw).is_pending());
+
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(EitherOrBoth::Left(2))));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(Some(EitherOrBoth::Left(3))));
+    assert_eq!(stream.poll_next_unpin(lw), Poll::Ready(None));
+}
+
+#[test]
+fn zip_longest_right() {
+    let stream = stream::iter(vec![1]).zip_longest(stream::iter(vec!['a', 'b']));
+    assert_eq!(stream.size_hint(), (2, Some(2)));
+    assert_eq!(
+        block_on(stream.collect::<Vec<_>>()),
+        vec![EitherOrBoth::Both(1, 'a'), EitherOrBoth::Right('b')],
+    );
+}